@@ -0,0 +1,303 @@
+// Operator tool for the escrow program: posts and settles offers, lists open
+// escrows and manages the config, e.g. for support staff or scripted runs
+// against devnet. Instructions come from the anchor-escrow-client builders.
+use std::rc::Rc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anchor_client::anchor_lang::{system_program, Discriminator};
+use anchor_client::solana_account_decoder::UiAccountEncoding;
+use anchor_client::solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use anchor_client::solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::instruction::Instruction;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::{read_keypair_file, Keypair};
+use anchor_client::{Client, Cluster, Program};
+use anchor_escrow_client::states::{Config, Escrow};
+use anchor_escrow_client::{accounts, decode, instruction, ix, pda, ConfigUpdate, ID};
+use anyhow::{anyhow, bail, Result};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser)]
+#[command(name = "escrow-cli", about = "Operate the anchor-escrow program")]
+struct Cli {
+    /// devnet, localnet, mainnet or an RPC URL
+    #[arg(long, global = true, default_value = "devnet")]
+    url: Cluster,
+    /// Signs and pays for every transaction; defaults to the Solana CLI wallet
+    #[arg(long, global = true)]
+    keypair: Option<String>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Post an offer of `amount` mint_a for `taker_amount` mint_b
+    CreateOffer {
+        #[arg(long)]
+        mint_a: Pubkey,
+        #[arg(long)]
+        mint_b: Pubkey,
+        #[arg(long)]
+        amount: u64,
+        #[arg(long)]
+        taker_amount: u64,
+        /// Escrow seed; the current time in nanoseconds if left out
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Free text shown to takers, up to 64 bytes
+        #[arg(long)]
+        note: Option<String>,
+        /// Up to 16 bytes; rerunning with the same key can't open a second offer
+        #[arg(long)]
+        idempotency_key: Option<String>,
+    },
+    /// List escrows that have not settled yet
+    List {
+        #[arg(long)]
+        maker: Option<Pubkey>,
+    },
+    /// Print one escrow
+    Show { escrow: Pubkey },
+    /// Confirm the fiat payment as the taker
+    ConfirmPayment { escrow: Pubkey },
+    /// Release a confirmed escrow to its taker as the maker
+    Release { escrow: Pubkey },
+    /// Refund and close an escrow as the maker
+    Cancel { escrow: Pubkey },
+    #[command(subcommand)]
+    Config(ConfigCommand),
+}
+
+#[derive(Subcommand)]
+enum ConfigCommand {
+    Show,
+    /// Admin only; options left out keep their current value
+    Update {
+        #[arg(long)]
+        admin: Option<Pubkey>,
+        #[arg(long)]
+        insurance_fee_bps: Option<u16>,
+        #[arg(long)]
+        maker_deposit_bps: Option<u16>,
+        #[arg(long)]
+        max_open_escrows: Option<u16>,
+        #[arg(long)]
+        daily_volume_limit: Option<u64>,
+        #[arg(long)]
+        weekly_volume_limit: Option<u64>,
+        /// Replaces the whole Config::FEATURE_* set
+        #[arg(long)]
+        features: Option<u64>,
+        #[arg(long)]
+        compliance_required: Option<bool>,
+        #[arg(long)]
+        dispute_fee: Option<u64>,
+        /// Seconds past an escrow's deadline before it can be reaped
+        #[arg(long)]
+        reap_grace_period: Option<u32>,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let keypair_path = match cli.keypair {
+        Some(path) => path,
+        None => format!("{}/.config/solana/id.json", std::env::var("HOME")?),
+    };
+    let payer = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow!("reading keypair {}: {}", keypair_path, e))?;
+    let client = Client::new_with_options(cli.url, Rc::new(payer), CommitmentConfig::confirmed());
+    let program = client.program(ID)?;
+    let me = program.payer();
+
+    match cli.command {
+        Command::CreateOffer {
+            mint_a,
+            mint_b,
+            amount,
+            taker_amount,
+            seed,
+            note,
+            idempotency_key,
+        } => {
+            let seed = match seed {
+                Some(seed) => seed,
+                None => SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos() as u64,
+            };
+            let args = instruction::Initialize {
+                seed,
+                initializer_amount: amount,
+                taker_amount,
+                terms_hash: [0; 32],
+                tags: 0,
+                category: 0,
+                note: padded("note", note.as_deref().unwrap_or_default())?,
+                idempotency_key: padded(
+                    "idempotency key",
+                    idempotency_key.as_deref().unwrap_or_default(),
+                )?,
+            };
+            let token_program = token_program(&program, &mint_a)?;
+            send(
+                &program,
+                ix::initialize(&me, &mint_a, &mint_b, &token_program, args),
+            )?;
+            println!("escrow {}", pda::escrow(seed));
+        }
+        Command::List { maker } => list(&program, maker)?,
+        Command::Show { escrow } => print_escrow(&escrow, &fetch_escrow(&program, &escrow)?),
+        Command::ConfirmPayment { escrow } => {
+            let state = fetch_escrow(&program, &escrow)?;
+            send(&program, ix::confirm_payment(&me, &escrow, &state.mint_a))?;
+        }
+        Command::Release { escrow } => {
+            let state = fetch_escrow(&program, &escrow)?;
+            if !state.is_payment_confirmed() {
+                bail!("payment for {} has not been confirmed", escrow);
+            }
+            let token_program = token_program(&program, &state.mint_a)?;
+            send(
+                &program,
+                ix::exchange(&me, &state.taker, &escrow, &state.mint_a, &token_program),
+            )?;
+        }
+        Command::Cancel { escrow } => {
+            let state = fetch_escrow(&program, &escrow)?;
+            let token_program = token_program(&program, &state.mint_a)?;
+            send(
+                &program,
+                ix::cancel(&me, &escrow, &state.mint_a, &token_program),
+            )?;
+        }
+        Command::Config(ConfigCommand::Show) => {
+            let config: Config = program.account(pda::config())?;
+            println!("admin                {}", config.admin);
+            println!("features             {:#b}", config.features);
+            println!("insurance_fee_bps    {}", config.insurance_fee_bps);
+            println!("maker_deposit_bps    {}", config.maker_deposit_bps);
+            println!("max_open_escrows     {}", config.max_open_escrows);
+            println!("daily_volume_limit   {}", config.daily_volume_limit);
+            println!("weekly_volume_limit  {}", config.weekly_volume_limit);
+            println!("compliance_required  {}", config.compliance_required);
+            println!("dispute_fee          {}", config.dispute_fee);
+            println!("reap_grace_period    {}", config.reap_grace_period);
+        }
+        Command::Config(ConfigCommand::Update {
+            admin,
+            insurance_fee_bps,
+            maker_deposit_bps,
+            max_open_escrows,
+            daily_volume_limit,
+            weekly_volume_limit,
+            features,
+            compliance_required,
+            dispute_fee,
+            reap_grace_period,
+        }) => {
+            let update = ConfigUpdate {
+                admin,
+                insurance_fee_bps,
+                maker_deposit_bps,
+                max_open_escrows,
+                daily_volume_limit,
+                weekly_volume_limit,
+                features,
+                compliance_required,
+                dispute_fee,
+                reap_grace_period,
+                ..ConfigUpdate::default()
+            };
+            let accounts = accounts::UpdateConfig {
+                admin: me,
+                config: pda::config(),
+                audit_log: pda::audit_log(),
+                system_program: system_program::ID,
+            };
+            send(
+                &program,
+                ix::build(accounts, instruction::UpdateConfig { update }),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn send(program: &Program<Rc<Keypair>>, ix: Instruction) -> Result<()> {
+    let signature = program.request().instruction(ix).send()?;
+    println!("{}", signature);
+    Ok(())
+}
+
+fn fetch_escrow(program: &Program<Rc<Keypair>>, escrow: &Pubkey) -> Result<Escrow> {
+    let data = program.rpc().get_account_data(escrow)?;
+    decode::escrow(&data).map_err(|e| anyhow!("{}: {}", escrow, e))
+}
+
+// Owner of the mint, i.e. Token or Token-2022
+fn token_program(program: &Program<Rc<Keypair>>, mint: &Pubkey) -> Result<Pubkey> {
+    Ok(program.rpc().get_account(mint)?.owner)
+}
+
+fn list(program: &Program<Rc<Keypair>>, maker: Option<Pubkey>) -> Result<()> {
+    let mut filters = vec![
+        RpcFilterType::DataSize(Escrow::SPACE as u64),
+        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, Escrow::DISCRIMINATOR)),
+    ];
+    if let Some(maker) = maker {
+        let offset = Escrow::DISCRIMINATOR.len() + std::mem::offset_of!(Escrow, initializer);
+        filters.push(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            offset,
+            maker.as_ref(),
+        )));
+    }
+    let config = RpcProgramAccountsConfig {
+        filters: Some(filters),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            ..RpcAccountInfoConfig::default()
+        },
+        ..RpcProgramAccountsConfig::default()
+    };
+    for (key, account) in program
+        .rpc()
+        .get_program_accounts_with_config(&ID, config)?
+    {
+        match decode::escrow(&account.data) {
+            Ok(escrow) if escrow.is_funded() => print_escrow(&key, &escrow),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn print_escrow(key: &Pubkey, escrow: &Escrow) {
+    let status = if escrow.is_payment_confirmed() {
+        "confirmed"
+    } else if escrow.has_taker() {
+        "taken"
+    } else {
+        "open"
+    };
+    println!(
+        "{} {:9} maker {} {} {} for {} {}",
+        key,
+        status,
+        escrow.initializer,
+        escrow.initializer_amount,
+        escrow.mint_a,
+        escrow.taker_amount,
+        escrow.mint_b,
+    );
+}
+
+// `text` zero-padded into a fixed-size field
+fn padded<const N: usize>(field: &str, text: &str) -> Result<[u8; N]> {
+    let mut bytes = [0u8; N];
+    if text.len() > N {
+        bail!("{} is longer than {} bytes", field, N);
+    }
+    bytes[..text.len()].copy_from_slice(text.as_bytes());
+    Ok(bytes)
+}
@@ -227,6 +227,11 @@ class EscrowAPI {
             const sellerAta = splToken.getAssociatedTokenAddressSync(tokenMint, sellerWallet.publicKey);
             const buyerAta = splToken.getAssociatedTokenAddressSync(tokenMint, buyerWallet);
             const platformAta = splToken.getAssociatedTokenAddressSync(tokenMint, this.platformWallet);
+            const [insuranceFund] = solanaWeb3.PublicKey.findProgramAddressSync(
+                [Buffer.from('insurance')],
+                this.programId
+            );
+            const insuranceAta = splToken.getAssociatedTokenAddressSync(tokenMint, insuranceFund, true);
             
             // Derive vault account
             const vault = splToken.getAssociatedTokenAddressSync(tokenMint, escrow, true);
@@ -245,15 +250,31 @@ class EscrowAPI {
                 escrow: escrow,
                 vault: vault,
                 platformWallet: this.platformWallet,
+                insuranceFund: insuranceFund,
+                insuranceAtaA: insuranceAta,
                 associatedTokenprogram: splToken.ASSOCIATED_TOKEN_PROGRAM_ID,
                 tokenProgram: splToken.TOKEN_PROGRAM_ID,
                 systemProgram: solanaWeb3.SystemProgram.programId,
             };
             
+            // Exchange no longer creates the payout ATAs, so create them idempotently up front
+            const createPayoutAtas = [
+                splToken.createAssociatedTokenAccountIdempotentInstruction(
+                    sellerWallet.publicKey, buyerAta, buyerWallet, tokenMint
+                ),
+                splToken.createAssociatedTokenAccountIdempotentInstruction(
+                    sellerWallet.publicKey, platformAta, this.platformWallet, tokenMint
+                ),
+                splToken.createAssociatedTokenAccountIdempotentInstruction(
+                    sellerWallet.publicKey, insuranceAta, insuranceFund, tokenMint
+                ),
+            ];
+
             // Execute exchange instruction
             const signature = await this.program.methods
                 .exchange()
                 .accounts(accounts)
+                .preInstructions(createPayoutAtas)
                 .rpc();
             
             // Confirm transaction
@@ -0,0 +1,25 @@
+use anchor_lang::error::ErrorCode;
+use anchor_lang::prelude::*;
+use anchor_lang::{AccountDeserialize, Discriminator};
+
+use anchor_escrow::states::Escrow;
+
+// Any of the program's Borsh accounts, e.g. `decode::account::<Config>(&data)`;
+// checks the discriminator
+pub fn account<T: AccountDeserialize>(data: &[u8]) -> Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+// Escrows are zero-copy, so they are read in place instead. Accounts still on
+// an older layout are shorter and fail here until `migrate_escrow` grows them.
+pub fn escrow(data: &[u8]) -> Result<Escrow> {
+    let end = Escrow::DISCRIMINATOR.len() + std::mem::size_of::<Escrow>();
+    require!(
+        data.starts_with(Escrow::DISCRIMINATOR),
+        ErrorCode::AccountDiscriminatorMismatch
+    );
+    let body = data
+        .get(Escrow::DISCRIMINATOR.len()..end)
+        .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+    Ok(bytemuck::pod_read_unaligned(body))
+}
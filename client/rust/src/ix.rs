@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{self, get_associated_token_address_with_program_id};
+
+use anchor_escrow::constants::PLATFORM_WALLET;
+use anchor_escrow::{accounts, instruction, ID};
+
+use crate::pda;
+
+// Any instruction from its generated structs, e.g.
+// `build(accounts::SetNote { .. }, instruction::SetNote { note })`. Optional
+// accounts left as None are sent as the program id, which Anchor reads as
+// absent.
+pub fn build(accounts: impl ToAccountMetas, args: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: ID,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+// The builders below fill in the PDAs and token accounts of a plain offer and
+// leave every optional account out. Offers behind a gated market, a
+// stablecoin list, attestations or a compliance desk need those accounts too,
+// so build them with `build` instead.
+
+// Maker posts an offer, self-funded; the escrow address follows from `args.seed`,
+// and a nonzero `args.idempotency_key` claims its record too
+pub fn initialize(
+    maker: &Pubkey,
+    mint_a: &Pubkey,
+    mint_b: &Pubkey,
+    token_program: &Pubkey,
+    args: instruction::Initialize,
+) -> Instruction {
+    let escrow = pda::escrow(args.seed);
+    let idempotency = if args.idempotency_key == [0; 16] {
+        None
+    } else {
+        Some(pda::idempotency(maker, &args.idempotency_key))
+    };
+    build(
+        accounts::Initialize {
+            initializer: *maker,
+            payer: *maker,
+            mint_a: *mint_a,
+            mint_b: *mint_b,
+            initializer_ata_a: get_associated_token_address_with_program_id(
+                maker,
+                mint_a,
+                token_program,
+            ),
+            escrow,
+            vault: pda::vault(&escrow, mint_a, token_program),
+            config: pda::config(),
+            gateway_token: None,
+            stablecoins: None,
+            initializer_block: pda::blocked(maker),
+            initializer_stats: pda::trader_stats(maker),
+            escrow_counter: pda::escrow_counter(),
+            maker_registry: pda::registry(maker),
+            idempotency,
+            partner_config: None,
+            platform_wallet: PLATFORM_WALLET,
+            associated_token_program: associated_token::ID,
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        args,
+    )
+}
+
+// Taker confirms the fiat payment, taking the offer if it is still open
+pub fn confirm_payment(taker: &Pubkey, escrow: &Pubkey, mint_a: &Pubkey) -> Instruction {
+    build(
+        accounts::ConfirmPayment {
+            taker: *taker,
+            payer: *taker,
+            escrow: *escrow,
+            initializer_ata_a: None,
+            mint_a: *mint_a,
+            config: pda::config(),
+            gateway_token: None,
+            taker_block: pda::blocked(taker),
+            global_attestations: None,
+            offer_attestations: None,
+            region_policy: None,
+            taker_stats: pda::trader_stats(taker),
+            instructions: None,
+            system_program: system_program::ID,
+        },
+        instruction::ConfirmPayment {},
+    )
+}
+
+// Maker releases a confirmed escrow to the taker
+pub fn exchange(
+    maker: &Pubkey,
+    taker: &Pubkey,
+    escrow: &Pubkey,
+    mint_a: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let insurance_fund = pda::insurance_fund();
+    build(
+        accounts::Exchange {
+            authority: *maker,
+            initializer: *maker,
+            taker: *taker,
+            mint_a: *mint_a,
+            taker_ata_a: get_associated_token_address_with_program_id(taker, mint_a, token_program),
+            platform_ata_a: get_associated_token_address_with_program_id(
+                &PLATFORM_WALLET,
+                mint_a,
+                token_program,
+            ),
+            escrow: *escrow,
+            vault: pda::vault(escrow, mint_a, token_program),
+            platform_wallet: PLATFORM_WALLET,
+            config: pda::config(),
+            insurance_fund,
+            insurance_ata_a: get_associated_token_address_with_program_id(
+                &insurance_fund,
+                mint_a,
+                token_program,
+            ),
+            taker_block: pda::blocked(taker),
+            initializer_stats: pda::trader_stats(maker),
+            session: None,
+            maker_registry: pda::registry(maker),
+            rebate_pool: None,
+            rebate_pool_ata_a: None,
+            maker_rebate: None,
+            receipt: None,
+            initializer_history: None,
+            taker_history: None,
+            rate_feed: None,
+            swap_router: None,
+            swap_program: None,
+            taker_ata_out: None,
+            donation: None,
+            charity_ata_a: None,
+            compliance_approval: None,
+            signer_nonce: None,
+            partner_config: None,
+            partner_ata_a: None,
+            partner_pool: None,
+            partner_pool_ata_a: None,
+            partner_accrual: None,
+            instructions: None,
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        instruction::Exchange {},
+    )
+}
+
+// Maker refunds and closes an escrow that has not settled
+pub fn cancel(
+    maker: &Pubkey,
+    escrow: &Pubkey,
+    mint_a: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    build(
+        accounts::Cancel {
+            authority: *maker,
+            initializer: *maker,
+            mint_a: *mint_a,
+            initializer_ata_a: get_associated_token_address_with_program_id(
+                maker,
+                mint_a,
+                token_program,
+            ),
+            funder_ata_a: None,
+            escrow: *escrow,
+            vault: pda::vault(escrow, mint_a, token_program),
+            config: pda::config(),
+            initializer_stats: pda::trader_stats(maker),
+            deposit: None,
+            session: None,
+            maker_registry: pda::registry(maker),
+            platform_wallet: PLATFORM_WALLET,
+            token_program: *token_program,
+            system_program: system_program::ID,
+        },
+        instruction::Cancel {},
+    )
+}
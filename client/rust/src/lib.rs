@@ -0,0 +1,12 @@
+// Rust client for the escrow program, so bots and backends don't hand-roll
+// instruction data. Every instruction has a generated argument struct in
+// `instruction` and account struct in `accounts`; `ix::build` turns any pair
+// into an `Instruction`, and `ix` has ready-made builders that derive the
+// accounts of the core offer flow.
+pub mod decode;
+pub mod ix;
+pub mod pda;
+
+pub use anchor_escrow::constants::PLATFORM_WALLET;
+pub use anchor_escrow::errors::EscrowError;
+pub use anchor_escrow::{accounts, instruction, states, CategoryFee, ConfigUpdate, ID};
@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+
+use anchor_escrow::ID;
+
+fn find(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &ID).0
+}
+
+pub fn config() -> Pubkey {
+    find(&[b"config"])
+}
+
+pub fn audit_log() -> Pubkey {
+    find(&[b"audit_log"])
+}
+
+pub fn escrow_counter() -> Pubkey {
+    find(&[b"escrow_counter"])
+}
+
+// Data-less owner of the insurance fund token accounts
+pub fn insurance_fund() -> Pubkey {
+    find(&[b"insurance"])
+}
+
+// Data-less owner of the maker rebate pool token accounts
+pub fn rebate_pool() -> Pubkey {
+    find(&[b"rebate_pool"])
+}
+
+// Data-less owner of the partner pool token accounts
+pub fn partner_pool() -> Pubkey {
+    find(&[b"partner_pool"])
+}
+
+// Emitter of the Wormhole settlement messages; EVM consumers register it as
+// the trusted source
+pub fn wormhole_emitter() -> Pubkey {
+    find(&[b"emitter"])
+}
+
+pub fn stablecoins() -> Pubkey {
+    find(&[b"stablecoins"])
+}
+
+pub fn arbitration() -> Pubkey {
+    find(&[b"arbitration"])
+}
+
+pub fn partner(partner_id: u16) -> Pubkey {
+    find(&[b"partner", &partner_id.to_le_bytes()])
+}
+
+pub fn governance() -> Pubkey {
+    find(&[b"governance"])
+}
+
+pub fn escrow(seed: u64) -> Pubkey {
+    find(&[b"state", &seed.to_le_bytes()])
+}
+
+// The escrow's associated token account for mint_a
+pub fn vault(escrow: &Pubkey, mint_a: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(escrow, mint_a, token_program)
+}
+
+pub fn trader_stats(wallet: &Pubkey) -> Pubkey {
+    find(&[b"trader_stats", wallet.as_ref()])
+}
+
+pub fn blocked(wallet: &Pubkey) -> Pubkey {
+    find(&[b"blocked", wallet.as_ref()])
+}
+
+pub fn registry(maker: &Pubkey) -> Pubkey {
+    find(&[b"registry", maker.as_ref()])
+}
+
+pub fn history(wallet: &Pubkey) -> Pubkey {
+    find(&[b"history", wallet.as_ref()])
+}
+
+pub fn session(maker: &Pubkey, session_key: &Pubkey) -> Pubkey {
+    find(&[b"session", maker.as_ref(), session_key.as_ref()])
+}
+
+pub fn maker_rebate(maker: &Pubkey, mint: &Pubkey) -> Pubkey {
+    find(&[b"maker_rebate", maker.as_ref(), mint.as_ref()])
+}
+
+pub fn partner_accrual(partner_id: u16, mint: &Pubkey) -> Pubkey {
+    find(&[b"partner_accrual", &partner_id.to_le_bytes(), mint.as_ref()])
+}
+
+pub fn rate_feed(mint_a: &Pubkey, mint_b: &Pubkey) -> Pubkey {
+    find(&[b"rate_feed", mint_a.as_ref(), mint_b.as_ref()])
+}
+
+pub fn template(maker: &Pubkey, template_id: u64) -> Pubkey {
+    find(&[b"template", maker.as_ref(), &template_id.to_le_bytes()])
+}
+
+pub fn recurring(maker: &Pubkey, schedule_id: u64) -> Pubkey {
+    find(&[b"recurring", maker.as_ref(), &schedule_id.to_le_bytes()])
+}
+
+pub fn arbitrator(wallet: &Pubkey) -> Pubkey {
+    find(&[b"arbitrator", wallet.as_ref()])
+}
+
+// Nonce the wallet's permits and signed release approvals must carry
+pub fn signer_nonce(wallet: &Pubkey) -> Pubkey {
+    find(&[b"nonce", wallet.as_ref()])
+}
+
+pub fn idempotency(maker: &Pubkey, idempotency_key: &[u8; 16]) -> Pubkey {
+    find(&[b"idempotency", maker.as_ref(), idempotency_key])
+}
+
+// Per-escrow accounts
+
+pub fn bond(escrow: &Pubkey) -> Pubkey {
+    find(&[b"bond", escrow.as_ref()])
+}
+
+pub fn maker_deposit(escrow: &Pubkey) -> Pubkey {
+    find(&[b"maker_deposit", escrow.as_ref()])
+}
+
+pub fn regions(escrow: &Pubkey) -> Pubkey {
+    find(&[b"regions", escrow.as_ref()])
+}
+
+pub fn attestations(escrow: &Pubkey) -> Pubkey {
+    find(&[b"attestations", escrow.as_ref()])
+}
+
+pub fn payment_details(escrow: &Pubkey) -> Pubkey {
+    find(&[b"payment_details", escrow.as_ref()])
+}
+
+pub fn donation(escrow: &Pubkey) -> Pubkey {
+    find(&[b"donation", escrow.as_ref()])
+}
+
+pub fn compliance_approval(escrow: &Pubkey) -> Pubkey {
+    find(&[b"compliance_approval", escrow.as_ref()])
+}
+
+pub fn receipt(escrow: &Pubkey, payment_confirmed_at: i64) -> Pubkey {
+    find(&[
+        b"receipt",
+        escrow.as_ref(),
+        &payment_confirmed_at.to_le_bytes(),
+    ])
+}
+
+pub fn milestone(escrow: &Pubkey, index: u8) -> Pubkey {
+    find(&[b"milestone", escrow.as_ref(), &[index]])
+}
+
+// Dispute accounts
+
+pub fn milestone_dispute(milestone: &Pubkey) -> Pubkey {
+    find(&[b"milestone_dispute", milestone.as_ref()])
+}
+
+pub fn jury(dispute: &Pubkey) -> Pubkey {
+    find(&[b"jury", dispute.as_ref()])
+}
+
+pub fn jury_vote(jury: &Pubkey, juror: &Pubkey) -> Pubkey {
+    find(&[b"jury_vote", jury.as_ref(), juror.as_ref()])
+}
+
+pub fn escalation(dispute: &Pubkey) -> Pubkey {
+    find(&[b"escalation", dispute.as_ref()])
+}
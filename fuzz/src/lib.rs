@@ -0,0 +1,357 @@
+// Invariant harness for the escrow program. It runs instruction sequences
+// against the compiled program in LiteSVM, keeps a model of what each escrow
+// should look like, and checks the chain against it after every step:
+//
+// - a live escrow's vault holds exactly what the escrow still owes
+// - `exchange` only ever succeeds on a confirmed escrow, signed by the maker
+// - a settlement pays out the whole vault, and the platform and insurance
+//   cut together never exceed the configured fee
+//
+// tests/state_machine.rs feeds it random sequences. It needs the program
+// built first (`anchor build`) and does nothing otherwise.
+use anchor_escrow_client::states::{Config, Escrow};
+use anchor_escrow_client::{decode, instruction, ix, pda, ID, PLATFORM_WALLET};
+use anchor_lang::AccountSerialize;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{TokenAccount, ID as TOKEN_PROGRAM_ID};
+use litesvm::LiteSVM;
+use litesvm_token::{CreateAssociatedTokenAccount, CreateMint, MintTo};
+use solana_sdk::account::Account;
+use solana_sdk::clock::Clock;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::Transaction;
+
+pub const PROGRAM_PATH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../target/deploy/anchor_escrow.so"
+);
+
+// What the maker starts with, enough for any sequence the tests generate
+const MAKER_BALANCE: u64 = 1_000_000_000_000;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Actor {
+    Maker,
+    Taker,
+    Stranger,
+}
+
+// `escrow` indexes the escrows opened so far, wrapping around
+#[derive(Clone, Debug)]
+pub enum Op {
+    Initialize { amount: u64, taker_amount: u64 },
+    ConfirmPayment { escrow: usize, actor: Actor },
+    Exchange { escrow: usize, actor: Actor },
+    Cancel { escrow: usize, actor: Actor },
+}
+
+struct Tracked {
+    key: Pubkey,
+    amount: u64,
+    confirmed: bool,
+    closed: bool,
+}
+
+pub struct Harness {
+    svm: LiteSVM,
+    maker: Keypair,
+    taker: Keypair,
+    stranger: Keypair,
+    mint_a: Pubkey,
+    mint_b: Pubkey,
+    escrows: Vec<Tracked>,
+    next_seed: u64,
+}
+
+impl Harness {
+    // None when the program hasn't been built yet
+    pub fn new() -> Option<Self> {
+        let program = std::fs::read(PROGRAM_PATH).ok()?;
+        let mut svm = LiteSVM::new();
+        svm.add_program(ID, &program).unwrap();
+
+        let (maker, taker, stranger) = (Keypair::new(), Keypair::new(), Keypair::new());
+        for wallet in [&maker, &taker, &stranger].iter() {
+            svm.airdrop(&wallet.pubkey(), 10 * LAMPORTS_PER_SOL)
+                .unwrap();
+        }
+        let mint_a = CreateMint::new(&mut svm, &maker)
+            .decimals(6)
+            .send()
+            .unwrap();
+        let mint_b = CreateMint::new(&mut svm, &maker)
+            .decimals(6)
+            .send()
+            .unwrap();
+        let maker_ata = CreateAssociatedTokenAccount::new(&mut svm, &maker, &mint_a)
+            .send()
+            .unwrap();
+        MintTo::new(&mut svm, &maker, &mint_a, &maker_ata, MAKER_BALANCE)
+            .send()
+            .unwrap();
+        for owner in [taker.pubkey(), PLATFORM_WALLET, pda::insurance_fund()].iter() {
+            CreateAssociatedTokenAccount::new(&mut svm, &maker, &mint_a)
+                .owner(owner)
+                .send()
+                .unwrap();
+        }
+
+        let mut harness = Harness {
+            svm,
+            maker,
+            taker,
+            stranger,
+            mint_a,
+            mint_b,
+            escrows: Vec::new(),
+            next_seed: 0,
+        };
+        harness.write_config();
+        Some(harness)
+    }
+
+    // LiteSVM deploys without an upgrade authority, so `initialize_config`
+    // can't run; write the account it would create instead
+    fn write_config(&mut self) {
+        let (config_key, bump) = Pubkey::find_program_address(&[b"config"], &ID);
+        let (_, insurance_bump) = Pubkey::find_program_address(&[b"insurance"], &ID);
+        let config = Config {
+            version: Config::VERSION,
+            bump,
+            insurance_bump,
+            insurance_fee_bps: 1_000,
+            admin: self.maker.pubkey(),
+            arbitration_fee_bps: 0,
+            maker_deposit_bps: 0,
+            max_cancel_strikes: 0,
+            gatekeeper_network: Pubkey::default(),
+            attestations_required: false,
+            daily_volume_limit: 0,
+            weekly_volume_limit: 0,
+            max_open_escrows: 0,
+            creation_fee_lamports: 0,
+            refund_creation_fee: false,
+            auto_release_after_hours: 0,
+            _reserved: [0; 1],
+            promo_fee_percentage: 0,
+            promo_starts_at: 0,
+            promo_ends_at: 0,
+            maker_rebate_bps: 0,
+            rebate_mint: Pubkey::default(),
+            risk_premium_max_bps: 0,
+            gc_bounty_bps: 0,
+            category_fee_overrides: 0,
+            category_fee_percentages: Default::default(),
+            _reserved_v2: [0; 8],
+            features: Config::DEFAULT_FEATURES,
+            max_rate_deviation_bps: 0,
+            stablecoin_only: false,
+            yield_maker_share_bps: 0,
+            compliance_required: false,
+            dispute_fee: 0,
+            reap_grace_period: 0,
+            _reserved_v3: [0; 14],
+        };
+        let mut data = Vec::with_capacity(Config::SPACE);
+        config.try_serialize(&mut data).unwrap();
+        data.resize(Config::SPACE, 0);
+        let account = Account {
+            lamports: self.svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.svm.set_account(config_key, account).unwrap();
+    }
+
+    pub fn apply(&mut self, op: &Op) {
+        match *op {
+            Op::Initialize {
+                amount,
+                taker_amount,
+            } => self.initialize(amount, taker_amount),
+            Op::ConfirmPayment { escrow, actor } => self.confirm_payment(escrow, actor),
+            Op::Exchange { escrow, actor } => self.exchange(escrow, actor),
+            Op::Cancel { escrow, actor } => self.cancel(escrow, actor),
+        }
+        self.check_invariants();
+    }
+
+    fn initialize(&mut self, amount: u64, taker_amount: u64) {
+        let seed = self.next_seed;
+        self.next_seed += 1;
+        let args = instruction::Initialize {
+            seed,
+            initializer_amount: amount,
+            taker_amount,
+            terms_hash: [0; 32],
+            tags: 0,
+            category: 0,
+            note: [0; 64],
+            idempotency_key: [0; 16],
+        };
+        let maker = self.maker.pubkey();
+        let ix = ix::initialize(&maker, &self.mint_a, &self.mint_b, &TOKEN_PROGRAM_ID, args);
+        if self.send(ix, Actor::Maker) {
+            self.escrows.push(Tracked {
+                key: pda::escrow(seed),
+                amount,
+                confirmed: false,
+                closed: false,
+            });
+        }
+    }
+
+    fn confirm_payment(&mut self, index: usize, actor: Actor) {
+        let index = match self.pick(index) {
+            Some(index) => index,
+            None => return,
+        };
+        let signer = self.signer(actor).pubkey();
+        let ix = ix::confirm_payment(&signer, &self.escrows[index].key, &self.mint_a);
+        if self.send(ix, actor) {
+            let tracked = &mut self.escrows[index];
+            assert!(!tracked.closed, "confirmed a closed escrow");
+            tracked.confirmed = true;
+        }
+    }
+
+    fn exchange(&mut self, index: usize, actor: Actor) {
+        let index = match self.pick(index) {
+            Some(index) => index,
+            None => return,
+        };
+        let (maker, taker) = (self.maker.pubkey(), self.taker.pubkey());
+        let key = self.escrows[index].key;
+        let taker_ata = self.ata(&taker);
+        let fee_atas = [self.ata(&PLATFORM_WALLET), self.ata(&pda::insurance_fund())];
+        let taker_before = self.balance(&taker_ata);
+        let fees_before: u64 = fee_atas.iter().map(|ata| self.balance(ata)).sum();
+
+        let mut ix = ix::exchange(&maker, &taker, &key, &self.mint_a, &TOKEN_PROGRAM_ID);
+        // The first account is the signing authority
+        ix.accounts[0].pubkey = self.signer(actor).pubkey();
+        if !self.send(ix, actor) {
+            return;
+        }
+
+        let tracked = &mut self.escrows[index];
+        assert!(
+            tracked.confirmed,
+            "settled {} without a confirmed payment",
+            key
+        );
+        assert_eq!(actor, Actor::Maker, "{:?} released {}", actor, key);
+        tracked.closed = true;
+        let amount = tracked.amount;
+
+        let fees = fee_atas.iter().map(|ata| self.balance(ata)).sum::<u64>() - fees_before;
+        let paid = self.balance(&taker_ata) - taker_before;
+        assert_eq!(paid + fees, amount, "settlement of {} leaked tokens", key);
+        let config: Config =
+            decode::account(&self.svm.get_account(&pda::config()).unwrap().data).unwrap();
+        let now = self.svm.get_sysvar::<Clock>().unix_timestamp;
+        let max_fee = amount as u128 * config.fee_percentage(0, now) as u128 / 100;
+        assert!(
+            fees as u128 <= max_fee,
+            "fee {} above {} on {}",
+            fees,
+            max_fee,
+            key
+        );
+    }
+
+    fn cancel(&mut self, index: usize, actor: Actor) {
+        let index = match self.pick(index) {
+            Some(index) => index,
+            None => return,
+        };
+        let maker = self.maker.pubkey();
+        let key = self.escrows[index].key;
+        let mut ix = ix::cancel(&maker, &key, &self.mint_a, &TOKEN_PROGRAM_ID);
+        ix.accounts[0].pubkey = self.signer(actor).pubkey();
+        if self.send(ix, actor) {
+            assert_eq!(actor, Actor::Maker, "{:?} cancelled {}", actor, key);
+            self.escrows[index].closed = true;
+        }
+    }
+
+    pub fn check_invariants(&self) {
+        for tracked in &self.escrows {
+            let account = self
+                .svm
+                .get_account(&tracked.key)
+                .filter(|a| a.lamports > 0);
+            let account = match (tracked.closed, account) {
+                (true, None) => continue,
+                (true, Some(_)) => panic!("{} survived its settlement", tracked.key),
+                (false, None) => panic!("{} vanished while open", tracked.key),
+                (false, Some(account)) => account,
+            };
+            let escrow: Escrow = decode::escrow(&account.data).unwrap();
+            assert_eq!(
+                escrow.is_payment_confirmed(),
+                tracked.confirmed,
+                "confirmation of {} drifted",
+                tracked.key
+            );
+            let vault = pda::vault(&tracked.key, &self.mint_a, &TOKEN_PROGRAM_ID);
+            assert_eq!(
+                self.balance(&vault),
+                escrow.initializer_amount - escrow.released_amount,
+                "vault of {} out of step with the escrow",
+                tracked.key
+            );
+        }
+    }
+
+    fn pick(&self, index: usize) -> Option<usize> {
+        if self.escrows.is_empty() {
+            None
+        } else {
+            Some(index % self.escrows.len())
+        }
+    }
+
+    fn signer(&self, actor: Actor) -> &Keypair {
+        match actor {
+            Actor::Maker => &self.maker,
+            Actor::Taker => &self.taker,
+            Actor::Stranger => &self.stranger,
+        }
+    }
+
+    fn ata(&self, owner: &Pubkey) -> Pubkey {
+        get_associated_token_address(owner, &self.mint_a)
+    }
+
+    fn balance(&self, token_account: &Pubkey) -> u64 {
+        match self.svm.get_account(token_account) {
+            Some(account) => decode::account::<TokenAccount>(&account.data)
+                .map(|token| token.amount)
+                .unwrap_or(0),
+            None => 0,
+        }
+    }
+
+    // Whether the transaction landed; failures are fine, the model only moves
+    // on success
+    fn send(&mut self, ix: Instruction, actor: Actor) -> bool {
+        let signer = self.signer(actor);
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&signer.pubkey()),
+            &[signer],
+            self.svm.latest_blockhash(),
+        );
+        let landed = self.svm.send_transaction(tx).is_ok();
+        // Identical retries would otherwise be rejected as duplicates
+        self.svm.expire_blockhash();
+        landed
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_escrow_fuzz::{Actor, Harness, Op};
+use proptest::prelude::*;
+
+fn actor() -> impl Strategy<Value = Actor> {
+    prop_oneof![
+        Just(Actor::Maker),
+        Just(Actor::Taker),
+        Just(Actor::Stranger)
+    ]
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        (0..1_000_000u64, 0..1_000_000u64).prop_map(|(amount, taker_amount)| Op::Initialize {
+            amount,
+            taker_amount
+        }),
+        (any::<usize>(), actor()).prop_map(|(escrow, actor)| Op::ConfirmPayment { escrow, actor }),
+        (any::<usize>(), actor()).prop_map(|(escrow, actor)| Op::Exchange { escrow, actor }),
+        (any::<usize>(), actor()).prop_map(|(escrow, actor)| Op::Cancel { escrow, actor }),
+    ]
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    // Every step re-checks the invariants listed in the harness, so a failing
+    // case shrinks to the shortest sequence that breaks one
+    #[test]
+    fn random_sequences_keep_invariants(ops in prop::collection::vec(op(), 1..32)) {
+        let mut harness = match Harness::new() {
+            Some(harness) => harness,
+            None => return Ok(()),
+        };
+        for op in &ops {
+            harness.apply(op);
+        }
+    }
+}
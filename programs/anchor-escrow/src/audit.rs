@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::clock;
+use crate::events::AdminActionRecorded;
+use crate::states::{AdminAction, AuditLog};
+
+// Appends an admin action to the audit trail. `params` are the instruction
+// arguments, Borsh-encoded so indexers can decode them with the IDL types.
+pub fn record_admin_action<T: AnchorSerialize>(
+    log: &mut AuditLog,
+    bump: u8,
+    action: AdminAction,
+    actor: Pubkey,
+    target: Pubkey,
+    params: &T,
+) -> Result<()> {
+    log.init_if_new(bump);
+    let timestamp = clock::now()?;
+    let params = params.try_to_vec()?;
+    let sequence = log.sequence;
+    let head = hashv(&[
+        &log.head,
+        &sequence.to_le_bytes(),
+        &[action as u8],
+        actor.as_ref(),
+        target.as_ref(),
+        &timestamp.to_le_bytes(),
+        &params,
+    ])
+    .to_bytes();
+
+    log.sequence += 1;
+    log.last_recorded_at = timestamp;
+    log.head = head;
+
+    emit!(AdminActionRecorded {
+        sequence,
+        action,
+        actor,
+        target,
+        timestamp,
+        params,
+        chain_hash: head,
+    });
+    Ok(())
+}
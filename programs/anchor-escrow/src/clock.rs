@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+// Unix time every expiry, vesting and dispute deadline check is made against
+#[cfg(not(feature = "test-clock"))]
+pub fn now() -> Result<i64> {
+    Ok(Clock::get()?.unix_timestamp)
+}
+
+// `test-clock` builds let a `warp_clock` instruction earlier in the same
+// transaction stand in for the Clock sysvar. The time is read back from the
+// processed instruction rather than an account, so the account constraints
+// that check deadlines see it too without every context taking an extra
+// account.
+#[cfg(feature = "test-clock")]
+pub fn now() -> Result<i64> {
+    use anchor_lang::solana_program::instruction::get_processed_sibling_instruction;
+    use anchor_lang::Discriminator;
+
+    let discriminator = crate::instruction::WarpClock::DISCRIMINATOR;
+    // Index 0 is the most recent sibling, so the last warp wins
+    let mut index = 0;
+    while let Some(ix) = get_processed_sibling_instruction(index) {
+        if ix.program_id == crate::ID && ix.data.starts_with(discriminator) {
+            let timestamp = ix
+                .data
+                .get(discriminator.len()..discriminator.len() + 8)
+                .ok_or(ProgramError::InvalidInstructionData)?;
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(timestamp);
+            return Ok(i64::from_le_bytes(bytes));
+        }
+        index += 1;
+    }
+    Ok(Clock::get()?.unix_timestamp)
+}
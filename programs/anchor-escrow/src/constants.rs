@@ -3,6 +3,133 @@ use anchor_lang::prelude::*;
 // Platform fee configuration - hardcoded in program
 pub const PLATFORM_FEE_PERCENTAGE: u8 = 6; // 6% platform fee
 
+// Semver (major, minor, patch) of this build, stamped on the escrows it
+// creates; keep in step with the crate version in Cargo.toml
+pub const PROGRAM_VERSION: [u8; 3] = [0, 1, 0];
+
 // Platform wallet address - hardcoded in program
 // This is the wallet that receives platform fees
+#[cfg(not(feature = "localnet"))]
 pub const PLATFORM_WALLET: Pubkey = pubkey!("CkjSZdXopqgh7jkPFn8MxdU7QKwfYdjQNNwbYABFpCx2");
+// Local validators pay fees to the dev wallet (cursor/wallet-dev.json)
+#[cfg(feature = "localnet")]
+pub const PLATFORM_WALLET: Pubkey = pubkey!("8EiCwjqc561w1JXe1wdhkHQXkH8tW3SxMeHwh8KT2gjj");
+
+// `devnet` and `localnet` builds shorten the waits and loosen the limits below
+// so integration runs don't sit on mainnet timers
+#[cfg(all(feature = "devnet", feature = "localnet"))]
+compile_error!("the `devnet` and `localnet` features are mutually exclusive");
+
+// Upper bound on escrows handled by one batch instruction, keeps the
+// transaction under the packet size limit
+pub const MAX_BATCH_SIZE: usize = 8;
+
+// How long a designated taker keeps the escrow before the initializer may
+// reassign it, so a reassignment can't race a fiat payment already in flight
+#[cfg(not(any(feature = "devnet", feature = "localnet")))]
+pub const TAKER_REASSIGN_COOLDOWN_SECS: i64 = 30 * 60;
+#[cfg(any(feature = "devnet", feature = "localnet"))]
+pub const TAKER_REASSIGN_COOLDOWN_SECS: i64 = 60;
+
+// Upper bound on tranches per milestone escrow
+pub const MAX_MILESTONES: u8 = 16;
+
+// Denominator for fee shares expressed in basis points
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+// Civic gateway program that owns gateway token (pass) accounts
+pub const CIVIC_GATEWAY_PROGRAM_ID: Pubkey = pubkey!("gatem74V238djXdzWnJf94Wo1DcnuGkfijbf3AuBhfs");
+
+// Solana Attestation Service program that owns attestation accounts
+pub const SAS_PROGRAM_ID: Pubkey = pubkey!("22zoJMtdu4tQc2PzL74ZUT7FrwgB1Udec8DdW4yw4BdG");
+
+// SPL Memo program, whose instruction data is the memo text itself
+pub const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+// Wormhole core bridge that `publish_settlement` posts messages through
+#[cfg(not(any(feature = "devnet", feature = "localnet")))]
+pub const WORMHOLE_PROGRAM_ID: Pubkey = pubkey!("worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTth");
+#[cfg(feature = "devnet")]
+pub const WORMHOLE_PROGRAM_ID: Pubkey = pubkey!("3u8hJUVTA4jH1wYAyUur7FFZVQ8H635K3tSHHF4ssjQ5");
+// The address the Wormhole devnet (Tilt) deploys its bridge at
+#[cfg(feature = "localnet")]
+pub const WORMHOLE_PROGRAM_ID: Pubkey = pubkey!("Bridge1p5gheXUvJ6jGWGeCsgPKgnE3YgdGKRVCMY9o");
+
+// Upper bound on attestation schemas one requirement set can ask for
+pub const MAX_ATTESTATION_SCHEMAS: usize = 4;
+
+// Upper bound on region codes in one offer's allow or block list
+pub const MAX_REGION_CODES: usize = 16;
+
+// Upper bound on fiat currencies one offer can be priced in
+pub const MAX_FIAT_QUOTES: usize = 4;
+
+// Longest a maker session key may stay valid before it has to be renewed
+pub const MAX_SESSION_DURATION: i64 = SECONDS_PER_WEEK;
+
+// Shortest interval a recurring schedule may post escrows at
+#[cfg(not(any(feature = "devnet", feature = "localnet")))]
+pub const MIN_RECURRING_INTERVAL: i64 = SECONDS_PER_HOUR;
+#[cfg(any(feature = "devnet", feature = "localnet"))]
+pub const MIN_RECURRING_INTERVAL: i64 = 60;
+
+// Highest fee a white-label partner may take on top of the platform fee
+pub const MAX_PARTNER_FEE_BPS: u16 = 500;
+
+// Longest URI or CID a payment details pointer can hold
+pub const MAX_PAYMENT_URI_LEN: usize = 128;
+
+// Longest bank reference a taker may confirm a payment with
+pub const MAX_PAYMENT_REFERENCE_LEN: usize = 64;
+
+// Open escrows one maker registry can list
+pub const MAX_REGISTRY_ESCROWS: usize = 64;
+
+// Longest buyer-protection delay an offer may put between payment
+// confirmation and release
+pub const MAX_RELEASE_DELAY: i64 = SECONDS_PER_DAY;
+
+// How long an idempotency key blocks a repeated `initialize` before anyone may
+// close its record
+pub const IDEMPOTENCY_KEY_TTL: i64 = SECONDS_PER_DAY;
+
+// Longest the config may hold off reaping past an escrow's deadline
+pub const MAX_REAP_GRACE_PERIOD: i64 = SECONDS_PER_DAY;
+
+// Levels of a trader's history tree, i.e. up to 2^20 recorded trades
+pub const TRADE_HISTORY_DEPTH: usize = 20;
+
+// Oldest a posted exchange rate may be for settlement to rely on it
+#[cfg(not(any(feature = "devnet", feature = "localnet")))]
+pub const MAX_RATE_AGE: i64 = 10 * 60;
+// Test feeds are posted by hand and go stale between runs
+#[cfg(any(feature = "devnet", feature = "localnet"))]
+pub const MAX_RATE_AGE: i64 = SECONDS_PER_DAY;
+
+// Mints the admin's stablecoin list can hold
+pub const MAX_STABLECOINS: usize = 32;
+
+// Charity wallets the admin's list can hold
+pub const MAX_CHARITIES: usize = 16;
+
+// Largest share of their payout a taker may pledge to charity
+pub const MAX_DONATION_BPS: u16 = 1_000;
+
+// Offer categories the config can set a fee for; higher ones pay the standard fee
+pub const MAX_FEE_CATEGORIES: usize = 16;
+
+// Volume limit windows
+pub const SECONDS_PER_HOUR: i64 = 60 * 60;
+pub const SECONDS_PER_DAY: i64 = 24 * SECONDS_PER_HOUR;
+pub const SECONDS_PER_WEEK: i64 = 7 * SECONDS_PER_DAY;
+
+// Region codes and languages one arbitrator can be tagged with
+pub const MAX_ARBITRATOR_REGIONS: usize = 8;
+pub const MAX_ARBITRATOR_LANGUAGES: usize = 4;
+
+// Bounds on a dispute jury's size; always odd, so a full vote has a majority
+#[cfg(not(any(feature = "devnet", feature = "localnet")))]
+pub const MIN_JURORS: usize = 3;
+#[cfg(any(feature = "devnet", feature = "localnet"))]
+pub const MIN_JURORS: usize = 1;
+pub const MAX_JURORS: usize = 7;
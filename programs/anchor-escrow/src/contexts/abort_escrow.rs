@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PLATFORM_WALLET;
+use crate::errors::EscrowError;
+use crate::fees::forfeit_creation_fee;
+use crate::states::{Escrow, EscrowStatus, MakerRegistry, TraderStats};
+
+#[derive(Accounts)]
+pub struct AbortEscrow<'info> {
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = escrow.load()?.status == EscrowStatus::Created as u8 @ EscrowError::EscrowAlreadyFunded,
+        close = initializer,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    // The initializer's profile, so the open-escrow count drops
+    #[account(
+        mut,
+        seeds = [b"trader_stats", escrow.load()?.initializer.as_ref()],
+        bump = initializer_stats.bump,
+    )]
+    pub initializer_stats: Box<Account<'info, TraderStats>>,
+    // The maker's registry, so the escrow drops off it
+    #[account(
+        mut,
+        seeds = [b"registry", escrow.load()?.initializer.as_ref()],
+        bump = maker_registry.bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    /// CHECK: This is the hardcoded platform wallet address, which keeps the
+    /// creation fee of offers that never settle
+    #[account(mut, address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+}
+
+impl<'info> AbortEscrow<'info> {
+    pub fn abort_escrow(&mut self) -> Result<()> {
+        // Nothing was deposited, so beyond the open-escrow count and creation
+        // fee closing the state account is all there is to do
+        self.initializer_stats.track_closed(1);
+        self.maker_registry.remove(&self.escrow.key());
+        if self.escrow.load()?.has_flag(Escrow::FLAG_CREATION_FEE_HELD) {
+            forfeit_creation_fee(
+                &self.escrow.to_account_info(),
+                &self.platform_wallet.to_account_info(),
+            )?;
+        }
+        Ok(())
+    }
+}
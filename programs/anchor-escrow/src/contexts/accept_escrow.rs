@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::clock;
+use crate::delegation::require_backing;
+use crate::errors::EscrowError;
+use crate::events::{CurrencySelected, PriceRevealed, TakerAssigned};
+use crate::gating::{require_gateway_pass, require_taker_attestations, require_taker_region};
+use crate::states::{
+    AttestationRequirements, Config, Escrow, FiatQuotes, RegionPolicy, TraderStats,
+};
+
+#[derive(Accounts)]
+pub struct AcceptEscrow<'info> {
+    #[account(mut)]
+    pub taker: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        constraint = escrow.load()?.is_funded() @ EscrowError::EscrowNotFunded,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        constraint = escrow.load()?.initializer != taker.key() @ EscrowError::InvalidTaker,
+        constraint = !escrow.load()?.is_bond_pending() @ EscrowError::BondRequired,
+        constraint = escrow.load()?.maker_deposit >= escrow.load()?.required_maker_deposit(config.maker_deposit_bps)? @ EscrowError::MakerDepositRequired,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    // The maker's mint_a ATA, required to show a delegated escrow is still backed
+    pub initializer_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Civic gateway token of the taker, verified when the market is gated
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+    /// CHECK: Blocklist PDA of the taker; must not exist
+    #[account(
+        seeds = [b"blocked", taker.key().as_ref()],
+        bump,
+        constraint = taker_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub taker_block: UncheckedAccount<'info>,
+    #[account(seeds = [b"attestations"], bump = global_attestations.bump)]
+    pub global_attestations: Option<Box<Account<'info, AttestationRequirements>>>,
+    #[account(
+        seeds = [b"attestations", escrow.key().as_ref()],
+        bump = offer_attestations.bump,
+    )]
+    pub offer_attestations: Option<Box<Account<'info, AttestationRequirements>>>,
+    #[account(
+        seeds = [b"regions", escrow.key().as_ref()],
+        bump = region_policy.bump,
+    )]
+    pub region_policy: Option<Box<Account<'info, RegionPolicy>>>,
+    // Required to accept an offer priced in several currencies
+    #[account(
+        seeds = [b"fiat_quotes", escrow.key().as_ref()],
+        bump = fiat_quotes.bump,
+    )]
+    pub fiat_quotes: Option<Box<Account<'info, FiatQuotes>>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TraderStats::SPACE,
+        seeds = [b"trader_stats", taker.key().as_ref()],
+        bump,
+    )]
+    pub taker_stats: Box<Account<'info, TraderStats>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AcceptEscrow<'info> {
+    // Remaining accounts carry the taker's SAS attestations, if any are required
+    pub fn accept_escrow(
+        &mut self,
+        bumps: &AcceptEscrowBumps,
+        attestations: &[AccountInfo],
+    ) -> Result<()> {
+        require!(
+            !self.escrow.load()?.has_hidden_price(),
+            EscrowError::PriceHidden
+        );
+        require!(
+            !self.escrow.load()?.has_flag(Escrow::FLAG_FIAT_QUOTES),
+            EscrowError::CurrencyNotSelected
+        );
+        require_backing(
+            &self.escrow.key(),
+            &*self.escrow.load()?,
+            self.initializer_ata_a.as_deref(),
+        )?;
+        require_gateway_pass(
+            &self.config,
+            self.gateway_token.as_deref(),
+            &self.taker.key(),
+        )?;
+        require_taker_attestations(
+            &self.config,
+            &*self.escrow.load()?,
+            self.global_attestations.as_deref(),
+            self.offer_attestations.as_deref(),
+            attestations,
+            &self.taker.key(),
+        )?;
+        require_taker_region(
+            &*self.escrow.load()?,
+            self.region_policy.as_deref(),
+            attestations,
+            &self.taker.key(),
+        )?;
+        let now = clock::now()?;
+        self.taker_stats
+            .init_if_new(self.taker.key(), bumps.taker_stats);
+        self.taker_stats.record_volume(
+            self.escrow.load()?.initializer_amount,
+            now,
+            &self.config,
+        )?;
+        self.escrow.load_mut()?.assign_taker(self.taker.key(), now);
+
+        emit!(TakerAssigned {
+            escrow: self.escrow.key(),
+            previous_taker: Pubkey::default(),
+            taker: self.taker.key(),
+        });
+        Ok(())
+    }
+
+    // Publishes a hidden price on acceptance, so nobody can see it on the
+    // order book and undercut or copy it before a taker commits
+    pub fn reveal_price(&mut self, taker_amount: u64, salt: [u8; 32]) -> Result<()> {
+        let mut escrow = self.escrow.load_mut()?;
+        require!(escrow.has_hidden_price(), EscrowError::PriceNotHidden);
+        require!(
+            Escrow::hidden_price_commitment(&self.escrow.key(), taker_amount, &salt)
+                == escrow.price_commitment,
+            EscrowError::PriceCommitmentMismatch
+        );
+        escrow.taker_amount = taker_amount;
+        escrow.price_commitment = [0; 32];
+
+        emit!(PriceRevealed {
+            escrow: self.escrow.key(),
+            taker: self.taker.key(),
+            taker_amount,
+        });
+        Ok(())
+    }
+
+    // Fixes the offer's price to the quote for `currency`, in which the taker
+    // will pay
+    pub fn select_currency(&mut self, currency: u16) -> Result<()> {
+        let mut escrow = self.escrow.load_mut()?;
+        require!(
+            escrow.has_flag(Escrow::FLAG_FIAT_QUOTES),
+            EscrowError::CurrencyNotQuoted
+        );
+        let taker_amount = self
+            .fiat_quotes
+            .as_ref()
+            .and_then(|quotes| quotes.price(currency))
+            .ok_or(EscrowError::CurrencyNotQuoted)?;
+        escrow.taker_amount = taker_amount;
+        escrow.fiat_currency = currency;
+        escrow.flags &= !Escrow::FLAG_FIAT_QUOTES;
+
+        emit!(CurrencySelected {
+            escrow: self.escrow.key(),
+            taker: self.taker.key(),
+            currency,
+            taker_amount,
+        });
+        Ok(())
+    }
+}
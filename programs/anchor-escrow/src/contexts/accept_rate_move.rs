@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::RateMoveAccepted;
+use crate::pricing::current_rate;
+use crate::states::{Escrow, RateFeed};
+
+// Renegotiation step for a tripped rate guard: maker and taker co-sign to
+// re-price the escrow at the feed's current rate, having settled any
+// difference between themselves, after which settlement goes through again.
+#[derive(Accounts)]
+pub struct AcceptRateMove<'info> {
+    pub initializer: Signer<'info>,
+    pub taker: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = taker @ EscrowError::InvalidTaker,
+        constraint = escrow.load()?.has_rate_snapshot() @ EscrowError::InvalidRate,
+        constraint = !escrow.load()?.is_frozen() @ EscrowError::EscrowFrozen,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        seeds = [b"rate_feed", escrow.load()?.mint_a.as_ref(), escrow.load()?.mint_b.as_ref()],
+        bump = rate_feed.bump,
+    )]
+    pub rate_feed: Box<Account<'info, RateFeed>>,
+}
+
+impl<'info> AcceptRateMove<'info> {
+    pub fn accept_rate_move(&mut self) -> Result<()> {
+        let rate = current_rate(&self.rate_feed, clock::now()?)?;
+        let mut escrow = self.escrow.load_mut()?;
+        let previous_rate = escrow.rate_snapshot;
+        escrow.rate_snapshot = rate;
+
+        emit!(RateMoveAccepted {
+            escrow: self.escrow.key(),
+            previous_rate,
+            rate,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_MILESTONES;
+use crate::errors::EscrowError;
+use crate::states::{Escrow, Milestone, ReleaseMode};
+
+#[derive(Accounts)]
+pub struct AddMilestone<'info> {
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        constraint = escrow.load()?.is_immediate() || escrow.load()?.is_milestones() @ EscrowError::ReleaseModeMismatch,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        init,
+        payer = payer,
+        space = Milestone::SPACE,
+        seeds = [b"milestone", escrow.key().as_ref(), &[escrow.load()?.milestone_count]],
+        bump
+    )]
+    pub milestone: Account<'info, Milestone>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AddMilestone<'info> {
+    pub fn add_milestone(
+        &mut self,
+        bumps: &AddMilestoneBumps,
+        amount: u64,
+        requires_arbiter: bool,
+    ) -> Result<()> {
+        require!(amount > 0, EscrowError::InvalidAmount);
+
+        let mut escrow = self.escrow.load_mut()?;
+        require!(
+            escrow.milestone_count < MAX_MILESTONES,
+            EscrowError::TooManyMilestones
+        );
+        require!(
+            !requires_arbiter || escrow.has_arbiter(),
+            EscrowError::ArbiterSignatureRequired
+        );
+        let milestone_total = escrow
+            .milestone_total
+            .checked_add(amount)
+            .ok_or(EscrowError::MathOverflow)?;
+        require!(
+            milestone_total <= escrow.initializer_amount,
+            EscrowError::MilestonesExceedEscrow
+        );
+
+        self.milestone.set_inner(Milestone {
+            version: Milestone::VERSION,
+            bump: bumps.milestone,
+            index: escrow.milestone_count,
+            requires_arbiter,
+            confirmed: false,
+            released: false,
+            escrow: self.escrow.key(),
+            amount,
+            disputed: false,
+            refunded: false,
+            _reserved: [0; 30],
+        });
+
+        escrow.release_mode = ReleaseMode::Milestones as u8;
+        escrow.milestone_count += 1;
+        escrow.milestone_total = milestone_total;
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::events::NonceAdvanced;
+use crate::states::SignerNonce;
+
+#[derive(Accounts)]
+pub struct AdvanceNonce<'info> {
+    pub wallet: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = SignerNonce::SPACE,
+        seeds = [b"nonce", wallet.key().as_ref()],
+        bump,
+    )]
+    pub signer_nonce: Box<Account<'info, SignerNonce>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AdvanceNonce<'info> {
+    // Skips the current nonce, voiding every permit or release approval the
+    // wallet signed with it
+    pub fn advance_nonce(&mut self, bumps: &AdvanceNonceBumps) -> Result<()> {
+        self.signer_nonce
+            .init_if_new(self.wallet.key(), bumps.signer_nonce);
+        self.signer_nonce.advance()?;
+
+        emit!(NonceAdvanced {
+            wallet: self.wallet.key(),
+            nonce: self.signer_nonce.nonce,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::MessageRootAnchored;
+use crate::states::{Escrow, MessageLog};
+
+#[derive(Accounts)]
+pub struct AnchorMessageRoot<'info> {
+    // Either the initializer or the taker
+    #[account(mut)]
+    pub party: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        constraint = escrow.load()?.has_taker() @ EscrowError::InvalidTaker,
+        constraint = party.key() == escrow.load()?.initializer || party.key() == escrow.load()?.taker @ EscrowError::NotEscrowParty,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MessageLog::SPACE,
+        seeds = [b"messages", escrow.key().as_ref()],
+        bump,
+    )]
+    pub log: Box<Account<'info, MessageLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> AnchorMessageRoot<'info> {
+    // The transcript only grows, so a root may not cover fewer messages than
+    // the one before it
+    pub fn anchor_message_root(
+        &mut self,
+        bumps: &AnchorMessageRootBumps,
+        root: [u8; 32],
+        message_count: u64,
+    ) -> Result<()> {
+        require!(
+            message_count > 0 && message_count >= self.log.message_count,
+            EscrowError::InvalidMessageRoot
+        );
+        if self.log.version == 0 {
+            self.log.version = MessageLog::VERSION;
+            self.log.bump = bumps.log;
+            self.log.escrow = self.escrow.key();
+        }
+        let now = clock::now()?;
+        self.log.anchor(root, message_count, self.party.key(), now);
+
+        emit!(MessageRootAnchored {
+            escrow: self.escrow.key(),
+            author: self.party.key(),
+            root,
+            message_count,
+            chain_hash: self.log.chain_hash,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::ReleaseApproved;
+use crate::states::{ComplianceApproval, ComplianceDesk, Escrow};
+
+// Signed by the platform's compliance desk or the offer's own compliance key,
+// once per key, after the taker is in. The desk account is only needed when
+// the desk is the one signing.
+#[derive(Accounts)]
+pub struct ApproveRelease<'info> {
+    #[account(mut)]
+    pub approver: Signer<'info>,
+    #[account(
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(seeds = [b"compliance"], bump = compliance.bump)]
+    pub compliance: Option<Box<Account<'info, ComplianceDesk>>>,
+    #[account(
+        init_if_needed,
+        payer = approver,
+        space = ComplianceApproval::SPACE,
+        seeds = [b"compliance_approval", escrow.key().as_ref()],
+        bump,
+    )]
+    pub approval: Box<Account<'info, ComplianceApproval>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ApproveRelease<'info> {
+    pub fn approve_release(&mut self, bumps: &ApproveReleaseBumps) -> Result<()> {
+        let taker = self.escrow.load()?.taker;
+        let offer_signer = self.escrow.load()?.compliance_signer;
+        let approver = self.approver.key();
+        let is_desk = match &self.compliance {
+            Some(compliance) => compliance.signer == approver,
+            None => false,
+        };
+        let is_offer = offer_signer != Pubkey::default() && offer_signer == approver;
+        require!(is_desk || is_offer, EscrowError::NotComplianceSigner);
+
+        // Approvals are for one taker; a reassigned escrow starts over
+        if self.approval.escrow != self.escrow.key() || self.approval.taker != taker {
+            self.approval.set_inner(ComplianceApproval {
+                version: ComplianceApproval::VERSION,
+                bump: bumps.approval,
+                escrow: self.escrow.key(),
+                taker,
+                desk_approved: false,
+                offer_approved: false,
+                approved_at: 0,
+                _reserved: [0; 16],
+            });
+        }
+        self.approval.desk_approved |= is_desk;
+        self.approval.offer_approved |= is_offer;
+        self.approval.approved_at = clock::now()?;
+
+        emit!(ReleaseApproved {
+            escrow: self.escrow.key(),
+            taker,
+            approver,
+            desk: is_desk,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::ArbiterAssigned;
+use crate::states::{ArbitrationPolicy, Arbitrator, Escrow, RegionPolicy};
+
+// `set_arbiter` with the arbiter picked from the registry. Remaining accounts
+// are candidate [b"arbitrator", wallet] profiles; the active one that best
+// matches the trade wins, the earliest on a tie. The trade's region is the
+// offer's region allow list, when it has one. Once the admin has set an
+// arbitration policy, only candidates staking its minimum are eligible.
+#[derive(Accounts)]
+pub struct AssignArbiter<'info> {
+    pub initializer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(seeds = [b"regions", escrow.key().as_ref()], bump = policy.bump)]
+    pub policy: Option<Box<Account<'info, RegionPolicy>>>,
+    /// CHECK: Arbitration policy PDA; may not exist yet
+    #[account(seeds = [b"arbitration"], bump)]
+    pub arbitration: UncheckedAccount<'info>,
+}
+
+impl<'info> AssignArbiter<'info> {
+    // `language` is the ISO 639-1 code the trade is conducted in, zeroes for
+    // no preference
+    pub fn assign_arbiter(
+        &mut self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+        language: [u8; 2],
+    ) -> Result<()> {
+        let regions: &[[u8; 2]] = match &self.policy {
+            Some(policy) if policy.allow_list => &policy.codes[..policy.code_count as usize],
+            _ => &[],
+        };
+
+        let min_stake = if self.arbitration.data_is_empty() {
+            0
+        } else {
+            let data = self.arbitration.try_borrow_data()?;
+            ArbitrationPolicy::try_deserialize(&mut &data[..])?.min_stake
+        };
+
+        let mut best: Option<(Pubkey, u8)> = None;
+        for info in remaining_accounts {
+            let candidate = Account::<Arbitrator>::try_from(info)?;
+            let address = Pubkey::create_program_address(
+                &[b"arbitrator", candidate.wallet.as_ref(), &[candidate.bump]],
+                &crate::ID,
+            )
+            .map_err(|_| EscrowError::InvalidArbiter)?;
+            require_keys_eq!(info.key(), address, EscrowError::InvalidArbiter);
+            if !candidate.is_eligible(min_stake) || candidate.wallet == self.initializer.key() {
+                continue;
+            }
+            let score = candidate.match_score(regions, language);
+            if best.is_none_or(|(_, best_score)| score > best_score) {
+                best = Some((candidate.wallet, score));
+            }
+        }
+        let (arbiter, score) = best.ok_or(EscrowError::NoArbitratorAvailable)?;
+        self.escrow.load_mut()?.arbiter = arbiter;
+
+        emit!(ArbiterAssigned {
+            escrow: self.escrow.key(),
+            arbiter,
+            region_match: score >= Arbitrator::REGION_SCORE,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::VestedClaimed;
+use crate::invariants::require_vault_backs_escrow;
+use crate::states::{Escrow, EscrowStatus, MakerRegistry, TraderStats};
+
+// `claim_vested` without the taker's signature, so automation networks can
+// push unlocked tranches on schedule. Funds only ever move to the taker, and
+// a call with nothing unlocked is a no-op rather than an error.
+#[derive(Accounts)]
+pub struct AutoRelease<'info> {
+    pub taker: SystemAccount<'info>,
+    // Receives the vault and escrow rent once everything has been claimed
+    #[account(mut)]
+    pub initializer: SystemAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program
+    )]
+    pub taker_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = mint_a,
+        has_one = taker @ EscrowError::InvalidTaker,
+        constraint = escrow.load()?.status == EscrowStatus::Releasing as u8 @ EscrowError::EscrowNotReleasing,
+        constraint = escrow.load()?.is_vesting() @ EscrowError::ReleaseModeMismatch,
+        constraint = !escrow.load()?.is_frozen() @ EscrowError::EscrowFrozen,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    // The initializer's profile, so the open-escrow count drops
+    #[account(
+        mut,
+        seeds = [b"trader_stats", escrow.load()?.initializer.as_ref()],
+        bump = initializer_stats.bump,
+    )]
+    pub initializer_stats: Box<Account<'info, TraderStats>>,
+    // The maker's registry, so the escrow drops off it
+    #[account(
+        mut,
+        seeds = [b"registry", escrow.load()?.initializer.as_ref()],
+        bump = maker_registry.bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> AutoRelease<'info> {
+    pub fn auto_release(&mut self) -> Result<()> {
+        let escrow = *self.escrow.load()?;
+        let now = clock::now()?;
+        let signer_seeds: [&[&[u8]]; 1] =
+            [&[b"state", &escrow.seed.to_le_bytes()[..], &[escrow.bump]]];
+
+        // The platform fee came off initializer_amount when vesting started
+        let total = escrow.initializer_amount;
+        let vested = escrow.vested_amount(total, now);
+        let claimable = vested.saturating_sub(escrow.released_amount);
+        if claimable == 0 {
+            return Ok(());
+        }
+
+        transfer_checked(
+            self.into_withdraw_context().with_signer(&signer_seeds),
+            claimable,
+            self.mint_a.decimals,
+        )?;
+
+        let released_amount = escrow.released_amount + claimable;
+        {
+            let mut state = self.escrow.load_mut()?;
+            state.released_amount = released_amount;
+            state.next_action_at = escrow.next_release_at(now);
+            require_vault_backs_escrow(&state, &mut self.vault)?;
+        }
+
+        emit!(VestedClaimed {
+            escrow: self.escrow.key(),
+            taker: self.taker.key(),
+            amount: claimable,
+            released_amount,
+        });
+
+        if released_amount == total {
+            close_account(self.into_close_context().with_signer(&signer_seeds))?;
+            self.escrow.close(self.initializer.to_account_info())?;
+            self.initializer_stats.track_settled();
+            self.maker_registry.remove(&self.escrow.key());
+        }
+        Ok(())
+    }
+
+    fn into_withdraw_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.taker_ata_a.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_close_context(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        let cpi_accounts = CloseAccount {
+            account: self.vault.to_account_info(),
+            destination: self.initializer.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
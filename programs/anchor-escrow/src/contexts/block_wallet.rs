@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::WalletBlocked;
+use crate::states::{AdminAction, AuditLog, BlockedWallet, Config};
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct BlockWallet<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        init,
+        payer = admin,
+        space = BlockedWallet::SPACE,
+        seeds = [b"blocked", wallet.as_ref()],
+        bump,
+    )]
+    pub blocked: Box<Account<'info, BlockedWallet>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> BlockWallet<'info> {
+    pub fn block_wallet(
+        &mut self,
+        bumps: &BlockWalletBumps,
+        wallet: Pubkey,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        let now = clock::now()?;
+        self.blocked.set_inner(BlockedWallet {
+            version: BlockedWallet::VERSION,
+            bump: bumps.blocked,
+            wallet,
+            blocked_at: now,
+            reason_hash,
+            _reserved: [0; 32],
+        });
+
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::BlockWallet,
+            self.admin.key(),
+            wallet,
+            &reason_hash,
+        )?;
+
+        emit!(WalletBlocked {
+            wallet,
+            admin: self.admin.key(),
+            reason_hash,
+        });
+        Ok(())
+    }
+}
@@ -1,69 +1,182 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{
-        close_account, transfer_checked, CloseAccount, Mint, Token, TokenAccount, TransferChecked,
-    },
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
 };
 
-use crate::states::Escrow;
+use crate::constants::PLATFORM_WALLET;
+use crate::errors::EscrowError;
+use crate::events::MakerDepositForfeited;
+use crate::fees::forfeit_creation_fee;
+use crate::states::{Config, Escrow, MakerDeposit, MakerRegistry, MakerSession, TraderStats};
 
 #[derive(Accounts)]
 pub struct Cancel<'info> {
+    // The maker, or a session key acting for them; pays for the stats account
     #[account(mut)]
-    initializer: Signer<'info>,
-    mint_a: Account<'info, Mint>,
+    authority: Signer<'info>,
+    #[account(mut)]
+    initializer: SystemAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    mint_a: InterfaceAccount<'info, Mint>,
     #[account(
         mut,
         associated_token::mint = mint_a,
-        associated_token::authority = initializer
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
+    )]
+    initializer_ata_a: InterfaceAccount<'info, TokenAccount>,
+    // Receives the refund instead when a third party funded the escrow
+    #[account(
+        mut,
+        token::mint = mint_a,
+        constraint = funder_ata_a.owner == escrow.load()?.funder @ EscrowError::InvalidFunder,
     )]
-    initializer_ata_a: Account<'info, TokenAccount>,
+    funder_ata_a: Option<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         has_one = initializer,
         has_one = mint_a,
+        constraint = escrow.load()?.is_funded() @ EscrowError::EscrowNotFunded,
+        constraint = !escrow.load()?.is_frozen() @ EscrowError::EscrowFrozen,
+        constraint = !escrow.load()?.has_yield_position() @ EscrowError::YieldPositionOpen,
+        // The arbiter rules on the vault while a milestone dispute is open
+        constraint = !escrow.load()?.is_dispute_paused() @ EscrowError::DisputeOpen,
         close = initializer,
-        seeds=[b"state", escrow.seed.to_le_bytes().as_ref()],
-        bump = escrow.bump,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
     )]
-    escrow: Account<'info, Escrow>,
+    escrow: AccountLoader<'info, Escrow>,
     #[account(
         mut,
         associated_token::mint = mint_a,
-        associated_token::authority = escrow
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    config: Box<Account<'info, Config>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TraderStats::SPACE,
+        seeds = [b"trader_stats", initializer.key().as_ref()],
+        bump,
     )]
-    pub vault: Account<'info, TokenAccount>,
-    associated_token_program: Program<'info, AssociatedToken>,
-    token_program: Program<'info, Token>,
+    initializer_stats: Box<Account<'info, TraderStats>>,
+    // Required when the escrow is backed by a maker deposit
+    #[account(
+        mut,
+        seeds = [b"maker_deposit", escrow.key().as_ref()],
+        bump = deposit.bump,
+    )]
+    deposit: Option<Box<Account<'info, MakerDeposit>>>,
+    // The signer's session, required unless the initializer signs
+    #[account(
+        seeds = [b"session", initializer.key().as_ref(), authority.key().as_ref()],
+        bump = session.bump,
+    )]
+    session: Option<Box<Account<'info, MakerSession>>>,
+    // The maker's registry, so the escrow drops off it
+    #[account(
+        mut,
+        seeds = [b"registry", initializer.key().as_ref()],
+        bump = maker_registry.bump,
+    )]
+    maker_registry: Box<Account<'info, MakerRegistry>>,
+    /// CHECK: This is the hardcoded platform wallet address, which keeps the
+    /// creation fee of offers that never settle
+    #[account(mut, address = PLATFORM_WALLET)]
+    platform_wallet: UncheckedAccount<'info>,
+    token_program: Interface<'info, TokenInterface>,
     system_program: Program<'info, System>,
 }
 
 impl<'info> Cancel<'info> {
-    pub fn refund_and_close_vault(&mut self) -> Result<()> {
+    pub fn refund_and_close_vault(&mut self, bumps: &CancelBumps) -> Result<()> {
+        MakerSession::authorize(
+            &self.initializer.key(),
+            &self.authority.key(),
+            self.session.as_deref(),
+            MakerSession::SCOPE_CANCEL,
+        )?;
+        let escrow = *self.escrow.load()?;
+        self.initializer_stats
+            .init_if_new(self.initializer.key(), bumps.initializer_stats);
+        self.initializer_stats.track_closed(1);
+        self.maker_registry.remove(&self.escrow.key());
+        if escrow.has_taker_paid() {
+            self.record_cancel_after_confirmation(&escrow)?;
+        }
+
         let signer_seeds: [&[&[u8]]; 1] = [&[
             b"state",
-            &self.escrow.seed.to_le_bytes()[..],
-            &[self.escrow.bump],
+            &escrow.seed.to_le_bytes()[..],
+            &[escrow.bump],
         ]];
 
         transfer_checked(
-            self.into_refund_context().with_signer(&signer_seeds),
-            self.escrow.initializer_amount,
+            self.into_refund_context(&escrow)?.with_signer(&signer_seeds),
+            escrow.initializer_amount,
             self.mint_a.decimals,
         )?;
 
+        if escrow.has_flag(Escrow::FLAG_CREATION_FEE_HELD) {
+            forfeit_creation_fee(
+                &self.escrow.to_account_info(),
+                &self.platform_wallet.to_account_info(),
+            )?;
+        }
+
         close_account(self.into_close_context().with_signer(&signer_seeds))
     }
 
-    fn into_refund_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+    // Backing out on a taker who already paid, even a single tranche, is a
+    // strike; past the configured limit the maker deposit goes to that taker
+    fn record_cancel_after_confirmation(&mut self, escrow: &Escrow) -> Result<()> {
+        self.initializer_stats.cancels_after_confirmation += 1;
+        let strikes = self.initializer_stats.cancels_after_confirmation;
+        let max_strikes = self.config.max_cancel_strikes as u32;
+        if max_strikes == 0 || strikes < max_strikes || escrow.maker_deposit == 0 {
+            return Ok(());
+        }
+
+        let deposit = self
+            .deposit
+            .as_mut()
+            .ok_or(EscrowError::MakerDepositRequired)?;
+        deposit.forfeited = true;
+        deposit.taker = escrow.taker;
+
+        emit!(MakerDepositForfeited {
+            escrow: self.escrow.key(),
+            maker: escrow.initializer,
+            taker: escrow.taker,
+            amount: deposit.amount,
+            strikes,
+        });
+        Ok(())
+    }
+
+    fn into_refund_context(
+        &self,
+        escrow: &Escrow,
+    ) -> Result<CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>> {
+        let to = if escrow.has_funder() {
+            self.funder_ata_a
+                .as_ref()
+                .ok_or(EscrowError::InvalidFunder)?
+                .to_account_info()
+        } else {
+            self.initializer_ata_a.to_account_info()
+        };
         let cpi_accounts = TransferChecked {
             from: self.vault.to_account_info(),
             mint: self.mint_a.to_account_info(),
-            to: self.initializer_ata_a.to_account_info(),
+            to,
             authority: self.escrow.to_account_info(),
         };
-        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+        Ok(CpiContext::new(self.token_program.to_account_info(), cpi_accounts))
     }
 
     fn into_close_context(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
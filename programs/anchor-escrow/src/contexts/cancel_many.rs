@@ -0,0 +1,153 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
+};
+
+use crate::constants::{MAX_BATCH_SIZE, PLATFORM_WALLET};
+use crate::errors::EscrowError;
+use crate::fees::forfeit_creation_fee;
+use crate::states::{Escrow, MakerRegistry, TraderStats};
+
+// Remaining accounts carry one writable (escrow, vault) pair per escrow being
+// cancelled; every escrow must belong to the signer and hold mint_a.
+#[derive(Accounts)]
+pub struct CancelMany<'info> {
+    #[account(mut)]
+    initializer: Signer<'info>,
+    #[account(mint::token_program = token_program)]
+    mint_a: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
+    )]
+    initializer_ata_a: InterfaceAccount<'info, TokenAccount>,
+    // The initializer's profile, so the open-escrow count drops
+    #[account(
+        mut,
+        seeds = [b"trader_stats", initializer.key().as_ref()],
+        bump = initializer_stats.bump,
+    )]
+    initializer_stats: Box<Account<'info, TraderStats>>,
+    // The maker's registry, so the escrows drop off it
+    #[account(
+        mut,
+        seeds = [b"registry", initializer.key().as_ref()],
+        bump = maker_registry.bump,
+    )]
+    maker_registry: Box<Account<'info, MakerRegistry>>,
+    /// CHECK: This is the hardcoded platform wallet address, which keeps the
+    /// creation fee of offers that never settle
+    #[account(mut, address = PLATFORM_WALLET)]
+    platform_wallet: UncheckedAccount<'info>,
+    token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CancelMany<'info> {
+    pub fn refund_and_close_vaults(
+        &mut self,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        let count = remaining_accounts.len() / 2;
+        require!(
+            count > 0 && count <= MAX_BATCH_SIZE,
+            EscrowError::InvalidBatchSize
+        );
+        require!(
+            remaining_accounts.len() == count * 2,
+            EscrowError::BatchAccountsMismatch
+        );
+
+        for accounts in remaining_accounts.chunks(2) {
+            self.refund_and_close(&accounts[0], &accounts[1])?;
+            self.maker_registry.remove(accounts[0].key);
+        }
+        self.initializer_stats.track_closed(count as u32);
+        Ok(())
+    }
+
+    fn refund_and_close(
+        &self,
+        escrow_info: &'info AccountInfo<'info>,
+        vault_info: &'info AccountInfo<'info>,
+    ) -> Result<()> {
+        let loader = AccountLoader::<Escrow>::try_from(escrow_info)?;
+        let escrow = *loader.load()?;
+        require_keys_eq!(
+            escrow.initializer,
+            self.initializer.key(),
+            EscrowError::BatchAccountsMismatch
+        );
+        require_keys_eq!(
+            escrow.mint_a,
+            self.mint_a.key(),
+            EscrowError::BatchAccountsMismatch
+        );
+        require!(escrow.is_funded(), EscrowError::EscrowNotFunded);
+        require!(!escrow.is_frozen(), EscrowError::EscrowFrozen);
+        require!(!escrow.has_yield_position(), EscrowError::YieldPositionOpen);
+        require!(!escrow.is_dispute_paused(), EscrowError::DisputeOpen);
+        // The batch refunds to the initializer only; `cancel` handles funders
+        require!(!escrow.has_funder(), EscrowError::InvalidFunder);
+        // Strikes and deposit forfeiture are only tracked by `cancel`
+        require!(
+            !escrow.has_taker_paid(),
+            EscrowError::CancelAfterConfirmation
+        );
+
+        let seed_bytes = escrow.seed.to_le_bytes();
+        let address = Pubkey::create_program_address(
+            &[b"state", seed_bytes.as_ref(), &[escrow.bump]],
+            &crate::ID,
+        )
+        .map_err(|_| EscrowError::InvalidEscrowAddress)?;
+        require_keys_eq!(loader.key(), address, EscrowError::InvalidEscrowAddress);
+
+        require_keys_eq!(
+            vault_info.key(),
+            get_associated_token_address_with_program_id(
+                &address,
+                &escrow.mint_a,
+                &self.token_program.key()
+            ),
+            EscrowError::BatchAccountsMismatch
+        );
+
+        let signer_seeds: [&[&[u8]]; 1] = [&[b"state", &seed_bytes[..], &[escrow.bump]]];
+
+        transfer_checked(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                TransferChecked {
+                    from: vault_info.clone(),
+                    mint: self.mint_a.to_account_info(),
+                    to: self.initializer_ata_a.to_account_info(),
+                    authority: escrow_info.clone(),
+                },
+                &signer_seeds,
+            ),
+            escrow.initializer_amount,
+            self.mint_a.decimals,
+        )?;
+
+        close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: vault_info.clone(),
+                destination: self.initializer.to_account_info(),
+                authority: escrow_info.clone(),
+            },
+            &signer_seeds,
+        ))?;
+
+        if escrow.has_flag(Escrow::FLAG_CREATION_FEE_HELD) {
+            forfeit_creation_fee(escrow_info, &self.platform_wallet.to_account_info())?;
+        }
+        loader.close(self.initializer.to_account_info())
+    }
+}
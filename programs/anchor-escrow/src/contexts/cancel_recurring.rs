@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::events::RecurringScheduleCancelled;
+use crate::states::RecurringSchedule;
+
+// Refunds the inventory of the runs left and closes the schedule; escrows it
+// already posted carry on as usual
+#[derive(Accounts)]
+pub struct CancelRecurring<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    #[account(
+        mut,
+        has_one = maker,
+        has_one = mint_a,
+        close = maker,
+        seeds = [b"recurring", maker.key().as_ref(), &schedule.schedule_id.to_le_bytes()],
+        bump = schedule.bump,
+    )]
+    pub schedule: Box<Account<'info, RecurringSchedule>>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program
+    )]
+    pub maker_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = schedule,
+        associated_token::token_program = token_program
+    )]
+    pub schedule_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CancelRecurring<'info> {
+    pub fn cancel_recurring(&mut self) -> Result<()> {
+        let schedule = &self.schedule;
+        let signer_seeds: [&[&[u8]]; 1] = [&[
+            b"recurring",
+            schedule.maker.as_ref(),
+            &schedule.schedule_id.to_le_bytes(),
+            &[schedule.bump],
+        ]];
+
+        let refunded = self.schedule_vault.amount;
+        if refunded > 0 {
+            transfer_checked(
+                self.into_refund_context().with_signer(&signer_seeds),
+                refunded,
+                self.mint_a.decimals,
+            )?;
+        }
+        close_account(self.into_close_context().with_signer(&signer_seeds))?;
+
+        emit!(RecurringScheduleCancelled {
+            schedule: self.schedule.key(),
+            runs_done: self.schedule.runs_done,
+            refunded,
+        });
+        Ok(())
+    }
+
+    fn into_refund_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.schedule_vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.maker_ata_a.to_account_info(),
+            authority: self.schedule.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_close_context(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        let cpi_accounts = CloseAccount {
+            account: self.schedule_vault.to_account_info(),
+            destination: self.maker.to_account_info(),
+            authority: self.schedule.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::errors::EscrowError;
+use crate::events::ArbitrationRewardsClaimed;
+use crate::states::{ArbitrationPolicy, Arbitrator};
+
+#[derive(Accounts)]
+pub struct ClaimArbitrationRewards<'info> {
+    pub wallet: Signer<'info>,
+    #[account(
+        mut,
+        has_one = wallet,
+        seeds = [b"arbitrator", wallet.key().as_ref()],
+        bump = arbitrator.bump,
+    )]
+    pub arbitrator: Box<Account<'info, Arbitrator>>,
+    #[account(seeds = [b"arbitration"], bump = arbitration.bump)]
+    pub arbitration: Box<Account<'info, ArbitrationPolicy>>,
+    #[account(
+        address = arbitration.stake_mint @ EscrowError::InvalidStakeMint,
+        mint::token_program = token_program
+    )]
+    pub stake_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = arbitration,
+        associated_token::token_program = token_program
+    )]
+    pub reward_pool: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = wallet,
+        associated_token::token_program = token_program
+    )]
+    pub wallet_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClaimArbitrationRewards<'info> {
+    // Pays what the pool can cover; the rest stays owed until it is topped up
+    pub fn claim_arbitration_rewards(&mut self) -> Result<()> {
+        let amount = self.arbitrator.pending_rewards.min(self.reward_pool.amount);
+        require!(amount > 0, EscrowError::NothingToClaim);
+
+        let signer_seeds: [&[&[u8]]; 1] = [&[b"arbitration", &[self.arbitration.bump]]];
+        transfer_checked(
+            self.into_claim_context().with_signer(&signer_seeds),
+            amount,
+            self.stake_mint.decimals,
+        )?;
+        self.arbitrator.pending_rewards -= amount;
+
+        emit!(ArbitrationRewardsClaimed {
+            arbitrator: self.wallet.key(),
+            amount,
+        });
+        Ok(())
+    }
+
+    fn into_claim_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.reward_pool.to_account_info(),
+            mint: self.stake_mint.to_account_info(),
+            to: self.wallet_ata.to_account_info(),
+            authority: self.arbitration.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
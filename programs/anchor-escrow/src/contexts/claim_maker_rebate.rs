@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::MakerRebateClaimed;
+use crate::states::MakerRebate;
+
+#[derive(Accounts)]
+pub struct ClaimMakerRebate<'info> {
+    pub maker: Signer<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        has_one = maker,
+        has_one = mint,
+        seeds = [b"maker_rebate", maker.key().as_ref(), mint.key().as_ref()],
+        bump = maker_rebate.bump,
+    )]
+    pub maker_rebate: Box<Account<'info, MakerRebate>>,
+    /// CHECK: Data-less PDA that owns the rebate pool token accounts
+    #[account(seeds = [b"rebate_pool"], bump)]
+    pub rebate_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = rebate_pool,
+        associated_token::token_program = token_program
+    )]
+    pub rebate_pool_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = maker,
+        token::token_program = token_program
+    )]
+    pub maker_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClaimMakerRebate<'info> {
+    pub fn claim_maker_rebate(&mut self, bumps: &ClaimMakerRebateBumps) -> Result<()> {
+        let amount = self.maker_rebate.take_accrued(clock::now()?)?;
+        require!(amount > 0, EscrowError::NoRebateAccrued);
+
+        let signer_seeds: [&[&[u8]]; 1] = [&[b"rebate_pool", &[bumps.rebate_pool]]];
+        transfer_checked(
+            self.into_claim_context().with_signer(&signer_seeds),
+            amount,
+            self.mint.decimals,
+        )?;
+
+        emit!(MakerRebateClaimed {
+            maker: self.maker.key(),
+            mint: self.mint.key(),
+            amount,
+        });
+        Ok(())
+    }
+
+    fn into_claim_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.rebate_pool_ata.to_account_info(),
+            mint: self.mint.to_account_info(),
+            to: self.maker_ata.to_account_info(),
+            authority: self.rebate_pool.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::PartnerFeesClaimed;
+use crate::states::{PartnerAccrual, PartnerConfig};
+
+#[derive(Accounts)]
+pub struct ClaimPartnerFees<'info> {
+    pub fee_wallet: Signer<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        has_one = fee_wallet,
+        seeds = [b"partner", partner_config.partner_id.to_le_bytes().as_ref()],
+        bump = partner_config.bump,
+    )]
+    pub partner_config: Box<Account<'info, PartnerConfig>>,
+    #[account(
+        mut,
+        has_one = mint,
+        seeds = [
+            b"partner_accrual",
+            partner_config.partner_id.to_le_bytes().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump = partner_accrual.bump,
+    )]
+    pub partner_accrual: Box<Account<'info, PartnerAccrual>>,
+    /// CHECK: Data-less PDA that owns the partner pool token accounts
+    #[account(seeds = [b"partner_pool"], bump)]
+    pub partner_pool: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = partner_pool,
+        associated_token::token_program = token_program
+    )]
+    pub partner_pool_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = fee_wallet,
+        token::token_program = token_program
+    )]
+    pub fee_wallet_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClaimPartnerFees<'info> {
+    pub fn claim_partner_fees(&mut self, bumps: &ClaimPartnerFeesBumps) -> Result<()> {
+        let amount = self.partner_accrual.take_accrued(clock::now()?)?;
+        require!(amount > 0, EscrowError::NoPartnerFeesAccrued);
+
+        let signer_seeds: [&[&[u8]]; 1] = [&[b"partner_pool", &[bumps.partner_pool]]];
+        transfer_checked(
+            self.into_claim_context().with_signer(&signer_seeds),
+            amount,
+            self.mint.decimals,
+        )?;
+
+        emit!(PartnerFeesClaimed {
+            partner_id: self.partner_config.partner_id,
+            fee_wallet: self.fee_wallet.key(),
+            mint: self.mint.key(),
+            amount,
+        });
+        Ok(())
+    }
+
+    fn into_claim_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.partner_pool_ata.to_account_info(),
+            mint: self.mint.to_account_info(),
+            to: self.fee_wallet_ata.to_account_info(),
+            authority: self.partner_pool.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -0,0 +1,266 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::clock;
+use crate::constants::PLATFORM_WALLET;
+use crate::errors::EscrowError;
+use crate::events::{InsuranceFunded, StreamClaimed};
+use crate::fees::{FeeBreakdown, SettlementSplit};
+use crate::invariants::require_vault_backs_escrow;
+use crate::payout::{pay_partner, PartnerAccounts, VaultAccounts};
+use crate::states::{
+    Config, Escrow, EscrowStatus, MakerRegistry, PartnerAccrual, PartnerConfig, TraderStats,
+};
+
+#[derive(Accounts)]
+pub struct ClaimStream<'info> {
+    // Pays for the partner's accrual when a claim opens it
+    #[account(mut)]
+    pub taker: Signer<'info>,
+    // Receives the vault and escrow rent once the stream is fully drawn
+    #[account(mut)]
+    pub initializer: SystemAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program
+    )]
+    pub taker_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = platform_wallet,
+        associated_token::token_program = token_program
+    )]
+    pub platform_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = mint_a,
+        has_one = taker @ EscrowError::InvalidTaker,
+        constraint = escrow.load()?.is_streaming() @ EscrowError::ReleaseModeMismatch,
+        constraint = escrow.load()?.status == EscrowStatus::Releasing as u8 @ EscrowError::EscrowNotReleasing,
+        constraint = !escrow.load()?.is_frozen() @ EscrowError::EscrowFrozen,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Data-less PDA that owns the insurance fund token accounts
+    #[account(seeds = [b"insurance"], bump = config.insurance_bump)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program
+    )]
+    pub insurance_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    // The initializer's profile, so the open-escrow count drops
+    #[account(
+        mut,
+        seeds = [b"trader_stats", escrow.load()?.initializer.as_ref()],
+        bump = initializer_stats.bump,
+    )]
+    pub initializer_stats: Box<Account<'info, TraderStats>>,
+    // The maker's registry, so the escrow drops off it
+    #[account(
+        mut,
+        seeds = [b"registry", escrow.load()?.initializer.as_ref()],
+        bump = maker_registry.bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    // The partner the offer was created through and the partner fee wallet's
+    // mint_a account, required when the escrow has a partner
+    #[account(
+        seeds = [b"partner", escrow.load()?.partner_id.to_le_bytes().as_ref()],
+        bump = partner_config.bump,
+    )]
+    pub partner_config: Option<Box<Account<'info, PartnerConfig>>>,
+    #[account(mut, token::mint = mint_a)]
+    pub partner_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    // Partner pool and the partner's accrual, required while the partner
+    // takes a share of the platform fee
+    /// CHECK: Data-less PDA that owns the partner pool token accounts
+    #[account(seeds = [b"partner_pool"], bump)]
+    pub partner_pool: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = partner_pool,
+        associated_token::token_program = token_program
+    )]
+    pub partner_pool_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        init_if_needed,
+        payer = taker,
+        space = PartnerAccrual::SPACE,
+        seeds = [
+            b"partner_accrual",
+            escrow.load()?.partner_id.to_le_bytes().as_ref(),
+            mint_a.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub partner_accrual: Option<Box<Account<'info, PartnerAccrual>>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimStream<'info> {
+    pub fn claim_stream(&mut self, bumps: &ClaimStreamBumps) -> Result<()> {
+        let escrow = *self.escrow.load()?;
+        let signer_seeds: [&[&[u8]]; 1] =
+            [&[b"state", &escrow.seed.to_le_bytes()[..], &[escrow.bump]]];
+
+        // Fees are charged on the cumulative gross so the per-claim split
+        // adds up exactly to the fee on the whole amount
+        let now = clock::now()?;
+        let streamed = escrow.vested_amount(escrow.initializer_amount, now);
+        require!(
+            streamed > escrow.released_amount,
+            EscrowError::NothingToClaim
+        );
+        let platform_fee =
+            FeeBreakdown::for_settlement(&self.config, escrow.category, streamed, now)?
+                .platform_fee
+                - FeeBreakdown::for_settlement(
+                    &self.config,
+                    escrow.category,
+                    escrow.released_amount,
+                    now,
+                )?
+                .platform_fee;
+        let amount = streamed - escrow.released_amount - platform_fee;
+
+        // The insurance fund's slice comes out of the platform fee
+        let mut split = SettlementSplit::for_payout(&self.config, platform_fee, amount)?;
+        if escrow.has_partner() {
+            let vault = VaultAccounts {
+                escrow: &self.escrow.to_account_info(),
+                vault: &self.vault.to_account_info(),
+                mint: &self.mint_a.to_account_info(),
+                decimals: self.mint_a.decimals,
+                token_program: &self.token_program.to_account_info(),
+            };
+            let partner = PartnerAccounts {
+                config: self.partner_config.as_deref(),
+                fee_ata: self.partner_ata_a.as_deref(),
+                pool_ata: self.partner_pool_ata_a.as_deref(),
+                accrual: self.partner_accrual.as_deref_mut(),
+                accrual_bump: bumps.partner_accrual,
+            };
+            split = split.with_partner(partner.config()?)?;
+            pay_partner(&vault, partner, &split, now, &signer_seeds)?;
+        }
+        let SettlementSplit {
+            platform_share,
+            insurance_share,
+            buyer_amount,
+            ..
+        } = split;
+        if insurance_share > 0 {
+            transfer_checked(
+                self.into_insurance_fee_context().with_signer(&signer_seeds),
+                insurance_share,
+                self.mint_a.decimals,
+            )?;
+            emit!(InsuranceFunded {
+                escrow: self.escrow.key(),
+                mint: self.mint_a.key(),
+                amount: insurance_share,
+            });
+        }
+        if platform_share > 0 {
+            transfer_checked(
+                self.into_platform_fee_context().with_signer(&signer_seeds),
+                platform_share,
+                self.mint_a.decimals,
+            )?;
+        }
+        if buyer_amount > 0 {
+            transfer_checked(
+                self.into_withdraw_context().with_signer(&signer_seeds),
+                buyer_amount,
+                self.mint_a.decimals,
+            )?;
+        }
+        {
+            let mut state = self.escrow.load_mut()?;
+            state.released_amount = streamed;
+            require_vault_backs_escrow(&state, &mut self.vault)?;
+        }
+
+        emit!(StreamClaimed {
+            escrow: self.escrow.key(),
+            taker: self.taker.key(),
+            amount: buyer_amount,
+            platform_fee,
+            released_amount: streamed,
+        });
+
+        if streamed == escrow.initializer_amount {
+            close_account(self.into_close_context().with_signer(&signer_seeds))?;
+            self.escrow.close(self.initializer.to_account_info())?;
+            self.initializer_stats.track_settled();
+            self.maker_registry.remove(&self.escrow.key());
+        }
+        Ok(())
+    }
+
+    fn into_withdraw_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.taker_ata_a.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_platform_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.platform_ata_a.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_insurance_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.insurance_ata_a.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_close_context(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        let cpi_accounts = CloseAccount {
+            account: self.vault.to_account_info(),
+            destination: self.initializer.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
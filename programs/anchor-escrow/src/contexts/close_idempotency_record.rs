@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::constants::IDEMPOTENCY_KEY_TTL;
+use crate::errors::EscrowError;
+use crate::events::IdempotencyRecordClosed;
+use crate::states::IdempotencyRecord;
+
+// Permissionless once the key has outlived any retry, refunding whoever paid
+// the rent
+#[derive(Accounts)]
+pub struct CloseIdempotencyRecord<'info> {
+    pub cranker: Signer<'info>,
+    /// CHECK: Only receives the rent back
+    #[account(mut, address = record.rent_payer)]
+    pub rent_payer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = clock::now()? >= record.created_at + IDEMPOTENCY_KEY_TTL @ EscrowError::IdempotencyKeyLive,
+        close = rent_payer,
+        seeds = [b"idempotency", record.maker.as_ref(), record.idempotency_key.as_ref()],
+        bump = record.bump,
+    )]
+    pub record: Box<Account<'info, IdempotencyRecord>>,
+}
+
+impl<'info> CloseIdempotencyRecord<'info> {
+    pub fn close_idempotency_record(&mut self) -> Result<()> {
+        emit!(IdempotencyRecordClosed {
+            maker: self.record.maker,
+            escrow: self.record.escrow,
+            closed_by: self.cranker.key(),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use anchor_spl::{
+    associated_token::get_associated_token_address_with_program_id,
+    token_interface::{
+        close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+        TransferChecked,
+    },
+};
+
+use crate::audit::record_admin_action;
+use crate::errors::EscrowError;
+use crate::events::OrphanClosed;
+use crate::states::{AdminAction, AuditLog, Config, Escrow, EscrowStatus};
+
+// Admin recovery for flows that failed halfway in older program versions,
+// leaving escrow `seed` with only one of its two accounts:
+// - a vault whose escrow state is gone: nothing on-chain names the owner any
+//   more, so the admin routes the tokens and rent from the escrow's history
+// - an escrow past funding whose vault is gone: the state is closed to its
+//   initializer
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct CloseOrphanedVault<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: May be closed already; inspected in the handler
+    #[account(mut, seeds = [b"state".as_ref(), &seed.to_le_bytes()], bump)]
+    pub escrow: UncheckedAccount<'info>,
+    /// CHECK: The escrow's mint_a ATA, checked against its address; may be closed already
+    #[account(mut)]
+    pub vault: UncheckedAccount<'info>,
+    // Receives an orphaned vault's tokens
+    #[account(
+        mut,
+        token::mint = mint_a,
+        token::token_program = token_program
+    )]
+    pub recipient_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    // Receives the rent of whichever account is closed; the initializer for an orphaned escrow
+    #[account(mut)]
+    pub rent_recipient: SystemAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseOrphanedVault<'info> {
+    pub fn close_orphaned_vault(&mut self, bumps: &CloseOrphanedVaultBumps, seed: u64) -> Result<()> {
+        require_keys_eq!(
+            self.vault.key(),
+            get_associated_token_address_with_program_id(
+                &self.escrow.key(),
+                &self.mint_a.key(),
+                &self.token_program.key()
+            ),
+            EscrowError::InvalidOrphan
+        );
+
+        let (target, amount) = match (self.escrow.data_is_empty(), self.vault.data_is_empty()) {
+            (true, false) => (self.vault.key(), self.close_vault(bumps, seed)?),
+            (false, true) => (self.escrow.key(), self.close_escrow()?),
+            _ => return err!(EscrowError::InvalidOrphan),
+        };
+
+        let recipient = self
+            .recipient_ata
+            .as_ref()
+            .map(|ata| ata.owner)
+            .unwrap_or_default();
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::CloseOrphanedVault,
+            self.admin.key(),
+            target,
+            &(seed, recipient, self.rent_recipient.key(), amount),
+        )?;
+
+        emit!(OrphanClosed {
+            seed,
+            account: target,
+            recipient,
+            rent_recipient: self.rent_recipient.key(),
+            amount,
+        });
+        Ok(())
+    }
+
+    // Sweeps and closes a vault left behind by a closed escrow, returning the amount swept
+    fn close_vault(&self, bumps: &CloseOrphanedVaultBumps, seed: u64) -> Result<u64> {
+        let amount = {
+            let data = self.vault.try_borrow_data()?;
+            TokenAccount::try_deserialize(&mut &data[..])?.amount
+        };
+        let seed_bytes = seed.to_le_bytes();
+        let signer_seeds: [&[&[u8]]; 1] = [&[b"state", &seed_bytes[..], &[bumps.escrow]]];
+
+        if amount > 0 {
+            let recipient = self
+                .recipient_ata
+                .as_ref()
+                .ok_or(EscrowError::InvalidOrphan)?;
+            transfer_checked(
+                CpiContext::new_with_signer(
+                    self.token_program.to_account_info(),
+                    TransferChecked {
+                        from: self.vault.to_account_info(),
+                        mint: self.mint_a.to_account_info(),
+                        to: recipient.to_account_info(),
+                        authority: self.escrow.to_account_info(),
+                    },
+                    &signer_seeds,
+                ),
+                amount,
+                self.mint_a.decimals,
+            )?;
+        }
+        close_account(CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            CloseAccount {
+                account: self.vault.to_account_info(),
+                destination: self.rent_recipient.to_account_info(),
+                authority: self.escrow.to_account_info(),
+            },
+            &signer_seeds,
+        ))?;
+        Ok(amount)
+    }
+
+    // Closes escrow state whose vault no longer exists; nothing is left to pay out
+    fn close_escrow(&self) -> Result<u64> {
+        let info = self.escrow.to_account_info();
+        {
+            let data = info.try_borrow_data()?;
+            require!(
+                *info.owner == crate::ID
+                    && data.len() == Escrow::SPACE
+                    && data[..8] == *Escrow::DISCRIMINATOR,
+                EscrowError::UnknownEscrowLayout
+            );
+            let escrow = bytemuck::from_bytes::<Escrow>(&data[8..]);
+            // Escrows that were never funded or are delegated have no vault by design
+            require!(
+                escrow.status != EscrowStatus::Created as u8 && !escrow.is_delegated(),
+                EscrowError::InvalidOrphan
+            );
+            require_keys_eq!(
+                self.rent_recipient.key(),
+                escrow.initializer,
+                EscrowError::InvalidOrphan
+            );
+        }
+
+        let lamports = info.lamports();
+        **self.rent_recipient.try_borrow_mut_lamports()? += lamports;
+        **info.try_borrow_mut_lamports()? = 0;
+        info.assign(&system_program::ID);
+        info.resize(0)?;
+        Ok(0)
+    }
+}
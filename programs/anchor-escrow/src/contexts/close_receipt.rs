@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::ReceiptClosed;
+use crate::states::Receipt;
+
+#[derive(Accounts)]
+pub struct CloseReceipt<'info> {
+    // The maker or the taker of the trade
+    pub party: Signer<'info>,
+    /// CHECK: Only receives the rent back
+    #[account(mut, address = receipt.rent_payer)]
+    pub rent_payer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        constraint = receipt.initializer == party.key() || receipt.taker == party.key() @ EscrowError::NotTradeParty,
+        close = rent_payer,
+        seeds = [
+            b"receipt",
+            receipt.escrow.as_ref(),
+            receipt.payment_confirmed_at.to_le_bytes().as_ref(),
+        ],
+        bump = receipt.bump,
+    )]
+    pub receipt: Box<Account<'info, Receipt>>,
+}
+
+impl<'info> CloseReceipt<'info> {
+    pub fn close_receipt(&mut self) -> Result<()> {
+        emit!(ReceiptClosed {
+            receipt: self.receipt.key(),
+            escrow: self.receipt.escrow,
+            closed_by: self.party.key(),
+        });
+        Ok(())
+    }
+}
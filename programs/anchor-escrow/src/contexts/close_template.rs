@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::states::OfferTemplate;
+
+#[derive(Accounts)]
+pub struct CloseTemplate<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    #[account(
+        mut,
+        has_one = maker,
+        close = maker,
+        seeds = [b"template", maker.key().as_ref(), &template.template_id.to_le_bytes()],
+        bump = template.bump,
+    )]
+    pub template: Box<Account<'info, OfferTemplate>>,
+}
+
+impl<'info> CloseTemplate<'info> {
+    // Escrows already posted from the template are unaffected
+    pub fn close_template(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
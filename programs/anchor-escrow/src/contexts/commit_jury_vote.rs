@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::JuryVoteCommitted;
+use crate::states::{Jury, JuryVote};
+
+#[derive(Accounts)]
+pub struct CommitJuryVote<'info> {
+    #[account(mut)]
+    pub juror: Signer<'info>,
+    #[account(
+        constraint = jury.is_juror(&juror.key()) @ EscrowError::NotAJuror,
+        seeds = [b"jury", jury.dispute.as_ref()],
+        bump = jury.bump,
+    )]
+    pub jury: Box<Account<'info, Jury>>,
+    #[account(
+        init,
+        payer = juror,
+        space = JuryVote::SPACE,
+        seeds = [b"jury_vote", jury.key().as_ref(), juror.key().as_ref()],
+        bump,
+    )]
+    pub vote: Box<Account<'info, JuryVote>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CommitJuryVote<'info> {
+    // `commitment` is JuryVote::commitment of the vote and a secret salt
+    pub fn commit_jury_vote(
+        &mut self,
+        bumps: &CommitJuryVoteBumps,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        let now = clock::now()?;
+        require!(self.jury.is_committing(now), EscrowError::JuryPhaseClosed);
+        self.vote.set_inner(JuryVote {
+            version: JuryVote::VERSION,
+            bump: bumps.vote,
+            revealed: false,
+            release_to_taker: false,
+            penalized: false,
+            jury: self.jury.key(),
+            juror: self.juror.key(),
+            commitment,
+            committed_at: now,
+        });
+
+        emit!(JuryVoteCommitted {
+            jury: self.jury.key(),
+            juror: self.juror.key(),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::states::{Escrow, Milestone};
+
+// The taker signals the milestone is delivered and asks for its tranche
+#[derive(Accounts)]
+pub struct ConfirmMilestone<'info> {
+    pub taker: Signer<'info>,
+    #[account(
+        has_one = taker @ EscrowError::InvalidTaker,
+        constraint = escrow.load()?.is_milestones() @ EscrowError::ReleaseModeMismatch,
+        constraint = escrow.load()?.milestone_total == escrow.load()?.initializer_amount @ EscrowError::MilestonesIncomplete,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        mut,
+        has_one = escrow,
+        constraint = !milestone.confirmed @ EscrowError::MilestoneAlreadyConfirmed,
+        constraint = !milestone.is_settled() @ EscrowError::MilestoneAlreadyReleased,
+        seeds = [b"milestone", escrow.key().as_ref(), &[milestone.index]],
+        bump = milestone.bump,
+    )]
+    pub milestone: Account<'info, Milestone>,
+}
+
+impl<'info> ConfirmMilestone<'info> {
+    pub fn confirm_milestone(&mut self) -> Result<()> {
+        self.milestone.confirmed = true;
+        Ok(())
+    }
+}
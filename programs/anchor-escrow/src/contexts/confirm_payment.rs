@@ -1,23 +1,200 @@
 use anchor_lang::prelude::*;
-use crate::states::Escrow;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token_interface::TokenAccount;
+use crate::clock;
+use crate::constants::MAX_PAYMENT_REFERENCE_LEN;
+use crate::delegation::require_backing;
+use crate::errors::EscrowError;
+use crate::events::{PartialPaymentConfirmed, PaymentReferenceConfirmed};
+use crate::gating::{require_gateway_pass, require_taker_attestations, require_taker_region};
+use crate::memo::require_memo;
+use crate::states::{AttestationRequirements, Config, Escrow, RegionPolicy, TraderStats};
 
 #[derive(Accounts)]
 pub struct ConfirmPayment<'info> {
     #[account(mut)]
     pub taker: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
     #[account(
         mut,
         has_one = mint_a,
-        seeds=[b"state", escrow.seed.to_le_bytes().as_ref()],
-        bump = escrow.bump,
+        constraint = escrow.load()?.is_funded() @ EscrowError::EscrowNotFunded,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.has_taker() || escrow.load()?.taker == taker.key() @ EscrowError::InvalidTaker,
+        constraint = !escrow.load()?.is_bond_pending() @ EscrowError::BondNotPosted,
+        constraint = !escrow.load()?.has_hidden_price() @ EscrowError::PriceHidden,
+        constraint = escrow.load()?.has_taker() || !escrow.load()?.has_flag(Escrow::FLAG_FIAT_QUOTES) @ EscrowError::CurrencyNotSelected,
+        // Confirming an open offer takes it, so the maker deposit must be in place
+        constraint = escrow.load()?.has_taker() || escrow.load()?.maker_deposit >= escrow.load()?.required_maker_deposit(config.maker_deposit_bps)? @ EscrowError::MakerDepositRequired,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    // The maker's mint_a ATA, required to show a delegated escrow is still backed
+    pub initializer_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub mint_a: InterfaceAccount<'info, anchor_spl::token_interface::Mint>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Civic gateway token of the taker, verified when the market is gated
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+    /// CHECK: Blocklist PDA of the taker; must not exist
+    #[account(
+        seeds = [b"blocked", taker.key().as_ref()],
+        bump,
+        constraint = taker_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub taker_block: UncheckedAccount<'info>,
+    #[account(seeds = [b"attestations"], bump = global_attestations.bump)]
+    pub global_attestations: Option<Box<Account<'info, AttestationRequirements>>>,
+    #[account(
+        seeds = [b"attestations", escrow.key().as_ref()],
+        bump = offer_attestations.bump,
+    )]
+    pub offer_attestations: Option<Box<Account<'info, AttestationRequirements>>>,
+    #[account(
+        seeds = [b"regions", escrow.key().as_ref()],
+        bump = region_policy.bump,
+    )]
+    pub region_policy: Option<Box<Account<'info, RegionPolicy>>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TraderStats::SPACE,
+        seeds = [b"trader_stats", taker.key().as_ref()],
+        bump,
     )]
-    pub escrow: Account<'info, Escrow>,
-    pub mint_a: Account<'info, anchor_spl::token::Mint>,
+    pub taker_stats: Box<Account<'info, TraderStats>>,
+    /// CHECK: Instructions sysvar, only read when confirming with a payment reference
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+    pub system_program: Program<'info, System>,
 }
 
 impl<'info> ConfirmPayment<'info> {
-    pub fn confirm_payment(&mut self) -> Result<()> {
-        self.escrow.payment_confirmed = true;
+    // Remaining accounts carry the taker's SAS attestations, if any are required
+    pub fn confirm_payment(
+        &mut self,
+        bumps: &ConfirmPaymentBumps,
+        attestations: &[AccountInfo],
+    ) -> Result<()> {
+        self.confirm(bumps, attestations, None, false)
+    }
+
+    // Full confirmation carrying the bank `reference`, which a Memo
+    // instruction earlier in the transaction must repeat; the only way to
+    // confirm offers that require a payment memo
+    pub fn confirm_payment_with_reference(
+        &mut self,
+        bumps: &ConfirmPaymentBumps,
+        attestations: &[AccountInfo],
+        reference: String,
+    ) -> Result<()> {
+        require!(
+            !reference.is_empty() && reference.len() <= MAX_PAYMENT_REFERENCE_LEN,
+            EscrowError::InvalidPaymentReference
+        );
+        let instructions = self
+            .instructions
+            .as_ref()
+            .ok_or(EscrowError::PaymentMemoRequired)?;
+        require_memo(instructions, reference.as_bytes())?;
+        self.confirm(bumps, attestations, None, true)?;
+
+        emit!(PaymentReferenceConfirmed {
+            escrow: self.escrow.key(),
+            taker: self.taker.key(),
+            reference,
+        });
+        Ok(())
+    }
+
+    // Confirms `amount` of fiat toward `taker_amount`; the last tranche
+    // confirms the payment as a whole
+    pub fn confirm_partial_payment(
+        &mut self,
+        bumps: &ConfirmPaymentBumps,
+        attestations: &[AccountInfo],
+        amount: u64,
+    ) -> Result<()> {
+        self.confirm(bumps, attestations, Some(amount), false)
+    }
+
+    fn confirm(
+        &mut self,
+        bumps: &ConfirmPaymentBumps,
+        attestations: &[AccountInfo],
+        tranche: Option<u64>,
+        memo_checked: bool,
+    ) -> Result<()> {
+        let now = clock::now()?;
+        let mut escrow = self.escrow.load_mut()?;
+        // A maker taking their own offer would fake volume and reputation
+        require_keys_neq!(escrow.initializer, self.taker.key(), EscrowError::SelfTrade);
+        require!(
+            memo_checked || !escrow.requires_payment_memo(),
+            EscrowError::PaymentMemoRequired
+        );
+        // The taker is about to pay off-chain against tokens that must still be there
+        require_backing(
+            &self.escrow.key(),
+            &escrow,
+            self.initializer_ata_a.as_deref(),
+        )?;
+        // Confirming an open offer implicitly accepts it
+        if !escrow.has_taker() {
+            require_gateway_pass(
+                &self.config,
+                self.gateway_token.as_deref(),
+                &self.taker.key(),
+            )?;
+            require_taker_attestations(
+                &self.config,
+                &escrow,
+                self.global_attestations.as_deref(),
+                self.offer_attestations.as_deref(),
+                attestations,
+                &self.taker.key(),
+            )?;
+            require_taker_region(
+                &escrow,
+                self.region_policy.as_deref(),
+                attestations,
+                &self.taker.key(),
+            )?;
+            self.taker_stats
+                .init_if_new(self.taker.key(), bumps.taker_stats);
+            self.taker_stats
+                .record_volume(escrow.initializer_amount, now, &self.config)?;
+            escrow.assign_taker(self.taker.key(), now);
+        }
+        let amount = match tranche {
+            Some(amount) => amount,
+            None => {
+                escrow.mark_payment_confirmed(now, &self.config);
+                return Ok(());
+            }
+        };
+        require!(
+            self.config.has_feature(Config::FEATURE_PARTIAL_PAYMENTS),
+            EscrowError::FeatureDisabled
+        );
+        require!(
+            escrow.accepts_partial_payments(),
+            EscrowError::PartialPaymentsDisabled
+        );
+        if escrow.confirm_tranche(amount, now)? {
+            escrow.mark_payment_confirmed(now, &self.config);
+        }
+        emit!(PartialPaymentConfirmed {
+            escrow: self.escrow.key(),
+            taker: self.taker.key(),
+            amount,
+            confirmed_amount: escrow.confirmed_amount,
+            taker_amount: escrow.taker_amount,
+        });
         Ok(())
     }
 }
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::clock;
+use crate::constants::{MAX_JURORS, MIN_JURORS};
+use crate::errors::EscrowError;
+use crate::events::JuryConvened;
+use crate::states::{
+    AdminAction, ArbitrationPolicy, Arbitrator, AuditLog, Config, Escrow, Jury, Milestone,
+    MilestoneDispute,
+};
+
+// The admin sends a high-value dispute to a jury instead of the escrow's
+// single arbiter. Remaining accounts are the jurors' [b"arbitrator", wallet]
+// profiles: an odd number of distinct, eligible arbitrators, none of them a
+// party to the trade. Jurors get `commit_period` seconds to commit to a vote
+// and `reveal_period` more to reveal it.
+#[derive(Accounts)]
+pub struct ConveneJury<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Arbitration policy PDA; may not exist yet
+    #[account(seeds = [b"arbitration"], bump)]
+    pub arbitration: UncheckedAccount<'info>,
+    #[account(
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        has_one = escrow,
+        constraint = milestone.disputed @ EscrowError::MilestoneNotDisputed,
+        seeds = [b"milestone", escrow.key().as_ref(), &[milestone.index]],
+        bump = milestone.bump,
+    )]
+    pub milestone: Box<Account<'info, Milestone>>,
+    #[account(
+        has_one = milestone,
+        seeds = [b"milestone_dispute", milestone.key().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Box<Account<'info, MilestoneDispute>>,
+    #[account(
+        init,
+        payer = admin,
+        space = Jury::SPACE,
+        seeds = [b"jury", dispute.key().as_ref()],
+        bump,
+    )]
+    pub jury: Box<Account<'info, Jury>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ConveneJury<'info> {
+    pub fn convene_jury(
+        &mut self,
+        bumps: &ConveneJuryBumps,
+        remaining_accounts: &'info [AccountInfo<'info>],
+        commit_period: i64,
+        reveal_period: i64,
+    ) -> Result<()> {
+        require!(
+            commit_period > 0 && reveal_period > 0,
+            EscrowError::InvalidJuryPeriod
+        );
+        let count = remaining_accounts.len();
+        require!(
+            (MIN_JURORS..=MAX_JURORS).contains(&count) && count % 2 == 1,
+            EscrowError::InvalidJury
+        );
+        let min_stake = if self.arbitration.data_is_empty() {
+            0
+        } else {
+            let data = self.arbitration.try_borrow_data()?;
+            ArbitrationPolicy::try_deserialize(&mut &data[..])?.min_stake
+        };
+        let (initializer, taker) = {
+            let escrow = self.escrow.load()?;
+            (escrow.initializer, escrow.taker)
+        };
+
+        let mut jurors = [Pubkey::default(); MAX_JURORS];
+        for (i, info) in remaining_accounts.iter().enumerate() {
+            let candidate = Account::<Arbitrator>::try_from(info)?;
+            let address = Pubkey::create_program_address(
+                &[b"arbitrator", candidate.wallet.as_ref(), &[candidate.bump]],
+                &crate::ID,
+            )
+            .map_err(|_| EscrowError::InvalidJury)?;
+            require_keys_eq!(info.key(), address, EscrowError::InvalidJury);
+            require!(
+                candidate.is_eligible(min_stake)
+                    && candidate.wallet != initializer
+                    && candidate.wallet != taker
+                    && !jurors[..i].contains(&candidate.wallet),
+                EscrowError::InvalidJury
+            );
+            jurors[i] = candidate.wallet;
+        }
+
+        let now = clock::now()?;
+        let commit_ends_at = now
+            .checked_add(commit_period)
+            .ok_or(EscrowError::MathOverflow)?;
+        let reveal_ends_at = commit_ends_at
+            .checked_add(reveal_period)
+            .ok_or(EscrowError::MathOverflow)?;
+        self.jury.set_inner(Jury {
+            version: Jury::VERSION,
+            bump: bumps.jury,
+            juror_count: count as u8,
+            votes_for_taker: 0,
+            votes_for_initializer: 0,
+            dispute: self.dispute.key(),
+            jurors,
+            convened_at: now,
+            commit_ends_at,
+            reveal_ends_at,
+            _reserved: [0; 16],
+        });
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::ConveneJury,
+            self.admin.key(),
+            self.dispute.key(),
+            &(jurors[..count].to_vec(), commit_period, reveal_period),
+        )?;
+
+        emit!(JuryConvened {
+            escrow: self.escrow.key(),
+            index: self.milestone.index,
+            jury: self.jury.key(),
+            juror_count: count as u8,
+            commit_ends_at,
+            reveal_ends_at,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::PLATFORM_WALLET;
+use crate::errors::EscrowError;
+use crate::events::FeesConverted;
+use crate::states::{Config, FeeConverter, SwapRouter};
+use crate::swap::{invoke_swap_route, SwapRoute};
+
+#[derive(Accounts)]
+pub struct ConvertFees<'info> {
+    // The config admin or the converter's keeper
+    pub caller: Signer<'info>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        seeds = [b"fee_converter"],
+        bump = fee_converter.bump,
+        constraint = fee_converter.may_convert(&config.admin, &caller.key()) @ EscrowError::Unauthorized,
+    )]
+    pub fee_converter: Box<Account<'info, FeeConverter>>,
+    #[account(
+        seeds = [b"swap_router"],
+        bump = swap_router.bump,
+        constraint = swap_router.is_enabled() @ EscrowError::SwapRouterNotSet,
+    )]
+    pub swap_router: Box<Account<'info, SwapRouter>>,
+    /// CHECK: The configured router program
+    #[account(executable, address = swap_router.program @ EscrowError::InvalidSwapRoute)]
+    pub swap_program: UncheckedAccount<'info>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    #[account(constraint = mint_in.key() != fee_converter.settlement_mint @ EscrowError::InvalidSwapRoute)]
+    pub mint_in: Box<InterfaceAccount<'info, Mint>>,
+    // Platform fee account the wallet approved the converter on
+    #[account(
+        mut,
+        token::mint = mint_in,
+        token::authority = platform_wallet,
+    )]
+    pub platform_ata_in: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = fee_converter.settlement_mint,
+        token::authority = platform_wallet,
+    )]
+    pub platform_ata_out: Box<InterfaceAccount<'info, TokenAccount>>,
+}
+
+impl<'info> ConvertFees<'info> {
+    // Swaps up to `amount` of the platform's `mint_in` fees into the
+    // settlement mint along the route in the remaining accounts, with the
+    // converter signing as delegate. Both legs stay in the platform wallet:
+    // the route may spend no more than `amount` and must pay at least
+    // `min_out` into the settlement account.
+    pub fn convert_fees(
+        &mut self,
+        amount: u64,
+        min_out: u64,
+        route_data: Vec<u8>,
+        route_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        require!(amount > 0, EscrowError::InvalidAmount);
+        let balance_in = self.platform_ata_in.amount;
+        let balance_out = self.platform_ata_out.amount;
+        let signer_seeds: [&[&[u8]]; 1] = [&[b"fee_converter", &[self.fee_converter.bump]]];
+        let route = SwapRoute {
+            min_out,
+            data: route_data,
+            accounts: route_accounts,
+        };
+        invoke_swap_route(
+            &self.swap_program.to_account_info(),
+            &self.fee_converter.key(),
+            &route,
+            &signer_seeds,
+        )?;
+
+        self.platform_ata_in.reload()?;
+        self.platform_ata_out.reload()?;
+        let amount_in = balance_in.saturating_sub(self.platform_ata_in.amount);
+        let amount_out = self.platform_ata_out.amount.saturating_sub(balance_out);
+        require!(amount_in <= amount, EscrowError::InvalidSwapRoute);
+        require!(amount_out >= min_out, EscrowError::SwapSlippageExceeded);
+
+        emit!(FeesConverted {
+            caller: self.caller.key(),
+            router: self.swap_program.key(),
+            mint_in: self.mint_in.key(),
+            amount_in,
+            mint_out: self.fee_converter.settlement_mint,
+            amount_out,
+        });
+        Ok(())
+    }
+}
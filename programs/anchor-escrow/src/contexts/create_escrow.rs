@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::constants::PLATFORM_WALLET;
+use crate::errors::EscrowError;
+use crate::fees::charge_creation_fee;
+use crate::gating::{require_gateway_pass, require_stablecoin_market};
+use crate::states::{Config, Escrow, MakerRegistry, StablecoinList, TraderStats};
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct CreateEscrow<'info> {
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        payer = payer,
+        space = Escrow::SPACE,
+        seeds = [b"state".as_ref(), &seed.to_le_bytes()],
+        bump
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Civic gateway token of the initializer, verified when the market is gated
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+    // Required while the config is in stablecoin-only mode
+    #[account(seeds = [b"stablecoins"], bump = stablecoins.bump)]
+    pub stablecoins: Option<Box<Account<'info, StablecoinList>>>,
+    /// CHECK: Blocklist PDA of the initializer; must not exist
+    #[account(
+        seeds = [b"blocked", initializer.key().as_ref()],
+        bump,
+        constraint = initializer_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub initializer_block: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TraderStats::SPACE,
+        seeds = [b"trader_stats", initializer.key().as_ref()],
+        bump,
+    )]
+    pub initializer_stats: Box<Account<'info, TraderStats>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MakerRegistry::SPACE,
+        seeds = [b"registry", initializer.key().as_ref()],
+        bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(mut, address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateEscrow<'info> {
+    pub fn create_escrow(
+        &mut self,
+        seed: u64,
+        bumps: &CreateEscrowBumps,
+        initializer_amount: u64,
+        taker_amount: u64,
+        terms_hash: [u8; 32],
+    ) -> Result<()> {
+        require_gateway_pass(
+            &self.config,
+            self.gateway_token.as_deref(),
+            &self.initializer.key(),
+        )?;
+        require_stablecoin_market(
+            &self.config,
+            self.stablecoins.as_deref(),
+            &self.mint_a.key(),
+            &self.mint_b.key(),
+        )?;
+        self.initializer_stats
+            .init_if_new(self.initializer.key(), bumps.initializer_stats);
+        self.initializer_stats.track_opened(1, &self.config)?;
+        self.maker_registry
+            .init_if_new(self.initializer.key(), bumps.maker_registry);
+        self.maker_registry.add(self.escrow.key())?;
+        let fee_held = charge_creation_fee(
+            &self.config,
+            self.payer.to_account_info(),
+            self.escrow.to_account_info(),
+            self.platform_wallet.to_account_info(),
+            self.system_program.to_account_info(),
+        )?;
+        let mut escrow = Escrow::new(
+            seed,
+            bumps.escrow,
+            self.initializer.key(),
+            self.mint_a.key(),
+            self.mint_b.key(),
+            initializer_amount,
+            taker_amount,
+        );
+        escrow.terms_hash = terms_hash;
+        if fee_held {
+            escrow.flags |= Escrow::FLAG_CREATION_FEE_HELD;
+        }
+        *self.escrow.load_init()? = escrow;
+        Ok(())
+    }
+}
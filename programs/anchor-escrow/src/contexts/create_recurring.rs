@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::clock;
+use crate::constants::MIN_RECURRING_INTERVAL;
+use crate::errors::EscrowError;
+use crate::events::RecurringScheduleCreated;
+use crate::invariants::require_deposit_received;
+use crate::states::{Config, RecurringSchedule};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct RecurringTerms {
+    pub taker: Pubkey,
+    pub initializer_amount: u64,
+    pub taker_amount: u64,
+    pub interval: i64,
+    pub runs: u32,
+    // Zero spawns the first escrow right away
+    pub first_run_at: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(schedule_id: u16)]
+pub struct CreateRecurring<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    pub mint_b: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = maker,
+        associated_token::token_program = token_program
+    )]
+    pub maker_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = payer,
+        space = RecurringSchedule::SPACE,
+        seeds = [b"recurring", maker.key().as_ref(), &schedule_id.to_le_bytes()],
+        bump,
+    )]
+    pub schedule: Box<Account<'info, RecurringSchedule>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint_a,
+        associated_token::authority = schedule,
+        associated_token::token_program = token_program
+    )]
+    pub schedule_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.has_feature(Config::FEATURE_RECURRING) @ EscrowError::FeatureDisabled,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateRecurring<'info> {
+    // Locks initializer_amount for every run up front
+    pub fn create_recurring(
+        &mut self,
+        bumps: &CreateRecurringBumps,
+        schedule_id: u16,
+        terms: RecurringTerms,
+    ) -> Result<()> {
+        require!(
+            terms.initializer_amount > 0
+                && terms.runs > 0
+                && terms.interval >= MIN_RECURRING_INTERVAL
+                && terms.first_run_at >= 0
+                && terms.taker != Pubkey::default()
+                && terms.taker != self.maker.key(),
+            EscrowError::InvalidRecurringSchedule
+        );
+        let total = terms
+            .initializer_amount
+            .checked_mul(terms.runs as u64)
+            .ok_or(EscrowError::MathOverflow)?;
+        let before = self.schedule_vault.amount;
+        transfer_checked(self.into_fund_context(), total, self.mint_a.decimals)?;
+        let received = require_deposit_received(&mut self.schedule_vault, before, total)?;
+
+        let now = clock::now()?;
+        self.schedule.set_inner(RecurringSchedule {
+            version: RecurringSchedule::VERSION,
+            bump: bumps.schedule,
+            schedule_id,
+            maker: self.maker.key(),
+            taker: terms.taker,
+            mint_a: self.mint_a.key(),
+            mint_b: self.mint_b.key(),
+            initializer_amount: terms.initializer_amount,
+            taker_amount: terms.taker_amount,
+            interval: terms.interval,
+            next_run_at: terms.first_run_at.max(now),
+            runs: terms.runs,
+            runs_done: 0,
+            _reserved: [0; 32],
+        });
+        self.schedule.settle_deposit(received)?;
+
+        emit!(RecurringScheduleCreated {
+            schedule: self.schedule.key(),
+            maker: self.maker.key(),
+            taker: terms.taker,
+            runs: terms.runs,
+            interval: terms.interval,
+        });
+        Ok(())
+    }
+
+    fn into_fund_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.maker_ata_a.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.schedule_vault.to_account_info(),
+            authority: self.maker.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
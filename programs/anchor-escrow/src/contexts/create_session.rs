@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::constants::MAX_SESSION_DURATION;
+use crate::errors::EscrowError;
+use crate::events::SessionCreated;
+use crate::states::MakerSession;
+
+#[derive(Accounts)]
+#[instruction(session_key: Pubkey)]
+pub struct CreateSession<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MakerSession::SPACE,
+        seeds = [b"session", maker.key().as_ref(), session_key.as_ref()],
+        bump,
+    )]
+    pub session: Box<Account<'info, MakerSession>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CreateSession<'info> {
+    // Calling it again for the same key renews or rescopes the session
+    pub fn create_session(
+        &mut self,
+        bumps: &CreateSessionBumps,
+        session_key: Pubkey,
+        expires_at: i64,
+        scopes: u8,
+    ) -> Result<()> {
+        let now = clock::now()?;
+        require!(
+            expires_at > now && expires_at - now <= MAX_SESSION_DURATION,
+            EscrowError::InvalidSessionExpiry
+        );
+        require!(
+            scopes != 0 && scopes & !MakerSession::SCOPE_ALL == 0,
+            EscrowError::InvalidSessionScope
+        );
+        require_keys_neq!(session_key, self.maker.key(), EscrowError::InvalidSession);
+
+        self.session.set_inner(MakerSession {
+            version: MakerSession::VERSION,
+            bump: bumps.session,
+            maker: self.maker.key(),
+            session_key,
+            expires_at,
+            scopes,
+            _reserved: [0; 32],
+        });
+
+        emit!(SessionCreated {
+            maker: self.maker.key(),
+            session_key,
+            expires_at,
+            scopes,
+        });
+        Ok(())
+    }
+}
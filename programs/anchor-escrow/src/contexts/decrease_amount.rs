@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::errors::EscrowError;
+use crate::fees::pro_rata;
+use crate::invariants::require_vault_backs_escrow;
+use crate::states::Escrow;
+
+#[derive(Accounts)]
+pub struct DecreaseAmount<'info> {
+    pub initializer: Signer<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
+    )]
+    pub initializer_ata_a: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = mint_a,
+        constraint = escrow.load()?.is_funded() @ EscrowError::EscrowNotFunded,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        constraint = !escrow.load()?.is_frozen() @ EscrowError::EscrowFrozen,
+        constraint = !escrow.load()?.has_yield_position() @ EscrowError::YieldPositionOpen,
+        // A funder's deposit only goes back to them, in full, through `cancel`
+        constraint = !escrow.load()?.has_funder() @ EscrowError::InvalidFunder,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> DecreaseAmount<'info> {
+    pub fn decrease_amount(&mut self, amount: u64) -> Result<()> {
+        let escrow = *self.escrow.load()?;
+        require!(amount > 0, EscrowError::InvalidAmount);
+        // Withdrawing everything is what `cancel` is for
+        require!(
+            amount < escrow.initializer_amount,
+            EscrowError::AmountExceedsEscrow
+        );
+
+        let signer_seeds: [&[&[u8]]; 1] =
+            [&[b"state", &escrow.seed.to_le_bytes()[..], &[escrow.bump]]];
+        transfer_checked(
+            self.into_withdraw_context().with_signer(&signer_seeds),
+            amount,
+            self.mint_a.decimals,
+        )?;
+
+        let new_amount = escrow.initializer_amount - amount;
+        let mut state = self.escrow.load_mut()?;
+        // Keep the unit price: taker_amount shrinks in proportion to the removed size
+        state.taker_amount = pro_rata(escrow.taker_amount, new_amount, escrow.initializer_amount)?;
+        state.initializer_amount = new_amount;
+        require_vault_backs_escrow(&state, &mut self.vault)
+    }
+
+    fn into_withdraw_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.initializer_ata_a.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
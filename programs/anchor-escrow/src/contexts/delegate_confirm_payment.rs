@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::states::{Config, Escrow, PaymentDelegate};
+
+// `confirm_payment` signed by the taker's registered delegate. Only for escrows
+// the taker has already taken: accepting an open offer stays with the main
+// wallet, which pays for the profile and passes the identity checks.
+#[derive(Accounts)]
+pub struct DelegateConfirmPayment<'info> {
+    pub delegate: Signer<'info>,
+    #[account(
+        mut,
+        constraint = escrow.load()?.is_funded() @ EscrowError::EscrowNotFunded,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = escrow.load()?.has_taker() @ EscrowError::InvalidTaker,
+        constraint = !escrow.load()?.is_bond_pending() @ EscrowError::BondNotPosted,
+        constraint = !escrow.load()?.requires_payment_memo() @ EscrowError::PaymentMemoRequired,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        has_one = delegate @ EscrowError::Unauthorized,
+        seeds = [b"payment_delegate", escrow.load()?.taker.as_ref()],
+        bump = payment_delegate.bump,
+    )]
+    pub payment_delegate: Box<Account<'info, PaymentDelegate>>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Blocklist PDA of the taker; must not exist
+    #[account(
+        seeds = [b"blocked", escrow.load()?.taker.as_ref()],
+        bump,
+        constraint = taker_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub taker_block: UncheckedAccount<'info>,
+}
+
+impl<'info> DelegateConfirmPayment<'info> {
+    pub fn delegate_confirm_payment(&mut self) -> Result<()> {
+        let now = clock::now()?;
+        self.escrow
+            .load_mut()?
+            .mark_payment_confirmed(now, &self.config);
+        Ok(())
+    }
+}
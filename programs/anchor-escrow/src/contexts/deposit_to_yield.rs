@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::EscrowError;
+use crate::events::YieldDeposited;
+use crate::lending::{deposit_to_adapter, AdapterAccounts};
+use crate::states::{Config, Escrow, YieldAdapter};
+
+// Lends a long-lived offer's vault out through the yield adapter until
+// `withdraw_from_yield` brings it back, which every payout or refund of the
+// escrow needs first. Only for plain offers nobody has paid for yet.
+#[derive(Accounts)]
+pub struct DepositToYield<'info> {
+    pub initializer: Signer<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = mint_a,
+        constraint = escrow.load()?.is_funded() @ EscrowError::EscrowNotFunded,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = escrow.load()?.is_immediate() @ EscrowError::ReleaseModeMismatch,
+        constraint = !escrow.load()?.has_yield_position() @ EscrowError::YieldPositionOpen,
+        constraint = !escrow.load()?.is_frozen() @ EscrowError::EscrowFrozen,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.has_feature(Config::FEATURE_YIELD) @ EscrowError::FeatureDisabled,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        mut,
+        seeds = [b"yield_adapter"],
+        bump = yield_adapter.bump,
+        constraint = yield_adapter.is_enabled() @ EscrowError::YieldAdapterNotSet,
+    )]
+    pub yield_adapter: Box<Account<'info, YieldAdapter>>,
+    /// CHECK: The configured adapter program
+    #[account(executable, address = yield_adapter.program)]
+    pub adapter_program: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> DepositToYield<'info> {
+    // Remaining accounts are the lending protocol's, handed to the adapter
+    pub fn deposit_to_yield(&mut self, protocol_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let escrow = *self.escrow.load()?;
+        let amount = self.vault.amount;
+        require!(amount > 0, EscrowError::InvalidAmount);
+        let signer_seeds: [&[&[u8]]; 1] = [&[
+            b"state",
+            &escrow.seed.to_le_bytes()[..],
+            &[escrow.bump],
+        ]];
+        deposit_to_adapter(
+            &AdapterAccounts {
+                adapter: &self.adapter_program.to_account_info(),
+                owner: &self.escrow.to_account_info(),
+                token_account: &self.vault.to_account_info(),
+                mint: &self.mint_a.to_account_info(),
+                token_program: &self.token_program.to_account_info(),
+                protocol_accounts,
+            },
+            amount,
+            &signer_seeds,
+        )?;
+
+        // Whatever actually left the vault is what has to come back
+        self.vault.reload()?;
+        let principal = amount.saturating_sub(self.vault.amount);
+        require!(principal > 0, EscrowError::InvalidAmount);
+        self.escrow.load_mut()?.yield_principal = principal;
+        self.yield_adapter.open_positions += 1;
+
+        emit!(YieldDeposited {
+            escrow: self.escrow.key(),
+            adapter: self.adapter_program.key(),
+            amount: principal,
+        });
+        Ok(())
+    }
+}
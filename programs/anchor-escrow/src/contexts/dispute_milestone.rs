@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::MilestoneDisputeOpened;
+use crate::states::{Config, Escrow, Milestone, MilestoneDispute};
+
+#[derive(Accounts)]
+pub struct DisputeMilestone<'info> {
+    // Either the initializer or the taker
+    #[account(mut)]
+    pub party: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    // Mutable to pause its deadline until the ruling
+    #[account(
+        mut,
+        constraint = escrow.load()?.is_milestones() @ EscrowError::ReleaseModeMismatch,
+        constraint = escrow.load()?.has_arbiter() @ EscrowError::InvalidArbiter,
+        constraint = escrow.load()?.has_taker() @ EscrowError::InvalidTaker,
+        constraint = party.key() == escrow.load()?.initializer || party.key() == escrow.load()?.taker @ EscrowError::NotEscrowParty,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        mut,
+        has_one = escrow,
+        constraint = !milestone.is_settled() @ EscrowError::MilestoneAlreadyReleased,
+        seeds = [b"milestone", escrow.key().as_ref(), &[milestone.index]],
+        bump = milestone.bump,
+    )]
+    pub milestone: Account<'info, Milestone>,
+    #[account(
+        init,
+        payer = payer,
+        space = MilestoneDispute::SPACE,
+        seeds = [b"milestone_dispute", milestone.key().as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, MilestoneDispute>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.has_feature(Config::FEATURE_DISPUTES) @ EscrowError::FeatureDisabled,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DisputeMilestone<'info> {
+    pub fn dispute_milestone(
+        &mut self,
+        bumps: &DisputeMilestoneBumps,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        let now = clock::now()?;
+        self.dispute.set_inner(MilestoneDispute {
+            version: MilestoneDispute::VERSION,
+            bump: bumps.dispute,
+            resolved: false,
+            released_to_taker: false,
+            milestone: self.milestone.key(),
+            opened_by: self.party.key(),
+            reason_hash,
+            opened_at: now,
+            resolved_at: 0,
+            arbiter: Pubkey::default(),
+        });
+        self.milestone.disputed = true;
+        self.escrow.load_mut()?.pause_for_dispute(now)?;
+        // Held in the dispute account on top of its rent until the ruling
+        if self.config.dispute_fee > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.party.to_account_info(),
+                        to: self.dispute.to_account_info(),
+                    },
+                ),
+                self.config.dispute_fee,
+            )?;
+        }
+
+        emit!(MilestoneDisputeOpened {
+            escrow: self.escrow.key(),
+            index: self.milestone.index,
+            opened_by: self.party.key(),
+            reason_hash,
+        });
+        Ok(())
+    }
+}
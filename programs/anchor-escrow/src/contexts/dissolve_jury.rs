@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::JuryDissolved;
+use crate::states::{AdminAction, AuditLog, Config, Jury};
+
+// Closes a hung jury, which hands the dispute back to the escrow's arbiter.
+// Jurors who sat on it can't sit on the same dispute again, since their
+// ballot addresses are taken.
+#[derive(Accounts)]
+pub struct DissolveJury<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"jury", jury.dispute.as_ref()],
+        bump = jury.bump,
+    )]
+    pub jury: Box<Account<'info, Jury>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> DissolveJury<'info> {
+    pub fn dissolve_jury(&mut self, bumps: &DissolveJuryBumps) -> Result<()> {
+        let now = clock::now()?;
+        require!(
+            now >= self.jury.reveal_ends_at && self.jury.verdict(now).is_none(),
+            EscrowError::JuryNotHung
+        );
+
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::DissolveJury,
+            self.admin.key(),
+            self.jury.dispute,
+            &(self.jury.votes_for_taker, self.jury.votes_for_initializer),
+        )?;
+
+        emit!(JuryDissolved {
+            jury: self.jury.key(),
+            dispute: self.jury.dispute,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::DisputeEscalated;
+use crate::states::{Escalation, Escrow, GovernanceAuthority, Milestone, MilestoneDispute};
+
+// Either party takes an open milestone dispute, including one before a jury,
+// to the DAO as the final word. Only possible once the admin has named a
+// governance authority.
+#[derive(Accounts)]
+pub struct EscalateDispute<'info> {
+    #[account(mut)]
+    pub party: Signer<'info>,
+    #[account(
+        constraint = party.key() == escrow.load()?.initializer || party.key() == escrow.load()?.taker @ EscrowError::NotEscrowParty,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        has_one = escrow,
+        constraint = milestone.disputed @ EscrowError::MilestoneNotDisputed,
+        seeds = [b"milestone", escrow.key().as_ref(), &[milestone.index]],
+        bump = milestone.bump,
+    )]
+    pub milestone: Box<Account<'info, Milestone>>,
+    #[account(
+        has_one = milestone,
+        seeds = [b"milestone_dispute", milestone.key().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Box<Account<'info, MilestoneDispute>>,
+    #[account(
+        seeds = [b"governance"],
+        bump = governance.bump,
+        constraint = governance.authority != Pubkey::default() @ EscrowError::GovernanceNotSet,
+    )]
+    pub governance: Box<Account<'info, GovernanceAuthority>>,
+    #[account(
+        init,
+        payer = party,
+        space = Escalation::SPACE,
+        seeds = [b"escalation", dispute.key().as_ref()],
+        bump,
+    )]
+    pub escalation: Box<Account<'info, Escalation>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> EscalateDispute<'info> {
+    pub fn escalate_dispute(
+        &mut self,
+        bumps: &EscalateDisputeBumps,
+        proposal: Pubkey,
+    ) -> Result<()> {
+        self.escalation.set_inner(Escalation {
+            version: Escalation::VERSION,
+            bump: bumps.escalation,
+            dispute: self.dispute.key(),
+            escalated_by: self.party.key(),
+            proposal,
+            escalated_at: clock::now()?,
+            _reserved: [0; 32],
+        });
+
+        emit!(DisputeEscalated {
+            escrow: self.escrow.key(),
+            index: self.milestone.index,
+            escalated_by: self.party.key(),
+            proposal,
+        });
+        Ok(())
+    }
+}
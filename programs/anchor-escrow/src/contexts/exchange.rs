@@ -1,90 +1,580 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{
-        close_account, transfer_checked, CloseAccount, Mint, Token, TokenAccount, TransferChecked,
-    },
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
 };
 
-use crate::states::Escrow;
-use crate::constants::{PLATFORM_FEE_PERCENTAGE, PLATFORM_WALLET};
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::gating::require_compliance_approval;
+use crate::states::{
+    ComplianceApproval, Config, DonationPledge, Escrow, MakerRebate, MakerRegistry, MakerSession,
+    PartnerAccrual, PartnerConfig, RateFeed, Receipt, SettledTrade, SignerNonce, SwapRouter,
+    TradeHistory, TraderStats,
+};
+use crate::constants::PLATFORM_WALLET;
+use crate::events::{InsuranceFunded, RiskPremiumCharged, SettlementSwapped};
+use crate::fees::SettlementSplit;
+use crate::payout::{
+    accrue_maker_rebate, donate, pay_partner, PartnerAccounts, RebateAccounts, VaultAccounts,
+};
+use crate::pricing::require_rate_within;
+use crate::sigverify::{
+    release_approval_message, require_ed25519_signature, require_secp256k1_signature,
+};
+use crate::swap::{invoke_swap_route, SwapRoute};
 
+// The taker and platform ATAs must already exist: clients prepend idempotent
+// ATA creation, which keeps init_if_needed and its system/ATA program CPIs off
+// the settlement path. A frozen one fails up front with its own error rather
+// than deep in the token program; the trade settles once it is thawed.
 #[derive(Accounts)]
 pub struct Exchange<'info> {
+    // The maker, a session key acting for them, or the relayer of a signed
+    // approval; pays for the stats account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub initializer: SystemAccount<'info>,
+    // Mutable to take back the rent of their donation pledge
     #[account(mut)]
-    pub initializer: Signer<'info>,
     pub taker: SystemAccount<'info>,
-    pub mint_a: Box<Account<'info, Mint>>,
-    pub mint_b: Box<Account<'info, Mint>>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
     #[account(
-        init_if_needed,
-        payer = initializer,
+        mut,
         associated_token::mint = mint_a,
-        associated_token::authority = taker
+        associated_token::authority = taker,
+        associated_token::token_program = token_program,
+        constraint = !taker_ata_a.is_frozen() @ EscrowError::TakerAccountFrozen,
     )]
-    pub taker_ata_a: Box<Account<'info, TokenAccount>>,
+    pub taker_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
-        init_if_needed,
-        payer = initializer,
+        mut,
         associated_token::mint = mint_a,
-        associated_token::authority = platform_wallet
+        associated_token::authority = platform_wallet,
+        associated_token::token_program = token_program,
+        constraint = !platform_ata_a.is_frozen() @ EscrowError::PlatformAccountFrozen,
     )]
-    pub platform_ata_a: Box<Account<'info, TokenAccount>>,
+    pub platform_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
     #[account(
         mut,
         has_one = mint_a,
-        constraint = escrow.payment_confirmed == true,
+        constraint = escrow.load()?.is_funded() @ EscrowError::EscrowNotFunded,
+        constraint = escrow.load()?.is_payment_confirmed(),
+        constraint = escrow.load()?.is_release_unlocked(clock::now()?) @ EscrowError::ReleaseDelayPending,
+        constraint = escrow.load()?.is_immediate() @ EscrowError::ReleaseModeMismatch,
+        // Escrows migrated from the legacy layout were confirmed without recording a taker
+        constraint = !escrow.load()?.has_taker() || escrow.load()?.taker == taker.key() @ EscrowError::InvalidTaker,
+        constraint = !escrow.load()?.is_frozen() @ EscrowError::EscrowFrozen,
+        constraint = !escrow.load()?.has_yield_position() @ EscrowError::YieldPositionOpen,
         close = initializer,
-        seeds=[b"state", escrow.seed.to_le_bytes().as_ref()],
-        bump = escrow.bump,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
     )]
-    pub escrow: Box<Account<'info, Escrow>>,
+    pub escrow: AccountLoader<'info, Escrow>,
     #[account(
         mut,
         associated_token::mint = mint_a,
-        associated_token::authority = escrow
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
     )]
-    pub vault: Box<Account<'info, TokenAccount>>,
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
     /// CHECK: This is the hardcoded platform wallet address
     #[account(address = PLATFORM_WALLET)]
     pub platform_wallet: UncheckedAccount<'info>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
-    pub token_program: Program<'info, Token>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Data-less PDA that owns the insurance fund token accounts
+    #[account(seeds = [b"insurance"], bump = config.insurance_bump)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program
+    )]
+    pub insurance_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Blocklist PDA of the taker; must not exist
+    #[account(
+        seeds = [b"blocked", taker.key().as_ref()],
+        bump,
+        constraint = taker_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub taker_block: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TraderStats::SPACE,
+        seeds = [b"trader_stats", initializer.key().as_ref()],
+        bump,
+    )]
+    pub initializer_stats: Box<Account<'info, TraderStats>>,
+    // The signer's session, required unless the initializer signs
+    #[account(
+        seeds = [b"session", initializer.key().as_ref(), authority.key().as_ref()],
+        bump = session.bump,
+    )]
+    pub session: Option<Box<Account<'info, MakerSession>>>,
+    // The maker's registry, so the escrow drops off it
+    #[account(
+        mut,
+        seeds = [b"registry", initializer.key().as_ref()],
+        bump = maker_registry.bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    // Rebate pool and the maker's accrual, required while the config rebates mint_a
+    /// CHECK: Data-less PDA that owns the rebate pool token accounts
+    #[account(seeds = [b"rebate_pool"], bump)]
+    pub rebate_pool: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = rebate_pool,
+        associated_token::token_program = token_program
+    )]
+    pub rebate_pool_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = MakerRebate::SPACE,
+        seeds = [b"maker_rebate", initializer.key().as_ref(), mint_a.key().as_ref()],
+        bump,
+    )]
+    pub maker_rebate: Option<Box<Account<'info, MakerRebate>>>,
+    // Written when passed, as proof of the trade that outlives the escrow
+    #[account(
+        init,
+        payer = authority,
+        space = Receipt::SPACE,
+        seeds = [
+            b"receipt",
+            escrow.key().as_ref(),
+            escrow.load()?.payment_confirmed_at.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub receipt: Option<Box<Account<'info, Receipt>>>,
+    // Both parties' trade histories, appended to when passed
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TradeHistory::SPACE,
+        seeds = [b"history", initializer.key().as_ref()],
+        bump,
+    )]
+    pub initializer_history: Option<Box<Account<'info, TradeHistory>>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TradeHistory::SPACE,
+        seeds = [b"history", taker.key().as_ref()],
+        bump,
+    )]
+    pub taker_history: Option<Box<Account<'info, TradeHistory>>>,
+    // The market's feed, required while the escrow has a rate snapshot
+    #[account(
+        seeds = [b"rate_feed", mint_a.key().as_ref(), escrow.load()?.mint_b.as_ref()],
+        bump = rate_feed.bump,
+    )]
+    pub rate_feed: Option<Box<Account<'info, RateFeed>>>,
+    // Router and the taker's account for the output mint, for `exchange_and_swap`
+    #[account(seeds = [b"swap_router"], bump = swap_router.bump)]
+    pub swap_router: Option<Box<Account<'info, SwapRouter>>>,
+    /// CHECK: The configured router program, checked against `swap_router`
+    #[account(executable)]
+    pub swap_program: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        constraint = taker_ata_out.owner == taker.key() @ EscrowError::InvalidSwapRoute,
+        constraint = taker_ata_out.mint != mint_a.key() @ EscrowError::InvalidSwapRoute,
+    )]
+    pub taker_ata_out: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    // The taker's pledge and the pledged charity's account, required once
+    // the taker pledged
+    #[account(
+        mut,
+        close = taker,
+        seeds = [b"donation", escrow.key().as_ref()],
+        bump = donation.bump,
+    )]
+    pub donation: Option<Box<Account<'info, DonationPledge>>>,
+    #[account(mut, token::mint = mint_a)]
+    pub charity_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    // Sign-off from `approve_release`, required while the config or the
+    // offer asks for compliance approval
+    #[account(
+        seeds = [b"compliance_approval", escrow.key().as_ref()],
+        bump = compliance_approval.bump,
+    )]
+    pub compliance_approval: Option<Box<Account<'info, ComplianceApproval>>>,
+    // The initializer's nonce, required by the signed-approval releases
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = SignerNonce::SPACE,
+        seeds = [b"nonce", initializer.key().as_ref()],
+        bump,
+    )]
+    pub signer_nonce: Option<Box<Account<'info, SignerNonce>>>,
+    // The partner the offer was created through and the partner fee wallet's
+    // mint_a account, required when the escrow has a partner
+    #[account(
+        seeds = [b"partner", escrow.load()?.partner_id.to_le_bytes().as_ref()],
+        bump = partner_config.bump,
+    )]
+    pub partner_config: Option<Box<Account<'info, PartnerConfig>>>,
+    #[account(mut, token::mint = mint_a)]
+    pub partner_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    // Partner pool and the partner's accrual, required while the partner
+    // takes a share of the platform fee
+    /// CHECK: Data-less PDA that owns the partner pool token accounts
+    #[account(seeds = [b"partner_pool"], bump)]
+    pub partner_pool: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = partner_pool,
+        associated_token::token_program = token_program
+    )]
+    pub partner_pool_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PartnerAccrual::SPACE,
+        seeds = [
+            b"partner_accrual",
+            escrow.load()?.partner_id.to_le_bytes().as_ref(),
+            mint_a.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub partner_accrual: Option<Box<Account<'info, PartnerAccrual>>>,
+    /// CHECK: Instructions sysvar, only read by the signed-approval releases
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: Option<UncheckedAccount<'info>>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> Exchange<'info> {
-    pub fn withdraw_and_close_vault(&mut self) -> Result<()> {
+    pub fn withdraw_and_close_vault(&mut self, bumps: &ExchangeBumps) -> Result<()> {
+        MakerSession::authorize(
+            &self.initializer.key(),
+            &self.authority.key(),
+            self.session.as_deref(),
+            MakerSession::SCOPE_RELEASE,
+        )?;
+        self.settle(bumps, None)
+    }
+
+    // Same, with the taker's payout swapped into the mint of `taker_ata_out`
+    pub fn release_and_swap(
+        &mut self,
+        bumps: &ExchangeBumps,
+        min_out: u64,
+        route_data: Vec<u8>,
+        route_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        MakerSession::authorize(
+            &self.initializer.key(),
+            &self.authority.key(),
+            self.session.as_deref(),
+            MakerSession::SCOPE_RELEASE,
+        )?;
+        let route = SwapRoute {
+            min_out,
+            data: route_data,
+            accounts: route_accounts,
+        };
+        self.settle(bumps, Some(route))
+    }
+
+    // Relayed release: instead of signing, the initializer approved it offline
+    // with the Ed25519 instruction placed just before this one
+    pub fn release_with_signature(
+        &mut self,
+        bumps: &ExchangeBumps,
+        valid_until: i64,
+        nonce: u64,
+    ) -> Result<()> {
+        require!(
+            clock::now()? <= valid_until,
+            EscrowError::ReleaseApprovalExpired
+        );
+        let instructions = self
+            .instructions
+            .as_ref()
+            .ok_or(EscrowError::InvalidReleaseApproval)?;
+        let message = release_approval_message(
+            &self.escrow.key(),
+            &self.taker.key(),
+            self.escrow.load()?.initializer_amount,
+            valid_until,
+            nonce,
+        );
+        require_ed25519_signature(instructions, &self.initializer.key(), &message)?;
+        self.consume_nonce(bumps, nonce)?;
+        self.settle(bumps, None)
+    }
+
+    // Same, approved by the escrow's EVM approver through the Secp256k1
+    // instruction placed just before this one
+    pub fn release_with_evm_signature(
+        &mut self,
+        bumps: &ExchangeBumps,
+        valid_until: i64,
+        nonce: u64,
+    ) -> Result<()> {
+        require!(
+            clock::now()? <= valid_until,
+            EscrowError::ReleaseApprovalExpired
+        );
+        let instructions = self
+            .instructions
+            .as_ref()
+            .ok_or(EscrowError::InvalidReleaseApproval)?;
+        let escrow = *self.escrow.load()?;
+        require!(escrow.has_evm_approver(), EscrowError::EvmApproverNotSet);
+        let message = release_approval_message(
+            &self.escrow.key(),
+            &self.taker.key(),
+            escrow.initializer_amount,
+            valid_until,
+            nonce,
+        );
+        require_secp256k1_signature(instructions, &escrow.evm_approver, &message)?;
+        self.consume_nonce(bumps, nonce)?;
+        self.settle(bumps, None)
+    }
+
+    fn consume_nonce(&mut self, bumps: &ExchangeBumps, nonce: u64) -> Result<()> {
+        let signer_nonce = self
+            .signer_nonce
+            .as_mut()
+            .ok_or(EscrowError::NonceAccountRequired)?;
+        let bump = bumps
+            .signer_nonce
+            .ok_or(EscrowError::NonceAccountRequired)?;
+        signer_nonce.init_if_new(self.initializer.key(), bump);
+        signer_nonce.consume(nonce)
+    }
+
+    fn settle(&mut self, bumps: &ExchangeBumps, swap: Option<SwapRoute<'_, 'info>>) -> Result<()> {
+        let escrow = *self.escrow.load()?;
+        require_compliance_approval(&self.config, &escrow, self.compliance_approval.as_deref())?;
+        let now = clock::now()?;
+        require_rate_within(&self.config, &escrow, self.rate_feed.as_deref(), now)?;
+        // The taker's side was counted when they took the offer
+        self.initializer_stats
+            .init_if_new(self.initializer.key(), bumps.initializer_stats);
+        // Priced on the record before this trade counts towards it
+        let loss_rate_bps = self.initializer_stats.dispute_loss_rate_bps();
+        self.initializer_stats.track_settled();
+        self.maker_registry.remove(&self.escrow.key());
+        self.initializer_stats
+            .record_volume(escrow.initializer_amount, now, &self.config)?;
         let signer_seeds: [&[&[u8]]; 1] = [&[
             b"state",
-            &self.escrow.seed.to_le_bytes()[..],
-            &[self.escrow.bump],
+            &escrow.seed.to_le_bytes()[..],
+            &[escrow.bump],
         ]];
 
-        // Calculate platform fee and buyer amount using hardcoded constants
-        let total_amount = self.escrow.initializer_amount;
-        let platform_fee = (total_amount * PLATFORM_FEE_PERCENTAGE as u64) / 100;
-        let buyer_amount = total_amount - platform_fee;
+        let mut split = SettlementSplit::compute(
+            &self.config,
+            &self.mint_a.key(),
+            escrow.category,
+            escrow.initializer_amount,
+            loss_rate_bps,
+            now,
+        )?;
+        let vault = VaultAccounts {
+            escrow: &self.escrow.to_account_info(),
+            vault: &self.vault.to_account_info(),
+            mint: &self.mint_a.to_account_info(),
+            decimals: self.mint_a.decimals,
+            token_program: &self.token_program.to_account_info(),
+        };
+        if escrow.has_partner() {
+            let partner = PartnerAccounts {
+                config: self.partner_config.as_deref(),
+                fee_ata: self.partner_ata_a.as_deref(),
+                pool_ata: self.partner_pool_ata_a.as_deref(),
+                accrual: self.partner_accrual.as_deref_mut(),
+                accrual_bump: bumps.partner_accrual,
+            };
+            split = split.with_partner(partner.config()?)?;
+            pay_partner(&vault, partner, &split, now, &signer_seeds)?;
+        }
+        // Comes out of the taker's payout, so the receipt shows what they kept
+        if escrow.has_donation_pledge() {
+            let pledge = self
+                .donation
+                .as_deref()
+                .ok_or(EscrowError::DonationPledgeMissing)?;
+            split = split.with_donation(pledge, self.mint_a.decimals)?;
+            if split.donation > 0 {
+                donate(
+                    &vault,
+                    Some(pledge),
+                    self.charity_ata_a.as_deref(),
+                    split.donation,
+                    &signer_seeds,
+                )?;
+            }
+        }
+        let SettlementSplit {
+            platform_share,
+            insurance_share,
+            rebate,
+            premium,
+            buyer_amount,
+            ..
+        } = split;
 
-        // Transfer platform fee to platform wallet
-        if platform_fee > 0 {
+        if let (Some(receipt), Some(bump)) = (self.receipt.as_mut(), bumps.receipt) {
+            receipt.set_inner(Receipt::new(
+                bump,
+                self.escrow.key(),
+                &escrow,
+                self.taker.key(),
+                buyer_amount,
+                now,
+                self.authority.key(),
+            ));
+        }
+        let trade = SettledTrade::new(
+            self.escrow.key(),
+            &escrow,
+            self.taker.key(),
+            buyer_amount,
+            now,
+        );
+        if let (Some(history), Some(bump)) =
+            (self.initializer_history.as_mut(), bumps.initializer_history)
+        {
+            history.record(self.initializer.key(), bump, &trade)?;
+        }
+        if let (Some(history), Some(bump)) = (self.taker_history.as_mut(), bumps.taker_history) {
+            history.record(self.taker.key(), bump, &trade)?;
+        }
+        if rebate > 0 {
+            accrue_maker_rebate(
+                &vault,
+                RebateAccounts {
+                    pool_ata: self.rebate_pool_ata_a.as_deref(),
+                    accrual: self.maker_rebate.as_deref_mut(),
+                    accrual_bump: bumps.maker_rebate,
+                },
+                self.initializer.key(),
+                rebate,
+                now,
+                &signer_seeds,
+            )?;
+        }
+        if premium > 0 {
+            emit!(RiskPremiumCharged {
+                escrow: self.escrow.key(),
+                initializer: self.initializer.key(),
+                loss_rate_bps,
+                amount: premium,
+            });
+        }
+        // Transfer the insurance fund's slice of the fee, then the platform's
+        if insurance_share > 0 {
+            transfer_checked(
+                self.into_insurance_fee_context().with_signer(&signer_seeds),
+                insurance_share,
+                self.mint_a.decimals,
+            )?;
+            emit!(InsuranceFunded {
+                escrow: self.escrow.key(),
+                mint: self.mint_a.key(),
+                amount: insurance_share,
+            });
+        }
+        if platform_share > 0 {
             transfer_checked(
                 self.into_platform_fee_context().with_signer(&signer_seeds),
-                platform_fee,
+                platform_share,
                 self.mint_a.decimals,
             )?;
         }
 
         // Transfer remaining amount to buyer
-        transfer_checked(
-            self.into_withdraw_context().with_signer(&signer_seeds),
-            buyer_amount,
-            self.mint_a.decimals,
-        )?;
+        if buyer_amount > 0 {
+            match swap {
+                Some(route) => self.swap_payout(&route, buyer_amount, &signer_seeds)?,
+                None => transfer_checked(
+                    self.into_withdraw_context().with_signer(&signer_seeds),
+                    buyer_amount,
+                    self.mint_a.decimals,
+                )?,
+            }
+        }
 
         close_account(self.into_close_context().with_signer(&signer_seeds))
     }
 
+    // Runs the payout through the router, which spends from the vault with the
+    // escrow as its authority. The taker must receive at least the route's
+    // minimum; whatever the route left in the vault goes to them in mint_a.
+    fn swap_payout(
+        &mut self,
+        route: &SwapRoute<'_, 'info>,
+        amount: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        let router = self
+            .swap_router
+            .as_ref()
+            .ok_or(EscrowError::SwapRouterNotSet)?;
+        require!(router.is_enabled(), EscrowError::SwapRouterNotSet);
+        let program = self
+            .swap_program
+            .as_ref()
+            .ok_or(EscrowError::InvalidSwapRoute)?;
+        require_keys_eq!(program.key(), router.program, EscrowError::InvalidSwapRoute);
+        let taker_ata_out = self
+            .taker_ata_out
+            .as_mut()
+            .ok_or(EscrowError::InvalidSwapRoute)?;
+        let balance_before = taker_ata_out.amount;
+
+        invoke_swap_route(
+            &program.to_account_info(),
+            &self.escrow.key(),
+            route,
+            signer_seeds,
+        )?;
+
+        taker_ata_out.reload()?;
+        let amount_out = taker_ata_out.amount.saturating_sub(balance_before);
+        require!(amount_out >= route.min_out, EscrowError::SwapSlippageExceeded);
+        let mint_out = taker_ata_out.mint;
+        self.vault.reload()?;
+        let unspent = self.vault.amount;
+        if unspent > 0 {
+            transfer_checked(
+                self.into_withdraw_context().with_signer(signer_seeds),
+                unspent,
+                self.mint_a.decimals,
+            )?;
+        }
+
+        emit!(SettlementSwapped {
+            escrow: self.escrow.key(),
+            taker: self.taker.key(),
+            router: program.key(),
+            mint_in: self.mint_a.key(),
+            amount_in: amount.saturating_sub(unspent),
+            mint_out,
+            amount_out,
+        });
+        Ok(())
+    }
+
     fn into_withdraw_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
         let cpi_accounts = TransferChecked {
             from: self.vault.to_account_info(),
@@ -105,6 +595,16 @@ impl<'info> Exchange<'info> {
         CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
     }
 
+    fn into_insurance_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.insurance_ata_a.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
     fn into_close_context(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
         let cpi_accounts = CloseAccount {
             account: self.vault.to_account_info(),
@@ -0,0 +1,363 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::clock;
+use crate::constants::PLATFORM_WALLET;
+use crate::delegation::require_backing;
+use crate::errors::EscrowError;
+use crate::events::{InsuranceFunded, MakerRebateAccrued, RiskPremiumCharged};
+use crate::fees::SettlementSplit;
+use crate::gating::require_compliance_approval;
+use crate::payout::{pay_partner, PartnerAccounts, VaultAccounts};
+use crate::pricing::require_rate_within;
+use crate::states::{
+    ComplianceApproval, Config, Escrow, MakerRebate, MakerRegistry, MakerSession, PartnerAccrual,
+    PartnerConfig, RateFeed, Receipt, SettledTrade, TradeHistory, TraderStats,
+};
+
+// `exchange` for delegated escrows: the same payouts, pulled straight from the
+// maker's ATA by the escrow PDA as delegate. Payout ATAs must already exist.
+#[derive(Accounts)]
+pub struct ExchangeDelegated<'info> {
+    // The maker, or a session key acting for them; pays for the stats account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub initializer: SystemAccount<'info>,
+    pub taker: SystemAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
+    )]
+    pub initializer_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program
+    )]
+    pub taker_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = platform_wallet,
+        associated_token::token_program = token_program
+    )]
+    pub platform_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = mint_a,
+        constraint = escrow.load()?.is_delegated() @ EscrowError::NotDelegated,
+        constraint = escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentNotConfirmed,
+        constraint = escrow.load()?.is_release_unlocked(clock::now()?) @ EscrowError::ReleaseDelayPending,
+        constraint = escrow.load()?.is_immediate() @ EscrowError::ReleaseModeMismatch,
+        constraint = escrow.load()?.taker == taker.key() @ EscrowError::InvalidTaker,
+        constraint = !escrow.load()?.is_frozen() @ EscrowError::EscrowFrozen,
+        close = initializer,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Data-less PDA that owns the insurance fund token accounts
+    #[account(seeds = [b"insurance"], bump = config.insurance_bump)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program
+    )]
+    pub insurance_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Blocklist PDA of the taker; must not exist
+    #[account(
+        seeds = [b"blocked", taker.key().as_ref()],
+        bump,
+        constraint = taker_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub taker_block: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TraderStats::SPACE,
+        seeds = [b"trader_stats", initializer.key().as_ref()],
+        bump,
+    )]
+    pub initializer_stats: Box<Account<'info, TraderStats>>,
+    // The maker's registry, so the escrow drops off it
+    #[account(
+        mut,
+        seeds = [b"registry", escrow.load()?.initializer.as_ref()],
+        bump = maker_registry.bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    // The signer's session, required unless the initializer signs
+    #[account(
+        seeds = [b"session", initializer.key().as_ref(), authority.key().as_ref()],
+        bump = session.bump,
+    )]
+    pub session: Option<Box<Account<'info, MakerSession>>>,
+    // Rebate pool and the maker's accrual, required while the config rebates mint_a
+    /// CHECK: Data-less PDA that owns the rebate pool token accounts
+    #[account(seeds = [b"rebate_pool"], bump)]
+    pub rebate_pool: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = rebate_pool,
+        associated_token::token_program = token_program
+    )]
+    pub rebate_pool_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = MakerRebate::SPACE,
+        seeds = [b"maker_rebate", initializer.key().as_ref(), mint_a.key().as_ref()],
+        bump,
+    )]
+    pub maker_rebate: Option<Box<Account<'info, MakerRebate>>>,
+    // Written when passed, as proof of the trade that outlives the escrow
+    #[account(
+        init,
+        payer = authority,
+        space = Receipt::SPACE,
+        seeds = [
+            b"receipt",
+            escrow.key().as_ref(),
+            escrow.load()?.payment_confirmed_at.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub receipt: Option<Box<Account<'info, Receipt>>>,
+    // Both parties' trade histories, appended to when passed
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TradeHistory::SPACE,
+        seeds = [b"history", initializer.key().as_ref()],
+        bump,
+    )]
+    pub initializer_history: Option<Box<Account<'info, TradeHistory>>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = TradeHistory::SPACE,
+        seeds = [b"history", taker.key().as_ref()],
+        bump,
+    )]
+    pub taker_history: Option<Box<Account<'info, TradeHistory>>>,
+    // The market's feed, required while the escrow has a rate snapshot
+    #[account(
+        seeds = [b"rate_feed", mint_a.key().as_ref(), escrow.load()?.mint_b.as_ref()],
+        bump = rate_feed.bump,
+    )]
+    pub rate_feed: Option<Box<Account<'info, RateFeed>>>,
+    // Sign-off from `approve_release`, required while the config or the
+    // offer asks for compliance approval
+    #[account(
+        seeds = [b"compliance_approval", escrow.key().as_ref()],
+        bump = compliance_approval.bump,
+    )]
+    pub compliance_approval: Option<Box<Account<'info, ComplianceApproval>>>,
+    // The partner the offer was created through and the partner fee wallet's
+    // mint_a account, required when the escrow has a partner
+    #[account(
+        seeds = [b"partner", escrow.load()?.partner_id.to_le_bytes().as_ref()],
+        bump = partner_config.bump,
+    )]
+    pub partner_config: Option<Box<Account<'info, PartnerConfig>>>,
+    #[account(mut, token::mint = mint_a)]
+    pub partner_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    // Partner pool and the partner's accrual, required while the partner
+    // takes a share of the platform fee
+    /// CHECK: Data-less PDA that owns the partner pool token accounts
+    #[account(seeds = [b"partner_pool"], bump)]
+    pub partner_pool: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = partner_pool,
+        associated_token::token_program = token_program
+    )]
+    pub partner_pool_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PartnerAccrual::SPACE,
+        seeds = [
+            b"partner_accrual",
+            escrow.load()?.partner_id.to_le_bytes().as_ref(),
+            mint_a.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub partner_accrual: Option<Box<Account<'info, PartnerAccrual>>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ExchangeDelegated<'info> {
+    pub fn exchange_delegated(&mut self, bumps: &ExchangeDelegatedBumps) -> Result<()> {
+        MakerSession::authorize(
+            &self.initializer.key(),
+            &self.authority.key(),
+            self.session.as_deref(),
+            MakerSession::SCOPE_RELEASE,
+        )?;
+        let escrow = *self.escrow.load()?;
+        require_compliance_approval(&self.config, &escrow, self.compliance_approval.as_deref())?;
+        // Fails if the maker pulled the delegation after the taker paid;
+        // that is a matter for dispute resolution
+        require_backing(&self.escrow.key(), &escrow, Some(&self.initializer_ata_a))?;
+
+        let now = clock::now()?;
+        require_rate_within(&self.config, &escrow, self.rate_feed.as_deref(), now)?;
+        self.initializer_stats
+            .init_if_new(self.initializer.key(), bumps.initializer_stats);
+        let loss_rate_bps = self.initializer_stats.dispute_loss_rate_bps();
+        self.initializer_stats.track_settled();
+        self.maker_registry.remove(&self.escrow.key());
+        self.initializer_stats
+            .record_volume(escrow.initializer_amount, now, &self.config)?;
+        let signer_seeds: [&[&[u8]]; 1] = [&[
+            b"state",
+            &escrow.seed.to_le_bytes()[..],
+            &[escrow.bump],
+        ]];
+
+        let mut split = SettlementSplit::compute(
+            &self.config,
+            &self.mint_a.key(),
+            escrow.category,
+            escrow.initializer_amount,
+            loss_rate_bps,
+            now,
+        )?;
+        if escrow.has_partner() {
+            // The maker's ATA stands in for the vault
+            let source = VaultAccounts {
+                escrow: &self.escrow.to_account_info(),
+                vault: &self.initializer_ata_a.to_account_info(),
+                mint: &self.mint_a.to_account_info(),
+                decimals: self.mint_a.decimals,
+                token_program: &self.token_program.to_account_info(),
+            };
+            let partner = PartnerAccounts {
+                config: self.partner_config.as_deref(),
+                fee_ata: self.partner_ata_a.as_deref(),
+                pool_ata: self.partner_pool_ata_a.as_deref(),
+                accrual: self.partner_accrual.as_deref_mut(),
+                accrual_bump: bumps.partner_accrual,
+            };
+            split = split.with_partner(partner.config()?)?;
+            pay_partner(&source, partner, &split, now, &signer_seeds)?;
+        }
+        let SettlementSplit {
+            platform_share,
+            insurance_share,
+            rebate,
+            premium,
+            buyer_amount,
+            ..
+        } = split;
+
+        if let (Some(receipt), Some(bump)) = (self.receipt.as_mut(), bumps.receipt) {
+            receipt.set_inner(Receipt::new(
+                bump,
+                self.escrow.key(),
+                &escrow,
+                self.taker.key(),
+                buyer_amount,
+                now,
+                self.authority.key(),
+            ));
+        }
+        let trade = SettledTrade::new(
+            self.escrow.key(),
+            &escrow,
+            self.taker.key(),
+            buyer_amount,
+            now,
+        );
+        if let (Some(history), Some(bump)) =
+            (self.initializer_history.as_mut(), bumps.initializer_history)
+        {
+            history.record(self.initializer.key(), bump, &trade)?;
+        }
+        if let (Some(history), Some(bump)) = (self.taker_history.as_mut(), bumps.taker_history) {
+            history.record(self.taker.key(), bump, &trade)?;
+        }
+        if rebate > 0 {
+            let pool_ata = self
+                .rebate_pool_ata_a
+                .as_ref()
+                .ok_or(EscrowError::MakerRebateAccountsMissing)?;
+            self.pull(pool_ata.to_account_info(), rebate, &signer_seeds)?;
+            let accrual = self
+                .maker_rebate
+                .as_mut()
+                .ok_or(EscrowError::MakerRebateAccountsMissing)?;
+            let bump = bumps
+                .maker_rebate
+                .ok_or(EscrowError::MakerRebateAccountsMissing)?;
+            accrual.init_if_new(self.initializer.key(), self.mint_a.key(), bump);
+            accrual.accrue(rebate, now)?;
+            emit!(MakerRebateAccrued {
+                escrow: self.escrow.key(),
+                maker: self.initializer.key(),
+                mint: self.mint_a.key(),
+                amount: rebate,
+            });
+        }
+        if premium > 0 {
+            emit!(RiskPremiumCharged {
+                escrow: self.escrow.key(),
+                initializer: self.initializer.key(),
+                loss_rate_bps,
+                amount: premium,
+            });
+        }
+        if insurance_share > 0 {
+            self.pull(self.insurance_ata_a.to_account_info(), insurance_share, &signer_seeds)?;
+            emit!(InsuranceFunded {
+                escrow: self.escrow.key(),
+                mint: self.mint_a.key(),
+                amount: insurance_share,
+            });
+        }
+        if platform_share > 0 {
+            self.pull(self.platform_ata_a.to_account_info(), platform_share, &signer_seeds)?;
+        }
+        if buyer_amount > 0 {
+            self.pull(self.taker_ata_a.to_account_info(), buyer_amount, &signer_seeds)?;
+        }
+        Ok(())
+    }
+
+    // Moves `amount` out of the maker's ATA under the escrow's delegation
+    fn pull(&self, to: AccountInfo<'info>, amount: u64, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+        let cpi_accounts = TransferChecked {
+            from: self.initializer_ata_a.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to,
+            authority: self.escrow.to_account_info(),
+        };
+        transfer_checked(
+            CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+                .with_signer(signer_seeds),
+            amount,
+            self.mint_a.decimals,
+        )
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::DeadlineExtended;
+use crate::states::Escrow;
+
+// Once a taker is in, the deadline is part of both sides' deal, so pushing it
+// back (say, for a bank transfer stuck in clearing) takes both signatures
+#[derive(Accounts)]
+pub struct ExtendDeadline<'info> {
+    pub initializer: Signer<'info>,
+    pub taker: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = taker,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+}
+
+impl<'info> ExtendDeadline<'info> {
+    // Also rescues an escrow that expired but hasn't been reaped yet
+    pub fn extend_deadline(&mut self, expires_at: i64) -> Result<()> {
+        let mut escrow = self.escrow.load_mut()?;
+        let old_expires_at = escrow.expires_at;
+        require!(
+            old_expires_at != 0 && expires_at > old_expires_at && expires_at > clock::now()?,
+            EscrowError::InvalidDeadlineExtension
+        );
+        escrow.expires_at = expires_at;
+        escrow.next_action_at = expires_at;
+
+        emit!(DeadlineExtended {
+            escrow: self.escrow.key(),
+            old_expires_at,
+            new_expires_at: expires_at,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,350 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::clock;
+use crate::constants::PLATFORM_WALLET;
+use crate::errors::EscrowError;
+use crate::events::{ForceReleased, InsuranceFunded, RiskPremiumCharged};
+use crate::fees::SettlementSplit;
+use crate::gating::require_compliance_approval;
+use crate::payout::{
+    accrue_maker_rebate, donate, pay_partner, PartnerAccounts, RebateAccounts, VaultAccounts,
+};
+use crate::states::{
+    ComplianceApproval, Config, DonationPledge, Escrow, MakerRebate, MakerRegistry,
+    PartnerAccrual, PartnerConfig, TraderStats,
+};
+
+// `exchange` on the taker's behalf once the seller has sat on a confirmed
+// payment past the auto-release window. Permissionless, so the taker or a
+// keeper can crank it; the payout only ever goes to the escrow's taker.
+#[derive(Accounts)]
+pub struct ForceRelease<'info> {
+    // Whoever cranks it; pays for the accruals the settlement opens
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    // Receives the vault and escrow rent
+    #[account(mut)]
+    pub initializer: SystemAccount<'info>,
+    // Gets the donation pledge's rent back
+    #[account(mut)]
+    pub taker: SystemAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program
+    )]
+    pub taker_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = platform_wallet,
+        associated_token::token_program = token_program
+    )]
+    pub platform_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = mint_a,
+        has_one = taker @ EscrowError::InvalidTaker,
+        constraint = escrow.load()?.is_funded() @ EscrowError::EscrowNotFunded,
+        constraint = escrow.load()?.is_immediate() @ EscrowError::ReleaseModeMismatch,
+        constraint = !escrow.load()?.is_frozen() @ EscrowError::EscrowFrozen,
+        constraint = !escrow.load()?.has_yield_position() @ EscrowError::YieldPositionOpen,
+        close = initializer,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Data-less PDA that owns the insurance fund token accounts
+    #[account(seeds = [b"insurance"], bump = config.insurance_bump)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program
+    )]
+    pub insurance_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Blocklist PDA of the taker; must not exist
+    #[account(
+        seeds = [b"blocked", taker.key().as_ref()],
+        bump,
+        constraint = taker_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub taker_block: UncheckedAccount<'info>,
+    // The initializer's profile, so the open-escrow count drops
+    #[account(
+        mut,
+        seeds = [b"trader_stats", escrow.load()?.initializer.as_ref()],
+        bump = initializer_stats.bump,
+    )]
+    pub initializer_stats: Box<Account<'info, TraderStats>>,
+    // The maker's registry, so the escrow drops off it
+    #[account(
+        mut,
+        seeds = [b"registry", escrow.load()?.initializer.as_ref()],
+        bump = maker_registry.bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    // Sign-off from `approve_release`, required while the config or the
+    // offer asks for compliance approval
+    #[account(
+        seeds = [b"compliance_approval", escrow.key().as_ref()],
+        bump = compliance_approval.bump,
+    )]
+    pub compliance_approval: Option<Box<Account<'info, ComplianceApproval>>>,
+    // Rebate pool and the maker's accrual, required while the config rebates mint_a
+    /// CHECK: Data-less PDA that owns the rebate pool token accounts
+    #[account(seeds = [b"rebate_pool"], bump)]
+    pub rebate_pool: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = rebate_pool,
+        associated_token::token_program = token_program
+    )]
+    pub rebate_pool_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MakerRebate::SPACE,
+        seeds = [b"maker_rebate", initializer.key().as_ref(), mint_a.key().as_ref()],
+        bump,
+    )]
+    pub maker_rebate: Option<Box<Account<'info, MakerRebate>>>,
+    // The taker's pledge and the pledged charity's account, required once
+    // the taker pledged
+    #[account(
+        mut,
+        close = taker,
+        seeds = [b"donation", escrow.key().as_ref()],
+        bump = donation.bump,
+    )]
+    pub donation: Option<Box<Account<'info, DonationPledge>>>,
+    #[account(mut, token::mint = mint_a)]
+    pub charity_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    // The partner the offer was created through and the partner fee wallet's
+    // mint_a account, required when the escrow has a partner
+    #[account(
+        seeds = [b"partner", escrow.load()?.partner_id.to_le_bytes().as_ref()],
+        bump = partner_config.bump,
+    )]
+    pub partner_config: Option<Box<Account<'info, PartnerConfig>>>,
+    #[account(mut, token::mint = mint_a)]
+    pub partner_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    // Partner pool and the partner's accrual, required while the partner
+    // takes a share of the platform fee
+    /// CHECK: Data-less PDA that owns the partner pool token accounts
+    #[account(seeds = [b"partner_pool"], bump)]
+    pub partner_pool: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = partner_pool,
+        associated_token::token_program = token_program
+    )]
+    pub partner_pool_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PartnerAccrual::SPACE,
+        seeds = [
+            b"partner_accrual",
+            escrow.load()?.partner_id.to_le_bytes().as_ref(),
+            mint_a.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub partner_accrual: Option<Box<Account<'info, PartnerAccrual>>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ForceRelease<'info> {
+    pub fn force_release(&mut self, bumps: &ForceReleaseBumps) -> Result<()> {
+        let escrow = *self.escrow.load()?;
+        require_compliance_approval(&self.config, &escrow, self.compliance_approval.as_deref())?;
+        let release_at = escrow
+            .auto_release_at(&self.config)
+            .ok_or(EscrowError::AutoReleaseNotDue)?;
+        let now = clock::now()?;
+        require!(now >= release_at, EscrowError::AutoReleaseNotDue);
+        // Priced on the record before this trade counts towards it. The
+        // seller's volume cap is left alone: it must not keep the taker's
+        // funds locked
+        let loss_rate_bps = self.initializer_stats.dispute_loss_rate_bps();
+        self.initializer_stats.track_settled();
+        self.maker_registry.remove(&self.escrow.key());
+        let signer_seeds: [&[&[u8]]; 1] =
+            [&[b"state", &escrow.seed.to_le_bytes()[..], &[escrow.bump]]];
+
+        // Split the same way `exchange` would have
+        let mut split = SettlementSplit::compute(
+            &self.config,
+            &self.mint_a.key(),
+            escrow.category,
+            escrow.initializer_amount,
+            loss_rate_bps,
+            now,
+        )?;
+        let vault = VaultAccounts {
+            escrow: &self.escrow.to_account_info(),
+            vault: &self.vault.to_account_info(),
+            mint: &self.mint_a.to_account_info(),
+            decimals: self.mint_a.decimals,
+            token_program: &self.token_program.to_account_info(),
+        };
+        if escrow.has_partner() {
+            let partner = PartnerAccounts {
+                config: self.partner_config.as_deref(),
+                fee_ata: self.partner_ata_a.as_deref(),
+                pool_ata: self.partner_pool_ata_a.as_deref(),
+                accrual: self.partner_accrual.as_deref_mut(),
+                accrual_bump: bumps.partner_accrual,
+            };
+            split = split.with_partner(partner.config()?)?;
+            pay_partner(&vault, partner, &split, now, &signer_seeds)?;
+        }
+        if escrow.has_donation_pledge() {
+            let pledge = self
+                .donation
+                .as_deref()
+                .ok_or(EscrowError::DonationPledgeMissing)?;
+            split = split.with_donation(pledge, self.mint_a.decimals)?;
+            if split.donation > 0 {
+                donate(
+                    &vault,
+                    Some(pledge),
+                    self.charity_ata_a.as_deref(),
+                    split.donation,
+                    &signer_seeds,
+                )?;
+            }
+        }
+        let SettlementSplit {
+            platform_share,
+            insurance_share,
+            rebate,
+            premium,
+            buyer_amount,
+            ..
+        } = split;
+        if rebate > 0 {
+            accrue_maker_rebate(
+                &vault,
+                RebateAccounts {
+                    pool_ata: self.rebate_pool_ata_a.as_deref(),
+                    accrual: self.maker_rebate.as_deref_mut(),
+                    accrual_bump: bumps.maker_rebate,
+                },
+                self.initializer.key(),
+                rebate,
+                now,
+                &signer_seeds,
+            )?;
+        }
+        if premium > 0 {
+            emit!(RiskPremiumCharged {
+                escrow: self.escrow.key(),
+                initializer: self.initializer.key(),
+                loss_rate_bps,
+                amount: premium,
+            });
+        }
+
+        // The platform fee, less the insurance fund's slice
+        if insurance_share > 0 {
+            transfer_checked(
+                self.into_insurance_fee_context().with_signer(&signer_seeds),
+                insurance_share,
+                self.mint_a.decimals,
+            )?;
+            emit!(InsuranceFunded {
+                escrow: self.escrow.key(),
+                mint: self.mint_a.key(),
+                amount: insurance_share,
+            });
+        }
+        if platform_share > 0 {
+            transfer_checked(
+                self.into_platform_fee_context().with_signer(&signer_seeds),
+                platform_share,
+                self.mint_a.decimals,
+            )?;
+        }
+
+        if buyer_amount > 0 {
+            transfer_checked(
+                self.into_withdraw_context().with_signer(&signer_seeds),
+                buyer_amount,
+                self.mint_a.decimals,
+            )?;
+        }
+
+        emit!(ForceReleased {
+            escrow: self.escrow.key(),
+            taker: escrow.taker,
+            amount: buyer_amount,
+        });
+
+        close_account(self.into_close_context().with_signer(&signer_seeds))
+    }
+
+    fn into_withdraw_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.taker_ata_a.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_platform_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.platform_ata_a.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_insurance_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.insurance_ata_a.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_close_context(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        let cpi_accounts = CloseAccount {
+            account: self.vault.to_account_info(),
+            destination: self.initializer.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
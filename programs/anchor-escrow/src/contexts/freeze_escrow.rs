@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::errors::EscrowError;
+use crate::events::EscrowFrozen;
+use crate::states::{AdminAction, AuditLog, Config, Escrow};
+
+#[derive(Accounts)]
+pub struct FreezeEscrow<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        mut,
+        constraint = !escrow.load()?.is_frozen() @ EscrowError::EscrowFrozen,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FreezeEscrow<'info> {
+    // `reason_hash` points at the off-chain fraud report
+    pub fn freeze_escrow(
+        &mut self,
+        bumps: &FreezeEscrowBumps,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        self.escrow.load_mut()?.flags |= Escrow::FLAG_FROZEN;
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::FreezeEscrow,
+            self.admin.key(),
+            self.escrow.key(),
+            &reason_hash,
+        )?;
+
+        emit!(EscrowFrozen {
+            escrow: self.escrow.key(),
+            admin: self.admin.key(),
+            reason_hash,
+        });
+        Ok(())
+    }
+}
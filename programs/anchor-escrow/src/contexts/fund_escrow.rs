@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::errors::EscrowError;
+use crate::invariants::{
+    require_deposit_received, require_status_transition, require_vault_backs_escrow,
+};
+use crate::states::{Escrow, EscrowStatus};
+
+// The funder does not have to be the initializer, e.g. a gift or a company
+// treasury funding an employee's listing; a third-party funder is recorded
+// and is who a cancellation refunds
+#[derive(Accounts)]
+pub struct FundEscrow<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = funder,
+        associated_token::token_program = token_program
+    )]
+    pub funder_ata_a: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        has_one = mint_a,
+        constraint = escrow.load()?.status == EscrowStatus::Created as u8 @ EscrowError::EscrowAlreadyFunded,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        init_if_needed,
+        payer = funder,
+        associated_token::mint = mint_a,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> FundEscrow<'info> {
+    pub fn fund_escrow(&mut self) -> Result<()> {
+        let initializer_amount = self.escrow.load()?.initializer_amount;
+        let before = self.vault.amount;
+        transfer_checked(
+            self.into_deposit_context(),
+            initializer_amount,
+            self.mint_a.decimals,
+        )?;
+        let received = require_deposit_received(&mut self.vault, before, initializer_amount)?;
+        let mut escrow = self.escrow.load_mut()?;
+        let status = escrow.status;
+        escrow.settle_deposit(received)?;
+        escrow.status = EscrowStatus::Funded as u8;
+        if self.funder.key() != escrow.initializer {
+            escrow.funder = self.funder.key();
+        }
+        require_status_transition(status, &escrow)?;
+        require_vault_backs_escrow(&escrow, &mut self.vault)
+    }
+
+    fn into_deposit_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.funder_ata_a.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.funder.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+
+use crate::constants::{BPS_DENOMINATOR, PLATFORM_WALLET};
+use crate::errors::EscrowError;
+use crate::events::EscrowGarbageCollected;
+use crate::fees::pro_rata;
+use crate::states::{
+    AttestationRequirements, ComplianceApproval, Config, DonationPledge, FiatQuotes, MakerRegistry,
+    MessageLog, Milestone, MilestoneDispute, PaymentDetails, RegionPolicy,
+};
+
+// Permissionless once the escrow account is gone (settled or cancelled).
+// Remaining accounts are the escrow's leftover auxiliary PDAs: offer
+// attestations, region policy, payment details, message log, donation
+// pledge, compliance approval, fiat quotes, milestones and milestone disputes, each
+// dispute after its milestone. Bonds and maker deposits hold funds and have their own release
+// paths. None of these record who paid their rent, so it goes to the
+// config's destinations instead: the cranker's bounty share, and the
+// platform wallet.
+#[derive(Accounts)]
+pub struct GcEscrow<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    /// CHECK: Must no longer hold escrow state
+    #[account(constraint = escrow.data_is_empty() @ EscrowError::EscrowNotSettled)]
+    pub escrow: UncheckedAccount<'info>,
+    // The former maker's registry, in case the escrow was closed before
+    // every close path removed it
+    #[account(
+        mut,
+        seeds = [b"registry", maker_registry.maker.as_ref()],
+        bump = maker_registry.bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(mut, address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+}
+
+impl<'info> GcEscrow<'info> {
+    pub fn gc_escrow(&mut self, remaining_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        require!(!remaining_accounts.is_empty(), EscrowError::InvalidGcAccount);
+        let escrow = self.escrow.key();
+        self.maker_registry.remove(&escrow);
+        let mut milestones: Vec<Pubkey> = Vec::with_capacity(remaining_accounts.len());
+        let mut reclaimed = 0u64;
+
+        for info in remaining_accounts {
+            require_keys_eq!(*info.owner, crate::ID, EscrowError::InvalidGcAccount);
+            let expected = {
+                let data = info.try_borrow_data()?;
+                let discriminator = data.get(..8).ok_or(EscrowError::InvalidGcAccount)?;
+                if discriminator == AttestationRequirements::DISCRIMINATOR {
+                    let bump = AttestationRequirements::try_deserialize(&mut &data[..])?.bump;
+                    derive(&[b"attestations", escrow.as_ref(), &[bump]])?
+                } else if discriminator == RegionPolicy::DISCRIMINATOR {
+                    let bump = RegionPolicy::try_deserialize(&mut &data[..])?.bump;
+                    derive(&[b"regions", escrow.as_ref(), &[bump]])?
+                } else if discriminator == PaymentDetails::DISCRIMINATOR {
+                    let bump = PaymentDetails::try_deserialize(&mut &data[..])?.bump;
+                    derive(&[b"payment_details", escrow.as_ref(), &[bump]])?
+                } else if discriminator == MessageLog::DISCRIMINATOR {
+                    let bump = MessageLog::try_deserialize(&mut &data[..])?.bump;
+                    derive(&[b"messages", escrow.as_ref(), &[bump]])?
+                } else if discriminator == DonationPledge::DISCRIMINATOR {
+                    let bump = DonationPledge::try_deserialize(&mut &data[..])?.bump;
+                    derive(&[b"donation", escrow.as_ref(), &[bump]])?
+                } else if discriminator == ComplianceApproval::DISCRIMINATOR {
+                    let bump = ComplianceApproval::try_deserialize(&mut &data[..])?.bump;
+                    derive(&[b"compliance_approval", escrow.as_ref(), &[bump]])?
+                } else if discriminator == FiatQuotes::DISCRIMINATOR {
+                    let bump = FiatQuotes::try_deserialize(&mut &data[..])?.bump;
+                    derive(&[b"fiat_quotes", escrow.as_ref(), &[bump]])?
+                } else if discriminator == Milestone::DISCRIMINATOR {
+                    let milestone = Milestone::try_deserialize(&mut &data[..])?;
+                    let address = derive(&[
+                        b"milestone",
+                        escrow.as_ref(),
+                        &[milestone.index],
+                        &[milestone.bump],
+                    ])?;
+                    milestones.push(address);
+                    address
+                } else if discriminator == MilestoneDispute::DISCRIMINATOR {
+                    let dispute = MilestoneDispute::try_deserialize(&mut &data[..])?;
+                    require!(
+                        milestones.contains(&dispute.milestone),
+                        EscrowError::InvalidGcAccount
+                    );
+                    derive(&[
+                        b"milestone_dispute",
+                        dispute.milestone.as_ref(),
+                        &[dispute.bump],
+                    ])?
+                } else {
+                    return err!(EscrowError::InvalidGcAccount);
+                }
+            };
+            require_keys_eq!(info.key(), expected, EscrowError::InvalidGcAccount);
+
+            reclaimed = reclaimed
+                .checked_add(info.lamports())
+                .ok_or(EscrowError::MathOverflow)?;
+            **info.try_borrow_mut_lamports()? = 0;
+            info.assign(&system_program::ID);
+            info.resize(0)?;
+        }
+
+        let bounty = pro_rata(
+            reclaimed,
+            self.config.gc_bounty_bps as u64,
+            BPS_DENOMINATOR as u64,
+        )?;
+        **self.cranker.try_borrow_mut_lamports()? += bounty;
+        **self.platform_wallet.try_borrow_mut_lamports()? += reclaimed - bounty;
+
+        emit!(EscrowGarbageCollected {
+            escrow,
+            cranker: self.cranker.key(),
+            accounts: remaining_accounts.len() as u8,
+            reclaimed,
+            bounty,
+        });
+        Ok(())
+    }
+}
+
+fn derive(seeds: &[&[u8]]) -> Result<Pubkey> {
+    Pubkey::create_program_address(seeds, &crate::ID)
+        .map_err(|_| error!(EscrowError::InvalidGcAccount))
+}
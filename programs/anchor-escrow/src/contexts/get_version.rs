@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PROGRAM_VERSION;
+use crate::states::{Config, Escrow};
+
+// Build and account layouts the deployed program speaks
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ProgramVersion {
+    pub major: u8,
+    pub minor: u8,
+    pub patch: u8,
+    // Escrow::VERSION and Config::VERSION
+    pub escrow_layout: u8,
+    pub config_layout: u8,
+}
+
+#[derive(Accounts)]
+pub struct GetVersion {}
+
+impl GetVersion {
+    pub fn get_version(&self) -> Result<ProgramVersion> {
+        let [major, minor, patch] = PROGRAM_VERSION;
+        Ok(ProgramVersion {
+            major,
+            minor,
+            patch,
+            escrow_layout: Escrow::VERSION,
+            config_layout: Config::VERSION,
+        })
+    }
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::PriceHidden;
+use crate::states::Escrow;
+
+// Takes the price off the order book until a taker reveals it on acceptance
+#[derive(Accounts)]
+pub struct HidePrice<'info> {
+    pub initializer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+}
+
+impl<'info> HidePrice<'info> {
+    // The maker hands the price and salt to takers off-chain. Only the price
+    // is hidden; the size is the vault balance, which anyone can read.
+    pub fn hide_price(&mut self, commitment: [u8; 32]) -> Result<()> {
+        require!(commitment != [0; 32], EscrowError::InvalidPriceCommitment);
+
+        let mut escrow = self.escrow.load_mut()?;
+        escrow.price_commitment = commitment;
+        escrow.taker_amount = 0;
+
+        emit!(PriceHidden {
+            escrow: self.escrow.key(),
+            commitment,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::errors::EscrowError;
+use crate::fees::pro_rata;
+use crate::invariants::{require_deposit_received, require_vault_backs_escrow};
+use crate::states::Escrow;
+
+#[derive(Accounts)]
+pub struct IncreaseAmount<'info> {
+    pub initializer: Signer<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
+    )]
+    pub initializer_ata_a: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = mint_a,
+        constraint = escrow.load()?.is_funded() @ EscrowError::EscrowNotFunded,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> IncreaseAmount<'info> {
+    pub fn increase_amount(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, EscrowError::InvalidAmount);
+
+        let before = self.vault.amount;
+        transfer_checked(self.into_deposit_context(), amount, self.mint_a.decimals)?;
+        let received = require_deposit_received(&mut self.vault, before, amount)?;
+
+        let mut escrow = self.escrow.load_mut()?;
+        let new_amount = escrow
+            .initializer_amount
+            .checked_add(received)
+            .ok_or(EscrowError::MathOverflow)?;
+        // Keep the unit price: taker_amount grows in proportion to the added size
+        if escrow.initializer_amount > 0 {
+            escrow.taker_amount =
+                pro_rata(escrow.taker_amount, new_amount, escrow.initializer_amount)?;
+        }
+        escrow.initializer_amount = new_amount;
+        require_vault_backs_escrow(&escrow, &mut self.vault)
+    }
+
+    fn into_deposit_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.initializer_ata_a.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.initializer.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -1,72 +1,224 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{transfer_checked, Mint, Token, TokenAccount, TransferChecked},
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
 };
 
-use crate::states::Escrow;
+use crate::clock;
+use crate::constants::PLATFORM_WALLET;
+use crate::errors::EscrowError;
+use crate::fees::charge_creation_fee;
+use crate::gating::{require_gateway_pass, require_stablecoin_market};
+use crate::invariants::{require_deposit_received, require_vault_backs_escrow};
+use crate::states::{
+    Config, Escrow, EscrowCounter, EscrowStatus, IdempotencyRecord, MakerRegistry, PartnerConfig,
+    StablecoinList, TraderStats,
+};
 
 #[derive(Accounts)]
-#[instruction(seed: u64, initializer_amount: u64)]
+#[instruction(
+    seed: u64,
+    initializer_amount: u64,
+    taker_amount: u64,
+    terms_hash: [u8; 32],
+    tags: u32,
+    category: u8,
+    note: [u8; 64],
+    idempotency_key: [u8; 16],
+)]
 pub struct Initialize<'info> {
     #[account(mut)]
     pub initializer: Signer<'info>,
-    pub mint_a: Account<'info, Mint>,
-    pub mint_b: Account<'info, Mint>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    pub mint_b: InterfaceAccount<'info, Mint>,
     #[account(
         mut,
         constraint = initializer_ata_a.amount >= initializer_amount,
         associated_token::mint = mint_a,
-        associated_token::authority = initializer
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
     )]
-    pub initializer_ata_a: Account<'info, TokenAccount>,
+    pub initializer_ata_a: InterfaceAccount<'info, TokenAccount>,
     #[account(
-        init_if_needed,
-        payer = initializer,
-        space = Escrow::INIT_SPACE,
+        init,
+        payer = payer,
+        space = Escrow::SPACE,
         seeds = [b"state".as_ref(), &seed.to_le_bytes()],
         bump
     )]
-    pub escrow: Account<'info, Escrow>,
+    pub escrow: AccountLoader<'info, Escrow>,
     #[account(
         init_if_needed,
-        payer = initializer,
+        payer = payer,
         associated_token::mint = mint_a,
-        associated_token::authority = escrow
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Civic gateway token of the initializer, verified when the market is gated
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+    // Required while the config is in stablecoin-only mode
+    #[account(seeds = [b"stablecoins"], bump = stablecoins.bump)]
+    pub stablecoins: Option<Box<Account<'info, StablecoinList>>>,
+    /// CHECK: Blocklist PDA of the initializer; must not exist
+    #[account(
+        seeds = [b"blocked", initializer.key().as_ref()],
+        bump,
+        constraint = initializer_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub initializer_block: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TraderStats::SPACE,
+        seeds = [b"trader_stats", initializer.key().as_ref()],
+        bump,
+    )]
+    pub initializer_stats: Box<Account<'info, TraderStats>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = EscrowCounter::SPACE,
+        seeds = [b"escrow_counter"],
+        bump,
+    )]
+    pub escrow_counter: Box<Account<'info, EscrowCounter>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MakerRegistry::SPACE,
+        seeds = [b"registry", initializer.key().as_ref()],
+        bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    // Required with a nonzero idempotency key; a retry carrying the same key
+    // fails here instead of opening a second escrow
+    #[account(
+        init,
+        payer = payer,
+        space = IdempotencyRecord::SPACE,
+        seeds = [b"idempotency", initializer.key().as_ref(), idempotency_key.as_ref()],
+        bump,
+    )]
+    pub idempotency: Option<Box<Account<'info, IdempotencyRecord>>>,
+    // The white-label frontend the offer is created through, if any
+    #[account(
+        seeds = [b"partner", partner_config.partner_id.to_le_bytes().as_ref()],
+        bump = partner_config.bump,
     )]
-    pub vault: Account<'info, TokenAccount>,
+    pub partner_config: Option<Box<Account<'info, PartnerConfig>>>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(mut, address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
     pub associated_token_program: Program<'info, AssociatedToken>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
 impl<'info> Initialize<'info> {
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_escrow(
         &mut self,
         seed: u64,
         bumps: &InitializeBumps,
         initializer_amount: u64,
         taker_amount: u64,
+        terms_hash: [u8; 32],
+        tags: u32,
+        category: u8,
+        note: [u8; 64],
+        idempotency_key: [u8; 16],
     ) -> Result<()> {
-        self.escrow.set_inner(Escrow {
+        match (self.idempotency.as_mut(), bumps.idempotency) {
+            (Some(record), Some(bump)) => {
+                require!(
+                    idempotency_key != [0; 16],
+                    EscrowError::InvalidIdempotencyKey
+                );
+                record.set_inner(IdempotencyRecord {
+                    version: IdempotencyRecord::VERSION,
+                    bump,
+                    maker: self.initializer.key(),
+                    idempotency_key,
+                    escrow: self.escrow.key(),
+                    rent_payer: self.payer.key(),
+                    created_at: clock::now()?,
+                    _reserved: [0; 16],
+                });
+            }
+            _ => require!(
+                idempotency_key == [0; 16],
+                EscrowError::InvalidIdempotencyKey
+            ),
+        }
+        require_gateway_pass(
+            &self.config,
+            self.gateway_token.as_deref(),
+            &self.initializer.key(),
+        )?;
+        require_stablecoin_market(
+            &self.config,
+            self.stablecoins.as_deref(),
+            &self.mint_a.key(),
+            &self.mint_b.key(),
+        )?;
+        self.initializer_stats
+            .init_if_new(self.initializer.key(), bumps.initializer_stats);
+        self.initializer_stats.track_opened(1, &self.config)?;
+        self.maker_registry
+            .init_if_new(self.initializer.key(), bumps.maker_registry);
+        self.maker_registry.add(self.escrow.key())?;
+        let fee_held = charge_creation_fee(
+            &self.config,
+            self.payer.to_account_info(),
+            self.escrow.to_account_info(),
+            self.platform_wallet.to_account_info(),
+            self.system_program.to_account_info(),
+        )?;
+        let mut escrow = Escrow::new(
             seed,
-            bump: bumps.escrow,
-            initializer: self.initializer.key(),
-            mint_a: self.mint_a.key(),
-            mint_b: self.mint_b.key(),
+            bumps.escrow,
+            self.initializer.key(),
+            self.mint_a.key(),
+            self.mint_b.key(),
             initializer_amount,
             taker_amount,
-            payment_confirmed: false,
-        });
+        );
+        escrow.terms_hash = terms_hash;
+        escrow.tags = tags;
+        escrow.category = category;
+        escrow.note = note;
+        if let Some(partner) = self.partner_config.as_deref() {
+            escrow.partner_id = partner.partner_id;
+        }
+        self.escrow_counter.init_if_new(bumps.escrow_counter);
+        escrow.sequence = self.escrow_counter.next_sequence()?;
+        if fee_held {
+            escrow.flags |= Escrow::FLAG_CREATION_FEE_HELD;
+        }
+        *self.escrow.load_init()? = escrow;
         Ok(())
     }
 
     pub fn deposit(&mut self, initializer_amount: u64) -> Result<()> {
+        let before = self.vault.amount;
         transfer_checked(
             self.into_deposit_context(),
             initializer_amount,
             self.mint_a.decimals,
-        )
+        )?;
+        let received = require_deposit_received(&mut self.vault, before, initializer_amount)?;
+        let mut escrow = self.escrow.load_mut()?;
+        escrow.settle_deposit(received)?;
+        escrow.status = EscrowStatus::Funded as u8;
+        require_vault_backs_escrow(&escrow, &mut self.vault)
     }
 
     fn into_deposit_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_spl::token_2022::spl_token_2022::extension::ExtensionType;
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_2022::{initialize_mint2, InitializeMint2, Token2022};
+use anchor_spl::token_2022_extensions::{
+    non_transferable_mint_initialize, NonTransferableMintInitialize,
+};
+
+use crate::audit::record_admin_action;
+use crate::errors::EscrowError;
+use crate::states::{AdminAction, AuditLog, Config};
+
+// Creates the Token-2022 "trade completed" badge mint at [b"badge_mint"]:
+// non-transferable, no decimals, minted only by the [b"badge_authority"] PDA
+#[derive(Accounts)]
+pub struct InitializeBadgeMint<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Created and initialized as the badge mint here
+    #[account(mut, seeds = [b"badge_mint"], bump)]
+    pub badge_mint: UncheckedAccount<'info>,
+    /// CHECK: Data-less PDA that mints badges
+    #[account(seeds = [b"badge_authority"], bump)]
+    pub badge_authority: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeBadgeMint<'info> {
+    pub fn initialize_badge_mint(&mut self, bumps: &InitializeBadgeMintBumps) -> Result<()> {
+        let space = ExtensionType::try_calculate_account_len::<MintState>(&[
+            ExtensionType::NonTransferable,
+        ])?;
+        let signer_seeds: [&[&[u8]]; 1] = [&[b"badge_mint", &[bumps.badge_mint]]];
+        create_account(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                CreateAccount {
+                    from: self.admin.to_account_info(),
+                    to: self.badge_mint.to_account_info(),
+                },
+            )
+            .with_signer(&signer_seeds),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &self.token_program.key(),
+        )?;
+        // Extensions have to be in place before the mint is initialized
+        non_transferable_mint_initialize(CpiContext::new(
+            self.token_program.to_account_info(),
+            NonTransferableMintInitialize {
+                token_program_id: self.token_program.to_account_info(),
+                mint: self.badge_mint.to_account_info(),
+            },
+        ))?;
+        initialize_mint2(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                InitializeMint2 {
+                    mint: self.badge_mint.to_account_info(),
+                },
+            ),
+            0,
+            &self.badge_authority.key(),
+            None,
+        )?;
+
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::InitializeBadgeMint,
+            self.admin.key(),
+            self.badge_mint.key(),
+            &(),
+        )
+    }
+}
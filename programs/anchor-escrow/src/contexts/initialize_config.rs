@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::constants::{BPS_DENOMINATOR, MAX_FEE_CATEGORIES};
+use crate::errors::EscrowError;
+use crate::program::AnchorEscrow;
+use crate::states::{AdminAction, AuditLog, Config};
+
+// Only the program's upgrade authority can bootstrap the config
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        init,
+        payer = admin,
+        space = Config::SPACE,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Data-less PDA that owns the insurance fund token accounts
+    #[account(seeds = [b"insurance"], bump)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, AnchorEscrow>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ EscrowError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeConfig<'info> {
+    pub fn initialize_config(
+        &mut self,
+        bumps: &InitializeConfigBumps,
+        insurance_fee_bps: u16,
+    ) -> Result<()> {
+        require!(
+            insurance_fee_bps <= BPS_DENOMINATOR,
+            EscrowError::InvalidFeeBps
+        );
+        self.config.set_inner(Config {
+            version: Config::VERSION,
+            bump: bumps.config,
+            insurance_bump: bumps.insurance_fund,
+            insurance_fee_bps,
+            admin: self.admin.key(),
+            arbitration_fee_bps: 0,
+            maker_deposit_bps: 0,
+            max_cancel_strikes: 0,
+            gatekeeper_network: Pubkey::default(),
+            attestations_required: false,
+            daily_volume_limit: 0,
+            weekly_volume_limit: 0,
+            max_open_escrows: 0,
+            creation_fee_lamports: 0,
+            refund_creation_fee: false,
+            auto_release_after_hours: 0,
+            _reserved: [0; 1],
+            promo_fee_percentage: 0,
+            promo_starts_at: 0,
+            promo_ends_at: 0,
+            maker_rebate_bps: 0,
+            rebate_mint: Pubkey::default(),
+            risk_premium_max_bps: 0,
+            gc_bounty_bps: 0,
+            category_fee_overrides: 0,
+            category_fee_percentages: [0; MAX_FEE_CATEGORIES],
+            _reserved_v2: [0; 8],
+            features: Config::DEFAULT_FEATURES,
+            max_rate_deviation_bps: 0,
+            stablecoin_only: false,
+            yield_maker_share_bps: 0,
+            compliance_required: false,
+            dispute_fee: 0,
+            reap_grace_period: 0,
+            _reserved_v3: [0; 14],
+        });
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::InitializeConfig,
+            self.admin.key(),
+            self.config.key(),
+            &insurance_fee_bps,
+        )
+    }
+}
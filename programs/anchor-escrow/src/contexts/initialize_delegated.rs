@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{approve, Approve, Mint, TokenAccount, TokenInterface};
+
+use crate::constants::PLATFORM_WALLET;
+use crate::errors::EscrowError;
+use crate::fees::charge_creation_fee;
+use crate::gating::{require_gateway_pass, require_stablecoin_market};
+use crate::states::{Config, Escrow, EscrowStatus, MakerRegistry, StablecoinList, TraderStats};
+
+// `initialize` for makers who won't use a custodial vault: instead of a
+// deposit, the escrow PDA is approved as delegate on the maker's mint_a ATA.
+// The offer stays live only while that approval and balance last; revoking
+// is how a delegated offer is cancelled.
+#[derive(Accounts)]
+#[instruction(seed: u64, initializer_amount: u64)]
+pub struct InitializeDelegated<'info> {
+    pub initializer: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        constraint = initializer_ata_a.amount >= initializer_amount,
+        associated_token::mint = mint_a,
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
+    )]
+    pub initializer_ata_a: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = Escrow::SPACE,
+        seeds = [b"state".as_ref(), &seed.to_le_bytes()],
+        bump
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.has_feature(Config::FEATURE_DELEGATED) @ EscrowError::FeatureDisabled,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Civic gateway token of the initializer, verified when the market is gated
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+    // Required while the config is in stablecoin-only mode
+    #[account(seeds = [b"stablecoins"], bump = stablecoins.bump)]
+    pub stablecoins: Option<Box<Account<'info, StablecoinList>>>,
+    /// CHECK: Blocklist PDA of the initializer; must not exist
+    #[account(
+        seeds = [b"blocked", initializer.key().as_ref()],
+        bump,
+        constraint = initializer_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub initializer_block: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TraderStats::SPACE,
+        seeds = [b"trader_stats", initializer.key().as_ref()],
+        bump,
+    )]
+    pub initializer_stats: Box<Account<'info, TraderStats>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MakerRegistry::SPACE,
+        seeds = [b"registry", initializer.key().as_ref()],
+        bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(mut, address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeDelegated<'info> {
+    pub fn initialize_delegated(
+        &mut self,
+        seed: u64,
+        bumps: &InitializeDelegatedBumps,
+        initializer_amount: u64,
+        taker_amount: u64,
+        terms_hash: [u8; 32],
+    ) -> Result<()> {
+        require_gateway_pass(
+            &self.config,
+            self.gateway_token.as_deref(),
+            &self.initializer.key(),
+        )?;
+        require_stablecoin_market(
+            &self.config,
+            self.stablecoins.as_deref(),
+            &self.mint_a.key(),
+            &self.mint_b.key(),
+        )?;
+        self.initializer_stats
+            .init_if_new(self.initializer.key(), bumps.initializer_stats);
+        self.initializer_stats.track_opened(1, &self.config)?;
+        self.maker_registry
+            .init_if_new(self.initializer.key(), bumps.maker_registry);
+        self.maker_registry.add(self.escrow.key())?;
+        let fee_held = charge_creation_fee(
+            &self.config,
+            self.payer.to_account_info(),
+            self.escrow.to_account_info(),
+            self.platform_wallet.to_account_info(),
+            self.system_program.to_account_info(),
+        )?;
+
+        // Replaces any earlier delegation on this ATA, which invalidates the
+        // delegated escrow it backed
+        approve(self.into_approve_context(), initializer_amount)?;
+
+        let mut escrow = Escrow::new(
+            seed,
+            bumps.escrow,
+            self.initializer.key(),
+            self.mint_a.key(),
+            self.mint_b.key(),
+            initializer_amount,
+            taker_amount,
+        );
+        escrow.terms_hash = terms_hash;
+        // Funded in the sense that takers can act on it; the tokens are pulled at settlement
+        escrow.status = EscrowStatus::Funded as u8;
+        escrow.flags |= Escrow::FLAG_DELEGATED;
+        if fee_held {
+            escrow.flags |= Escrow::FLAG_CREATION_FEE_HELD;
+        }
+        *self.escrow.load_init()? = escrow;
+        Ok(())
+    }
+
+    fn into_approve_context(&self) -> CpiContext<'_, '_, '_, 'info, Approve<'info>> {
+        let cpi_accounts = Approve {
+            to: self.initializer_ata_a.to_account_info(),
+            delegate: self.escrow.to_account_info(),
+            authority: self.initializer.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
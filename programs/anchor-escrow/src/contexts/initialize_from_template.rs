@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::clock;
+use crate::constants::PLATFORM_WALLET;
+use crate::errors::EscrowError;
+use crate::events::EscrowPostedFromTemplate;
+use crate::fees::charge_creation_fee;
+use crate::gating::{require_gateway_pass, require_stablecoin_market};
+use crate::invariants::require_deposit_received;
+use crate::states::{
+    Config, Escrow, EscrowStatus, MakerRegistry, OfferTemplate, StablecoinList, TraderStats,
+};
+
+// `initialize` priced and configured by one of the maker's templates
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct InitializeFromTemplate<'info> {
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        has_one = mint_a,
+        has_one = mint_b,
+        seeds = [b"template", initializer.key().as_ref(), &template.template_id.to_le_bytes()],
+        bump = template.bump,
+    )]
+    pub template: Box<Account<'info, OfferTemplate>>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
+    )]
+    pub initializer_ata_a: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = Escrow::SPACE,
+        seeds = [b"state".as_ref(), &seed.to_le_bytes()],
+        bump
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint_a,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Civic gateway token of the initializer, verified when the market is gated
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+    // Required while the config is in stablecoin-only mode
+    #[account(seeds = [b"stablecoins"], bump = stablecoins.bump)]
+    pub stablecoins: Option<Box<Account<'info, StablecoinList>>>,
+    /// CHECK: Blocklist PDA of the initializer; must not exist
+    #[account(
+        seeds = [b"blocked", initializer.key().as_ref()],
+        bump,
+        constraint = initializer_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub initializer_block: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TraderStats::SPACE,
+        seeds = [b"trader_stats", initializer.key().as_ref()],
+        bump,
+    )]
+    pub initializer_stats: Box<Account<'info, TraderStats>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MakerRegistry::SPACE,
+        seeds = [b"registry", initializer.key().as_ref()],
+        bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(mut, address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeFromTemplate<'info> {
+    pub fn initialize_from_template(
+        &mut self,
+        seed: u64,
+        bumps: &InitializeFromTemplateBumps,
+        initializer_amount: u64,
+    ) -> Result<()> {
+        let terms = self.template.terms;
+        terms.check_amount(initializer_amount)?;
+        require_gateway_pass(
+            &self.config,
+            self.gateway_token.as_deref(),
+            &self.initializer.key(),
+        )?;
+        require_stablecoin_market(
+            &self.config,
+            self.stablecoins.as_deref(),
+            &self.mint_a.key(),
+            &self.mint_b.key(),
+        )?;
+        self.initializer_stats
+            .init_if_new(self.initializer.key(), bumps.initializer_stats);
+        self.initializer_stats.track_opened(1, &self.config)?;
+        self.maker_registry
+            .init_if_new(self.initializer.key(), bumps.maker_registry);
+        self.maker_registry.add(self.escrow.key())?;
+        let fee_held = charge_creation_fee(
+            &self.config,
+            self.payer.to_account_info(),
+            self.escrow.to_account_info(),
+            self.platform_wallet.to_account_info(),
+            self.system_program.to_account_info(),
+        )?;
+
+        let mut escrow = Escrow::new(
+            seed,
+            bumps.escrow,
+            self.initializer.key(),
+            self.mint_a.key(),
+            self.mint_b.key(),
+            initializer_amount,
+            terms.taker_amount(initializer_amount)?,
+        );
+        escrow.payment_methods = terms.payment_methods;
+        escrow.auto_release_after = terms.auto_release_after;
+        if terms.expires_after > 0 {
+            escrow.expires_at = clock::now()?.saturating_add(terms.expires_after);
+            escrow.next_action_at = escrow.expires_at;
+        }
+        if fee_held {
+            escrow.flags |= Escrow::FLAG_CREATION_FEE_HELD;
+        }
+        require!(
+            self.initializer_ata_a.amount >= escrow.initializer_amount,
+            EscrowError::AmountExceedsEscrow
+        );
+        let before = self.vault.amount;
+        transfer_checked(
+            self.into_deposit_context(),
+            escrow.initializer_amount,
+            self.mint_a.decimals,
+        )?;
+        let received =
+            require_deposit_received(&mut self.vault, before, escrow.initializer_amount)?;
+        escrow.settle_deposit(received)?;
+        escrow.status = EscrowStatus::Funded as u8;
+        *self.escrow.load_init()? = escrow;
+
+        emit!(EscrowPostedFromTemplate {
+            template: self.template.key(),
+            escrow: self.escrow.key(),
+            initializer: self.initializer.key(),
+            taker_amount: escrow.taker_amount,
+        });
+        Ok(())
+    }
+
+    fn into_deposit_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.initializer_ata_a.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.initializer.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
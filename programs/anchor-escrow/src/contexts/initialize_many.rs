@@ -0,0 +1,225 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_spl::{
+    associated_token::{create_idempotent, AssociatedToken, Create},
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::constants::{MAX_BATCH_SIZE, PLATFORM_WALLET};
+use crate::errors::EscrowError;
+use crate::fees::charge_creation_fee;
+use crate::gating::{require_gateway_pass, require_stablecoin_market};
+use crate::invariants::deposit_received;
+use crate::states::{Config, Escrow, EscrowStatus, MakerRegistry, StablecoinList, TraderStats};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct EscrowTerms {
+    pub seed: u64,
+    pub initializer_amount: u64,
+    pub taker_amount: u64,
+    pub terms_hash: [u8; 32],
+}
+
+// Remaining accounts carry one writable (escrow, vault) pair per entry, in
+// the same order as the terms.
+#[derive(Accounts)]
+pub struct InitializeMany<'info> {
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
+    )]
+    pub initializer_ata_a: InterfaceAccount<'info, TokenAccount>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Civic gateway token of the initializer, verified when the market is gated
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+    // Required while the config is in stablecoin-only mode
+    #[account(seeds = [b"stablecoins"], bump = stablecoins.bump)]
+    pub stablecoins: Option<Box<Account<'info, StablecoinList>>>,
+    /// CHECK: Blocklist PDA of the initializer; must not exist
+    #[account(
+        seeds = [b"blocked", initializer.key().as_ref()],
+        bump,
+        constraint = initializer_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub initializer_block: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TraderStats::SPACE,
+        seeds = [b"trader_stats", initializer.key().as_ref()],
+        bump,
+    )]
+    pub initializer_stats: Box<Account<'info, TraderStats>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MakerRegistry::SPACE,
+        seeds = [b"registry", initializer.key().as_ref()],
+        bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(mut, address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeMany<'info> {
+    pub fn initialize_many(
+        &mut self,
+        bumps: &InitializeManyBumps,
+        terms: &[EscrowTerms],
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        require_gateway_pass(
+            &self.config,
+            self.gateway_token.as_deref(),
+            &self.initializer.key(),
+        )?;
+        require_stablecoin_market(
+            &self.config,
+            self.stablecoins.as_deref(),
+            &self.mint_a.key(),
+            &self.mint_b.key(),
+        )?;
+        require!(
+            !terms.is_empty() && terms.len() <= MAX_BATCH_SIZE,
+            EscrowError::InvalidBatchSize
+        );
+        require!(
+            remaining_accounts.len() == terms.len() * 2,
+            EscrowError::BatchAccountsMismatch
+        );
+        self.initializer_stats
+            .init_if_new(self.initializer.key(), bumps.initializer_stats);
+        self.initializer_stats
+            .track_opened(terms.len() as u32, &self.config)?;
+        self.maker_registry
+            .init_if_new(self.initializer.key(), bumps.maker_registry);
+
+        let total = terms
+            .iter()
+            .try_fold(0u64, |acc, t| acc.checked_add(t.initializer_amount))
+            .ok_or(EscrowError::MathOverflow)?;
+        require!(
+            self.initializer_ata_a.amount >= total,
+            EscrowError::AmountExceedsEscrow
+        );
+
+        for (t, accounts) in terms.iter().zip(remaining_accounts.chunks(2)) {
+            let (escrow, vault) = (&accounts[0], &accounts[1]);
+            self.create_vault(escrow, vault)?;
+            let before = token_amount(vault)?;
+            transfer_checked(
+                self.into_deposit_context(vault.clone()),
+                t.initializer_amount,
+                self.mint_a.decimals,
+            )?;
+            let received = deposit_received(before, token_amount(vault)?, t.initializer_amount)?;
+            self.create_escrow(t, received, escrow)?;
+            self.maker_registry.add(escrow.key())?;
+        }
+        Ok(())
+    }
+
+    fn create_escrow(
+        &self,
+        terms: &EscrowTerms,
+        received: u64,
+        escrow: &'info AccountInfo<'info>,
+    ) -> Result<()> {
+        let seed_bytes = terms.seed.to_le_bytes();
+        let (address, bump) =
+            Pubkey::find_program_address(&[b"state", seed_bytes.as_ref()], &crate::ID);
+        require_keys_eq!(escrow.key(), address, EscrowError::InvalidEscrowAddress);
+
+        let signer_seeds: [&[&[u8]]; 1] = [&[b"state", &seed_bytes[..], &[bump]]];
+        create_account(
+            CpiContext::new_with_signer(
+                self.system_program.to_account_info(),
+                CreateAccount {
+                    from: self.payer.to_account_info(),
+                    to: escrow.clone(),
+                },
+                &signer_seeds,
+            ),
+            Rent::get()?.minimum_balance(Escrow::SPACE),
+            Escrow::SPACE as u64,
+            &crate::ID,
+        )?;
+
+        let fee_held = charge_creation_fee(
+            &self.config,
+            self.payer.to_account_info(),
+            escrow.clone(),
+            self.platform_wallet.to_account_info(),
+            self.system_program.to_account_info(),
+        )?;
+
+        let loader = AccountLoader::<Escrow>::try_from_unchecked(&crate::ID, escrow)?;
+        let mut state = Escrow::new(
+            terms.seed,
+            bump,
+            self.initializer.key(),
+            self.mint_a.key(),
+            self.mint_b.key(),
+            terms.initializer_amount,
+            terms.taker_amount,
+        );
+        state.settle_deposit(received)?;
+        // The deposit lands in the same instruction
+        state.status = EscrowStatus::Funded as u8;
+        state.terms_hash = terms.terms_hash;
+        if fee_held {
+            state.flags |= Escrow::FLAG_CREATION_FEE_HELD;
+        }
+        *loader.load_init()? = state;
+        // Persists the account discriminator
+        loader.exit(&crate::ID)
+    }
+
+    fn create_vault(&self, escrow: &AccountInfo<'info>, vault: &AccountInfo<'info>) -> Result<()> {
+        create_idempotent(CpiContext::new(
+            self.associated_token_program.to_account_info(),
+            Create {
+                payer: self.payer.to_account_info(),
+                associated_token: vault.clone(),
+                authority: escrow.clone(),
+                mint: self.mint_a.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            },
+        ))
+    }
+
+    fn into_deposit_context(
+        &self,
+        vault: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.initializer_ata_a.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: vault,
+            authority: self.initializer.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+fn token_amount(vault: &AccountInfo) -> Result<u64> {
+    Ok(TokenAccount::try_deserialize(&mut &vault.try_borrow_data()?[..])?.amount)
+}
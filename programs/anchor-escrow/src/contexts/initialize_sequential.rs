@@ -0,0 +1,189 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::constants::PLATFORM_WALLET;
+use crate::errors::EscrowError;
+use crate::fees::charge_creation_fee;
+use crate::gating::{require_gateway_pass, require_stablecoin_market};
+use crate::invariants::require_deposit_received;
+use crate::states::{
+    Config, Escrow, EscrowCounter, EscrowStatus, MakerRegistry, StablecoinList, TraderStats,
+};
+
+// `initialize` without a caller-chosen seed: the escrow lands at the maker's
+// next sequential address, EscrowCounter::sequential_seed(initializer, n),
+// so a maker's escrows can be enumerated from their stats account alone.
+#[derive(Accounts)]
+#[instruction(initializer_amount: u64)]
+pub struct InitializeSequential<'info> {
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        constraint = initializer_ata_a.amount >= initializer_amount,
+        associated_token::mint = mint_a,
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
+    )]
+    pub initializer_ata_a: InterfaceAccount<'info, TokenAccount>,
+    // Ahead of the escrow, whose address depends on it
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TraderStats::SPACE,
+        seeds = [b"trader_stats", initializer.key().as_ref()],
+        bump,
+    )]
+    pub initializer_stats: Box<Account<'info, TraderStats>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MakerRegistry::SPACE,
+        seeds = [b"registry", initializer.key().as_ref()],
+        bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    #[account(
+        init,
+        payer = payer,
+        space = Escrow::SPACE,
+        seeds = [
+            b"state".as_ref(),
+            &EscrowCounter::sequential_seed(
+                &initializer.key(),
+                initializer_stats.sequential_escrows
+            ).to_le_bytes()
+        ],
+        bump
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint_a,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = EscrowCounter::SPACE,
+        seeds = [b"escrow_counter"],
+        bump,
+    )]
+    pub escrow_counter: Box<Account<'info, EscrowCounter>>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Civic gateway token of the initializer, verified when the market is gated
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+    // Required while the config is in stablecoin-only mode
+    #[account(seeds = [b"stablecoins"], bump = stablecoins.bump)]
+    pub stablecoins: Option<Box<Account<'info, StablecoinList>>>,
+    /// CHECK: Blocklist PDA of the initializer; must not exist
+    #[account(
+        seeds = [b"blocked", initializer.key().as_ref()],
+        bump,
+        constraint = initializer_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub initializer_block: UncheckedAccount<'info>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(mut, address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeSequential<'info> {
+    pub fn initialize_sequential(
+        &mut self,
+        bumps: &InitializeSequentialBumps,
+        initializer_amount: u64,
+        taker_amount: u64,
+        terms_hash: [u8; 32],
+    ) -> Result<()> {
+        require_gateway_pass(
+            &self.config,
+            self.gateway_token.as_deref(),
+            &self.initializer.key(),
+        )?;
+        require_stablecoin_market(
+            &self.config,
+            self.stablecoins.as_deref(),
+            &self.mint_a.key(),
+            &self.mint_b.key(),
+        )?;
+        self.initializer_stats
+            .init_if_new(self.initializer.key(), bumps.initializer_stats);
+        let seed = EscrowCounter::sequential_seed(
+            &self.initializer.key(),
+            self.initializer_stats.sequential_escrows,
+        );
+        self.initializer_stats.sequential_escrows = self
+            .initializer_stats
+            .sequential_escrows
+            .checked_add(1)
+            .ok_or(EscrowError::MathOverflow)?;
+        self.initializer_stats.track_opened(1, &self.config)?;
+        self.maker_registry
+            .init_if_new(self.initializer.key(), bumps.maker_registry);
+        self.maker_registry.add(self.escrow.key())?;
+        let fee_held = charge_creation_fee(
+            &self.config,
+            self.payer.to_account_info(),
+            self.escrow.to_account_info(),
+            self.platform_wallet.to_account_info(),
+            self.system_program.to_account_info(),
+        )?;
+
+        let before = self.vault.amount;
+        transfer_checked(
+            self.into_deposit_context(),
+            initializer_amount,
+            self.mint_a.decimals,
+        )?;
+        let received = require_deposit_received(&mut self.vault, before, initializer_amount)?;
+
+        let mut escrow = Escrow::new(
+            seed,
+            bumps.escrow,
+            self.initializer.key(),
+            self.mint_a.key(),
+            self.mint_b.key(),
+            initializer_amount,
+            taker_amount,
+        );
+        escrow.terms_hash = terms_hash;
+        escrow.settle_deposit(received)?;
+        // The deposit lands in the same instruction
+        escrow.status = EscrowStatus::Funded as u8;
+        self.escrow_counter.init_if_new(bumps.escrow_counter);
+        escrow.sequence = self.escrow_counter.next_sequence()?;
+        if fee_held {
+            escrow.flags |= Escrow::FLAG_CREATION_FEE_HELD;
+        }
+        *self.escrow.load_init()? = escrow;
+        Ok(())
+    }
+
+    fn into_deposit_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.initializer_ata_a.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.initializer.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::clock;
+use crate::constants::PLATFORM_WALLET;
+use crate::errors::EscrowError;
+use crate::events::EscrowCreatedWithPermit;
+use crate::fees::charge_creation_fee;
+use crate::gating::{require_gateway_pass, require_stablecoin_market};
+use crate::invariants::require_deposit_received;
+use crate::sigverify::{permit_message, require_ed25519_signature};
+use crate::states::{
+    Config, Escrow, EscrowStatus, MakerRegistry, PermitReceipt, SignerNonce, StablecoinList,
+    TraderStats,
+};
+
+// `initialize` for makers without SOL: the maker signs the escrow parameters
+// offline and has approved the [b"permit_authority"] PDA as delegate on their
+// mint_a ATA; the relayer submits the Ed25519 instruction just before this one
+// and pays for everything.
+#[derive(Accounts)]
+#[instruction(seed: u64, initializer_amount: u64)]
+pub struct InitializeWithPermit<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub initializer: SystemAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        constraint = initializer_ata_a.amount >= initializer_amount,
+        associated_token::mint = mint_a,
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
+    )]
+    pub initializer_ata_a: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: Data-less PDA makers approve as delegate to have permits pull their deposit
+    #[account(seeds = [b"permit_authority"], bump)]
+    pub permit_authority: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = Escrow::SPACE,
+        seeds = [b"state".as_ref(), &seed.to_le_bytes()],
+        bump
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint_a,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        init,
+        payer = payer,
+        space = PermitReceipt::SPACE,
+        seeds = [b"permit", initializer.key().as_ref(), &seed.to_le_bytes()],
+        bump
+    )]
+    pub permit_receipt: Box<Account<'info, PermitReceipt>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = SignerNonce::SPACE,
+        seeds = [b"nonce", initializer.key().as_ref()],
+        bump,
+    )]
+    pub signer_nonce: Box<Account<'info, SignerNonce>>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.has_feature(Config::FEATURE_PERMITS) @ EscrowError::FeatureDisabled,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Civic gateway token of the initializer, verified when the market is gated
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+    // Required while the config is in stablecoin-only mode
+    #[account(seeds = [b"stablecoins"], bump = stablecoins.bump)]
+    pub stablecoins: Option<Box<Account<'info, StablecoinList>>>,
+    /// CHECK: Blocklist PDA of the initializer; must not exist
+    #[account(
+        seeds = [b"blocked", initializer.key().as_ref()],
+        bump,
+        constraint = initializer_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub initializer_block: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TraderStats::SPACE,
+        seeds = [b"trader_stats", initializer.key().as_ref()],
+        bump,
+    )]
+    pub initializer_stats: Box<Account<'info, TraderStats>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MakerRegistry::SPACE,
+        seeds = [b"registry", initializer.key().as_ref()],
+        bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(mut, address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    /// CHECK: Instructions sysvar, read to find the maker's Ed25519 permit
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions: UncheckedAccount<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeWithPermit<'info> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize_with_permit(
+        &mut self,
+        bumps: &InitializeWithPermitBumps,
+        seed: u64,
+        initializer_amount: u64,
+        taker_amount: u64,
+        terms_hash: [u8; 32],
+        valid_until: i64,
+        nonce: u64,
+    ) -> Result<()> {
+        let now = clock::now()?;
+        require!(now <= valid_until, EscrowError::PermitExpired);
+        let message = permit_message(
+            &self.initializer.key(),
+            &self.mint_a.key(),
+            &self.mint_b.key(),
+            seed,
+            initializer_amount,
+            taker_amount,
+            &terms_hash,
+            valid_until,
+            nonce,
+        );
+        require_ed25519_signature(&self.instructions, &self.initializer.key(), &message)
+            .map_err(|_| EscrowError::InvalidPermit)?;
+        self.signer_nonce
+            .init_if_new(self.initializer.key(), bumps.signer_nonce);
+        self.signer_nonce.consume(nonce)?;
+        self.permit_receipt.set_inner(PermitReceipt {
+            version: PermitReceipt::VERSION,
+            bump: bumps.permit_receipt,
+            maker: self.initializer.key(),
+            escrow: self.escrow.key(),
+            relayer: self.payer.key(),
+            used_at: now,
+            _reserved: [0; 32],
+        });
+
+        require_gateway_pass(
+            &self.config,
+            self.gateway_token.as_deref(),
+            &self.initializer.key(),
+        )?;
+        require_stablecoin_market(
+            &self.config,
+            self.stablecoins.as_deref(),
+            &self.mint_a.key(),
+            &self.mint_b.key(),
+        )?;
+        self.initializer_stats
+            .init_if_new(self.initializer.key(), bumps.initializer_stats);
+        self.initializer_stats.track_opened(1, &self.config)?;
+        self.maker_registry
+            .init_if_new(self.initializer.key(), bumps.maker_registry);
+        self.maker_registry.add(self.escrow.key())?;
+        let fee_held = charge_creation_fee(
+            &self.config,
+            self.payer.to_account_info(),
+            self.escrow.to_account_info(),
+            self.platform_wallet.to_account_info(),
+            self.system_program.to_account_info(),
+        )?;
+        let mut escrow = Escrow::new(
+            seed,
+            bumps.escrow,
+            self.initializer.key(),
+            self.mint_a.key(),
+            self.mint_b.key(),
+            initializer_amount,
+            taker_amount,
+        );
+        escrow.terms_hash = terms_hash;
+        // The deposit lands in the same instruction
+        escrow.status = EscrowStatus::Funded as u8;
+        if fee_held {
+            escrow.flags |= Escrow::FLAG_CREATION_FEE_HELD;
+        }
+
+        let signer_seeds: [&[&[u8]]; 1] = [&[b"permit_authority", &[bumps.permit_authority]]];
+        let before = self.vault.amount;
+        transfer_checked(
+            self.into_deposit_context().with_signer(&signer_seeds),
+            initializer_amount,
+            self.mint_a.decimals,
+        )?;
+        let received = require_deposit_received(&mut self.vault, before, initializer_amount)?;
+        escrow.settle_deposit(received)?;
+        *self.escrow.load_init()? = escrow;
+
+        emit!(EscrowCreatedWithPermit {
+            escrow: self.escrow.key(),
+            maker: self.initializer.key(),
+            relayer: self.payer.key(),
+        });
+        Ok(())
+    }
+
+    fn into_deposit_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.initializer_ata_a.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.permit_authority.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::constants::PLATFORM_WALLET;
+use crate::delegation::is_backed;
+use crate::errors::EscrowError;
+use crate::events::DelegatedEscrowInvalidated;
+use crate::fees::forfeit_creation_fee;
+use crate::states::{Escrow, MakerRegistry, TraderStats};
+
+// Permissionless: closes a delegated offer whose maker revoked, re-delegated
+// or spent the tokens backing it. Once a taker has confirmed payment the
+// escrow is left for dispute resolution instead.
+#[derive(Accounts)]
+pub struct InvalidateDelegated<'info> {
+    // Receives the escrow rent
+    #[account(mut)]
+    pub initializer: SystemAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = mint_a,
+        constraint = escrow.load()?.is_delegated() @ EscrowError::NotDelegated,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        close = initializer,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    // The ATA the delegation was made on; any other account proves nothing
+    #[account(
+        associated_token::mint = mint_a,
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
+    )]
+    pub initializer_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    // The initializer's profile, so the open-escrow count drops
+    #[account(
+        mut,
+        seeds = [b"trader_stats", escrow.load()?.initializer.as_ref()],
+        bump = initializer_stats.bump,
+    )]
+    pub initializer_stats: Box<Account<'info, TraderStats>>,
+    // The maker's registry, so the escrow drops off it
+    #[account(
+        mut,
+        seeds = [b"registry", escrow.load()?.initializer.as_ref()],
+        bump = maker_registry.bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    /// CHECK: This is the hardcoded platform wallet address, which keeps the
+    /// creation fee of offers that never settle
+    #[account(mut, address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> InvalidateDelegated<'info> {
+    pub fn invalidate_delegated(&mut self) -> Result<()> {
+        let escrow = *self.escrow.load()?;
+        require!(
+            !is_backed(&self.escrow.key(), &escrow, &self.initializer_ata_a),
+            EscrowError::DelegationIntact
+        );
+
+        if escrow.has_flag(Escrow::FLAG_CREATION_FEE_HELD) {
+            forfeit_creation_fee(
+                &self.escrow.to_account_info(),
+                &self.platform_wallet.to_account_info(),
+            )?;
+        }
+        self.initializer_stats.track_closed(1);
+        self.maker_registry.remove(&self.escrow.key());
+
+        emit!(DelegatedEscrowInvalidated {
+            escrow: self.escrow.key(),
+            initializer: escrow.initializer,
+        });
+        Ok(())
+    }
+}
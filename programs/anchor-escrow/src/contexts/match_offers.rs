@@ -0,0 +1,327 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::clock;
+use crate::constants::PLATFORM_WALLET;
+use crate::errors::EscrowError;
+use crate::events::OffersMatched;
+use crate::fees::{split_insurance, FeeBreakdown};
+use crate::gating::{
+    require_compliance_approval, require_gateway_pass, require_taker_attestations,
+    require_taker_region,
+};
+use crate::states::{Config, Escrow, MakerRegistry, TraderStats};
+
+// Crosses two opposing open offers, `offer_a` selling mint_a for mint_b and
+// `offer_b` selling mint_b for mint_a: each maker takes the other's whole
+// vault, less the platform fee. Permissionless, so a matcher bot can run the
+// venue as an on-chain order book; both makers already committed to their
+// price, and each gets at least what they asked for or nothing happens.
+// Each maker stands in as the other offer's taker, so offers gated on
+// attestations, regions or compliance approval don't match.
+#[derive(Accounts)]
+pub struct MatchOffers<'info> {
+    pub matcher: Signer<'info>,
+    // Receive their offer's escrow and vault rent back
+    #[account(mut, address = offer_a.load()?.initializer)]
+    pub maker_a: SystemAccount<'info>,
+    #[account(mut, address = offer_b.load()?.initializer)]
+    pub maker_b: SystemAccount<'info>,
+    #[account(mint::token_program = token_program_a)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mint::token_program = token_program_b,
+        constraint = mint_b.key() != mint_a.key() @ EscrowError::OffersDoNotCross,
+    )]
+    pub mint_b: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        has_one = mint_a,
+        has_one = mint_b,
+        constraint = offer_a.load()?.is_matchable(clock::now()?) @ EscrowError::OfferNotMatchable,
+        close = maker_a,
+        seeds=[b"state", offer_a.load()?.seed.to_le_bytes().as_ref()],
+        bump = offer_a.load()?.bump,
+    )]
+    pub offer_a: AccountLoader<'info, Escrow>,
+    #[account(
+        mut,
+        constraint = offer_b.load()?.mint_a == mint_b.key() @ EscrowError::OffersDoNotCross,
+        constraint = offer_b.load()?.mint_b == mint_a.key() @ EscrowError::OffersDoNotCross,
+        constraint = offer_b.load()?.is_matchable(clock::now()?) @ EscrowError::OfferNotMatchable,
+        close = maker_b,
+        seeds=[b"state", offer_b.load()?.seed.to_le_bytes().as_ref()],
+        bump = offer_b.load()?.bump,
+    )]
+    pub offer_b: AccountLoader<'info, Escrow>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = offer_a,
+        associated_token::token_program = token_program_a
+    )]
+    pub vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_b,
+        associated_token::authority = offer_b,
+        associated_token::token_program = token_program_b
+    )]
+    pub vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+    // Each maker's account for the mint they bought
+    #[account(
+        mut,
+        associated_token::mint = mint_b,
+        associated_token::authority = maker_a,
+        associated_token::token_program = token_program_b
+    )]
+    pub maker_a_ata_b: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = maker_b,
+        associated_token::token_program = token_program_a
+    )]
+    pub maker_b_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = platform_wallet,
+        associated_token::token_program = token_program_a
+    )]
+    pub platform_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_b,
+        associated_token::authority = platform_wallet,
+        associated_token::token_program = token_program_b
+    )]
+    pub platform_ata_b: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.has_feature(Config::FEATURE_ORDER_MATCHING) @ EscrowError::FeatureDisabled,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Data-less PDA that owns the insurance fund token accounts
+    #[account(seeds = [b"insurance"], bump = config.insurance_bump)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program_a
+    )]
+    pub insurance_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_b,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program_b
+    )]
+    pub insurance_ata_b: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Blocklist PDAs of both makers; must not exist
+    #[account(
+        seeds = [b"blocked", maker_a.key().as_ref()],
+        bump,
+        constraint = maker_a_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub maker_a_block: UncheckedAccount<'info>,
+    /// CHECK: See above
+    #[account(
+        seeds = [b"blocked", maker_b.key().as_ref()],
+        bump,
+        constraint = maker_b_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub maker_b_block: UncheckedAccount<'info>,
+    /// CHECK: Civic gateway tokens of the makers, verified when the market is gated
+    pub maker_a_gateway_token: Option<UncheckedAccount<'info>>,
+    /// CHECK: See above
+    pub maker_b_gateway_token: Option<UncheckedAccount<'info>>,
+    // The makers' profiles, so the open-escrow counts drop
+    #[account(
+        mut,
+        seeds = [b"trader_stats", offer_a.load()?.initializer.as_ref()],
+        bump = maker_a_stats.bump,
+    )]
+    pub maker_a_stats: Box<Account<'info, TraderStats>>,
+    // The makers' registries, so the offers drop off them
+    #[account(
+        mut,
+        seeds = [b"registry", offer_a.load()?.initializer.as_ref()],
+        bump = maker_a_registry.bump,
+    )]
+    pub maker_a_registry: Box<Account<'info, MakerRegistry>>,
+    #[account(
+        mut,
+        seeds = [b"trader_stats", offer_b.load()?.initializer.as_ref()],
+        bump = maker_b_stats.bump,
+    )]
+    pub maker_b_stats: Box<Account<'info, TraderStats>>,
+    #[account(
+        mut,
+        seeds = [b"registry", offer_b.load()?.initializer.as_ref()],
+        bump = maker_b_registry.bump,
+    )]
+    pub maker_b_registry: Box<Account<'info, MakerRegistry>>,
+    pub token_program_a: Interface<'info, TokenInterface>,
+    pub token_program_b: Interface<'info, TokenInterface>,
+}
+
+// One side of a match: an offer's vault paying out to the other offer's maker
+struct Leg<'a, 'info> {
+    offer: &'a AccountLoader<'info, Escrow>,
+    vault: &'a InterfaceAccount<'info, TokenAccount>,
+    mint: &'a InterfaceAccount<'info, Mint>,
+    counterparty_ata: &'a InterfaceAccount<'info, TokenAccount>,
+    platform_ata: &'a InterfaceAccount<'info, TokenAccount>,
+    insurance_ata: &'a InterfaceAccount<'info, TokenAccount>,
+    maker: &'a SystemAccount<'info>,
+    token_program: &'a Interface<'info, TokenInterface>,
+}
+
+impl<'a, 'info> Leg<'a, 'info> {
+    // Empties and closes the vault, fees first; returns what the counterparty got
+    fn settle(&self, escrow: &Escrow, config: &Config, fees: &FeeBreakdown) -> Result<u64> {
+        let signer_seeds: [&[&[u8]]; 1] =
+            [&[b"state", &escrow.seed.to_le_bytes()[..], &[escrow.bump]]];
+        let net_amount = fees.net_amount;
+        let (platform_share, insurance_share) =
+            split_insurance(fees.platform_fee, config.insurance_fee_bps)?;
+
+        for (to, amount) in [
+            (self.insurance_ata, insurance_share),
+            (self.platform_ata, platform_share),
+            (self.counterparty_ata, net_amount),
+        ]
+        .iter()
+        {
+            if *amount > 0 {
+                transfer_checked(
+                    self.into_transfer_context(to).with_signer(&signer_seeds),
+                    *amount,
+                    self.mint.decimals,
+                )?;
+            }
+        }
+        close_account(self.into_close_context().with_signer(&signer_seeds))?;
+        Ok(net_amount)
+    }
+
+    fn into_transfer_context(
+        &self,
+        to: &InterfaceAccount<'info, TokenAccount>,
+    ) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint.to_account_info(),
+            to: to.to_account_info(),
+            authority: self.offer.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_close_context(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        let cpi_accounts = CloseAccount {
+            account: self.vault.to_account_info(),
+            destination: self.maker.to_account_info(),
+            authority: self.offer.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+impl<'info> MatchOffers<'info> {
+    pub fn match_offers(&mut self) -> Result<()> {
+        let now = clock::now()?;
+        let offer_a = *self.offer_a.load()?;
+        let offer_b = *self.offer_b.load()?;
+        let fees_a = FeeBreakdown::for_settlement(
+            &self.config,
+            offer_a.category,
+            offer_a.initializer_amount,
+            now,
+        )?;
+        let fees_b = FeeBreakdown::for_settlement(
+            &self.config,
+            offer_b.category,
+            offer_b.initializer_amount,
+            now,
+        )?;
+        // Each side must deliver, after its fee, at least what the other
+        // asked for
+        require!(
+            fees_a.net_amount >= offer_b.taker_amount && fees_b.net_amount >= offer_a.taker_amount,
+            EscrowError::OffersDoNotCross
+        );
+        self.require_counterparty(
+            &offer_a,
+            &self.maker_b.key(),
+            self.maker_b_gateway_token.as_deref(),
+        )?;
+        self.require_counterparty(
+            &offer_b,
+            &self.maker_a.key(),
+            self.maker_a_gateway_token.as_deref(),
+        )?;
+
+        let leg_a = Leg {
+            offer: &self.offer_a,
+            vault: &self.vault_a,
+            mint: &self.mint_a,
+            counterparty_ata: &self.maker_b_ata_a,
+            platform_ata: &self.platform_ata_a,
+            insurance_ata: &self.insurance_ata_a,
+            maker: &self.maker_a,
+            token_program: &self.token_program_a,
+        };
+        let amount_a = leg_a.settle(&offer_a, &self.config, &fees_a)?;
+        let leg_b = Leg {
+            offer: &self.offer_b,
+            vault: &self.vault_b,
+            mint: &self.mint_b,
+            counterparty_ata: &self.maker_a_ata_b,
+            platform_ata: &self.platform_ata_b,
+            insurance_ata: &self.insurance_ata_b,
+            maker: &self.maker_b,
+            token_program: &self.token_program_b,
+        };
+        let amount_b = leg_b.settle(&offer_b, &self.config, &fees_b)?;
+
+        self.maker_a_stats.track_settled();
+        self.maker_b_stats.track_settled();
+        self.maker_a_registry.remove(&self.offer_a.key());
+        self.maker_b_registry.remove(&self.offer_b.key());
+
+        emit!(OffersMatched {
+            matcher: self.matcher.key(),
+            offer_a: self.offer_a.key(),
+            offer_b: self.offer_b.key(),
+            mint_a: self.mint_a.key(),
+            amount_a,
+            mint_b: self.mint_b.key(),
+            amount_b,
+        });
+        Ok(())
+    }
+
+    // `taker` may take `offer` the way `confirm_payment` would let them,
+    // without any attestations or approvals to show for it
+    fn require_counterparty(
+        &self,
+        offer: &Escrow,
+        taker: &Pubkey,
+        gateway_token: Option<&AccountInfo>,
+    ) -> Result<()> {
+        require_gateway_pass(&self.config, gateway_token, taker)?;
+        require_taker_attestations(&self.config, offer, None, None, &[], taker)?;
+        require_taker_region(offer, None, &[], taker)?;
+        require_compliance_approval(&self.config, offer, None)
+    }
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+
+use crate::errors::EscrowError;
+use crate::states::Config;
+
+// Permissionless, like `migrate_escrow`: fields are only ever appended to
+// Config, so upgrading is growing the account and zeroing the new tail.
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: layout is detected from the discriminator and size, then validated below
+    #[account(mut, owner = crate::ID, seeds = [b"config"], bump)]
+    pub config: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateConfig<'info> {
+    pub fn migrate_config(&mut self) -> Result<()> {
+        let info = self.config.to_account_info();
+        {
+            let data = info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == *Config::DISCRIMINATOR,
+                EscrowError::UnknownConfigLayout
+            );
+            require!(
+                data.len() != Config::SPACE,
+                EscrowError::ConfigAlreadyMigrated
+            );
+            require!(
+                data.len() == Config::V1_SPACE || data.len() == Config::V2_SPACE,
+                EscrowError::UnknownConfigLayout
+            );
+        }
+        let old_space = info.data_len();
+
+        let rent = Rent::get()?.minimum_balance(Config::SPACE);
+        let shortfall = rent.saturating_sub(info.lamports());
+        if shortfall > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.payer.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+
+        info.resize(Config::SPACE)?;
+        let mut data = info.try_borrow_mut_data()?;
+        data[old_space..].fill(0);
+        // `version` is the first field after the discriminator
+        data[8] = Config::VERSION;
+        // `features` opens the version 3 tail; it keeps everything on
+        data[Config::V2_SPACE..Config::V2_SPACE + 8]
+            .copy_from_slice(&Config::DEFAULT_FEATURES.to_le_bytes());
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+
+use crate::errors::EscrowError;
+use crate::states::{Escrow, EscrowStatus, EscrowV0};
+
+// Permissionless: migration only reshapes the account, so any payer may top
+// up the rent and upgrade an escrow left behind by an older program version.
+#[derive(Accounts)]
+pub struct MigrateEscrow<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: layout is detected from the discriminator and size, then validated below
+    #[account(mut, owner = crate::ID)]
+    pub escrow: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateEscrow<'info> {
+    pub fn migrate_escrow(&mut self) -> Result<()> {
+        let info = self.escrow.to_account_info();
+        let upgraded = {
+            let data = info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == *Escrow::DISCRIMINATOR,
+                EscrowError::UnknownEscrowLayout
+            );
+
+            if data.len() == EscrowV0::SPACE {
+                let legacy = EscrowV0::deserialize(&mut &data[8..])?;
+                let mut escrow = Escrow::new(
+                    legacy.seed,
+                    legacy.bump,
+                    legacy.initializer,
+                    legacy.mint_a,
+                    legacy.mint_b,
+                    legacy.initializer_amount,
+                    legacy.taker_amount,
+                );
+                escrow.payment_confirmed = legacy.payment_confirmed as u8;
+                // Legacy escrows were always funded at initialize
+                escrow.status = EscrowStatus::Funded as u8;
+                escrow
+            } else if [
+                Escrow::V1_SPACE,
+                Escrow::V2_SPACE,
+                Escrow::V3_SPACE,
+                Escrow::V4_SPACE,
+            ]
+            .contains(&data.len())
+            {
+                // Versions 1 to 4 are prefixes of the current layout
+                let mut escrow: Escrow = bytemuck::Zeroable::zeroed();
+                bytemuck::bytes_of_mut(&mut escrow)[..data.len() - 8].copy_from_slice(&data[8..]);
+                escrow.version = Escrow::VERSION;
+                escrow
+            } else if data.len() == Escrow::SPACE {
+                return err!(EscrowError::EscrowAlreadyMigrated);
+            } else {
+                return err!(EscrowError::UnknownEscrowLayout);
+            }
+        };
+
+        let address = Pubkey::create_program_address(
+            &[
+                b"state",
+                upgraded.seed.to_le_bytes().as_ref(),
+                &[upgraded.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| EscrowError::InvalidEscrowAddress)?;
+        require_keys_eq!(info.key(), address, EscrowError::InvalidEscrowAddress);
+
+        let rent = Rent::get()?.minimum_balance(Escrow::SPACE);
+        let shortfall = rent.saturating_sub(info.lamports());
+        if shortfall > 0 {
+            transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    Transfer {
+                        from: self.payer.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+
+        info.resize(Escrow::SPACE)?;
+        let mut data = info.try_borrow_mut_data()?;
+        data[8..].fill(0);
+        *bytemuck::from_bytes_mut::<Escrow>(&mut data[8..]) = upgraded;
+        Ok(())
+    }
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_2022::{mint_to, MintTo, Token2022},
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::errors::EscrowError;
+use crate::events::BadgesMinted;
+use crate::states::{Config, Receipt};
+
+// Mints one non-transferable badge to each party of a settled trade, so a
+// holder's balance is their count of completed trades. Keyed on the trade's
+// receipt, which counts each settlement once; clients append it to the
+// settling transaction. Permissionless: the payer covers any new badge ATAs.
+#[derive(Accounts)]
+pub struct MintBadges<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub initializer: SystemAccount<'info>,
+    pub taker: SystemAccount<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = taker @ EscrowError::InvalidTaker,
+        constraint = !receipt.badges_minted @ EscrowError::BadgesAlreadyMinted,
+        seeds = [
+            b"receipt",
+            receipt.escrow.as_ref(),
+            receipt.payment_confirmed_at.to_le_bytes().as_ref(),
+        ],
+        bump = receipt.bump,
+    )]
+    pub receipt: Box<Account<'info, Receipt>>,
+    #[account(
+        mut,
+        seeds = [b"badge_mint"],
+        bump,
+        mint::authority = badge_authority,
+        mint::token_program = token_program,
+    )]
+    pub badge_mint: Box<InterfaceAccount<'info, Mint>>,
+    /// CHECK: Data-less PDA that mints badges
+    #[account(seeds = [b"badge_authority"], bump)]
+    pub badge_authority: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = badge_mint,
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
+    )]
+    pub initializer_badge: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = badge_mint,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program
+    )]
+    pub taker_badge: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.has_feature(Config::FEATURE_BADGES) @ EscrowError::FeatureDisabled,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MintBadges<'info> {
+    pub fn mint_badges(&mut self, bumps: &MintBadgesBumps) -> Result<()> {
+        let signer_seeds: [&[&[u8]]; 1] = [&[b"badge_authority", &[bumps.badge_authority]]];
+        mint_to(
+            self.into_mint_context(self.initializer_badge.to_account_info())
+                .with_signer(&signer_seeds),
+            1,
+        )?;
+        mint_to(
+            self.into_mint_context(self.taker_badge.to_account_info())
+                .with_signer(&signer_seeds),
+            1,
+        )?;
+        self.receipt.badges_minted = true;
+
+        emit!(BadgesMinted {
+            receipt: self.receipt.key(),
+            initializer: self.initializer.key(),
+            taker: self.taker.key(),
+        });
+        Ok(())
+    }
+
+    fn into_mint_context(
+        &self,
+        to: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, MintTo<'info>> {
+        let cpi_accounts = MintTo {
+            mint: self.badge_mint.to_account_info(),
+            to,
+            authority: self.badge_authority.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -1,8 +1,258 @@
 pub mod initialize;
 pub use initialize::*;
+pub mod create_escrow;
+pub use create_escrow::*;
+pub mod fund_escrow;
+pub use fund_escrow::*;
+pub mod abort_escrow;
+pub use abort_escrow::*;
+pub mod increase_amount;
+pub use increase_amount::*;
+pub mod decrease_amount;
+pub use decrease_amount::*;
+pub mod reprice;
+pub use reprice::*;
+pub mod accept_escrow;
+pub use accept_escrow::*;
+pub mod reassign_taker;
+pub use reassign_taker::*;
+pub mod set_release_schedule;
+pub use set_release_schedule::*;
 pub mod cancel;
 pub use cancel::*;
 pub mod exchange;
 pub use exchange::*;
 pub mod confirm_payment;
 pub use confirm_payment::*;
+pub mod view_quote;
+pub use view_quote::*;
+pub mod view_fee_breakdown;
+pub use view_fee_breakdown::*;
+pub mod get_version;
+pub use get_version::*;
+pub mod initialize_many;
+pub use initialize_many::*;
+pub mod cancel_many;
+pub use cancel_many::*;
+pub mod migrate_escrow;
+pub use migrate_escrow::*;
+pub mod start_vesting;
+pub use start_vesting::*;
+pub mod claim_vested;
+pub use claim_vested::*;
+pub mod set_arbiter;
+pub use set_arbiter::*;
+pub mod add_milestone;
+pub use add_milestone::*;
+pub mod confirm_milestone;
+pub use confirm_milestone::*;
+pub mod release_milestone;
+pub use release_milestone::*;
+pub mod dispute_milestone;
+pub use dispute_milestone::*;
+pub mod resolve_milestone_dispute;
+pub use resolve_milestone_dispute::*;
+pub mod claim_stream;
+pub use claim_stream::*;
+pub mod initialize_config;
+pub use initialize_config::*;
+pub mod update_config;
+pub use update_config::*;
+pub mod pay_claim;
+pub use pay_claim::*;
+pub mod require_bond;
+pub use require_bond::*;
+pub mod post_bond;
+pub use post_bond::*;
+pub mod release_bond;
+pub use release_bond::*;
+pub mod post_maker_deposit;
+pub use post_maker_deposit::*;
+pub mod refund_maker_deposit;
+pub use refund_maker_deposit::*;
+pub mod set_global_attestations;
+pub use set_global_attestations::*;
+pub mod set_offer_attestations;
+pub use set_offer_attestations::*;
+pub mod set_offer_regions;
+pub use set_offer_regions::*;
+pub mod block_wallet;
+pub use block_wallet::*;
+pub mod unblock_wallet;
+pub use unblock_wallet::*;
+pub mod set_volume_tier;
+pub use set_volume_tier::*;
+pub mod set_expiry;
+pub use set_expiry::*;
+pub mod reap_expired;
+pub use reap_expired::*;
+pub mod auto_release;
+pub use auto_release::*;
+pub mod set_auto_release;
+pub use set_auto_release::*;
+pub mod force_release;
+pub use force_release::*;
+pub mod freeze_escrow;
+pub use freeze_escrow::*;
+pub mod unfreeze_escrow;
+pub use unfreeze_escrow::*;
+pub mod set_payment_delegate;
+pub use set_payment_delegate::*;
+pub mod revoke_payment_delegate;
+pub use revoke_payment_delegate::*;
+pub mod delegate_confirm_payment;
+pub use delegate_confirm_payment::*;
+pub mod create_session;
+pub use create_session::*;
+pub mod revoke_session;
+pub use revoke_session::*;
+pub mod set_evm_approver;
+pub use set_evm_approver::*;
+pub mod set_payment_verifier;
+pub use set_payment_verifier::*;
+pub mod set_accept_payment_proof;
+pub use set_accept_payment_proof::*;
+pub mod verify_payment_proof;
+pub use verify_payment_proof::*;
+pub mod relist_escrow;
+pub use relist_escrow::*;
+pub mod set_template;
+pub use set_template::*;
+pub mod close_template;
+pub use close_template::*;
+pub mod initialize_from_template;
+pub use initialize_from_template::*;
+pub mod create_recurring;
+pub use create_recurring::*;
+pub mod spawn_recurring;
+pub use spawn_recurring::*;
+pub mod cancel_recurring;
+pub use cancel_recurring::*;
+pub mod set_payment_details;
+pub use set_payment_details::*;
+pub mod anchor_message_root;
+pub use anchor_message_root::*;
+pub mod migrate_config;
+pub use migrate_config::*;
+pub mod claim_maker_rebate;
+pub use claim_maker_rebate::*;
+pub mod initialize_with_permit;
+pub use initialize_with_permit::*;
+pub mod initialize_delegated;
+pub use initialize_delegated::*;
+pub mod exchange_delegated;
+pub use exchange_delegated::*;
+pub mod invalidate_delegated;
+pub use invalidate_delegated::*;
+pub mod close_orphaned_vault;
+pub use close_orphaned_vault::*;
+pub mod gc_escrow;
+pub use gc_escrow::*;
+pub mod initialize_sequential;
+pub use initialize_sequential::*;
+pub mod prune_registry;
+pub use prune_registry::*;
+pub mod set_partial_payments;
+pub use set_partial_payments::*;
+pub mod release_tranche;
+pub use release_tranche::*;
+pub mod set_release_delay;
+pub use set_release_delay::*;
+pub mod close_receipt;
+pub use close_receipt::*;
+pub mod initialize_badge_mint;
+pub use initialize_badge_mint::*;
+pub mod mint_badges;
+pub use mint_badges::*;
+pub mod set_rate_feed;
+pub use set_rate_feed::*;
+pub mod post_rate;
+pub use post_rate::*;
+pub mod set_rate_snapshot;
+pub use set_rate_snapshot::*;
+pub mod accept_rate_move;
+pub use accept_rate_move::*;
+pub mod set_stablecoin;
+pub use set_stablecoin::*;
+pub mod set_swap_router;
+pub use set_swap_router::*;
+pub mod set_fee_converter;
+pub use set_fee_converter::*;
+pub mod convert_fees;
+pub use convert_fees::*;
+pub mod set_yield_adapter;
+pub use set_yield_adapter::*;
+pub mod deposit_to_yield;
+pub use deposit_to_yield::*;
+pub mod withdraw_from_yield;
+pub use withdraw_from_yield::*;
+pub mod set_yield_split;
+pub use set_yield_split::*;
+pub mod set_charity;
+pub use set_charity::*;
+pub mod pledge_donation;
+pub use pledge_donation::*;
+pub mod set_compliance_desk;
+pub use set_compliance_desk::*;
+pub mod set_offer_compliance;
+pub use set_offer_compliance::*;
+pub mod approve_release;
+pub use approve_release::*;
+pub mod set_note;
+pub use set_note::*;
+pub mod set_arbitrator;
+pub use set_arbitrator::*;
+pub mod assign_arbiter;
+pub use assign_arbiter::*;
+pub mod set_arbitration_policy;
+pub use set_arbitration_policy::*;
+pub mod stake_arbitrator;
+pub use stake_arbitrator::*;
+pub mod request_unstake;
+pub use request_unstake::*;
+pub mod withdraw_stake;
+pub use withdraw_stake::*;
+pub mod claim_arbitration_rewards;
+pub use claim_arbitration_rewards::*;
+pub mod overturn_ruling;
+pub use overturn_ruling::*;
+pub mod convene_jury;
+pub use convene_jury::*;
+pub mod commit_jury_vote;
+pub use commit_jury_vote::*;
+pub mod reveal_jury_vote;
+pub use reveal_jury_vote::*;
+pub mod penalize_juror;
+pub use penalize_juror::*;
+pub mod dissolve_jury;
+pub use dissolve_jury::*;
+pub mod set_governance;
+pub use set_governance::*;
+pub mod escalate_dispute;
+pub use escalate_dispute::*;
+pub mod hide_price;
+pub use hide_price::*;
+pub mod set_fiat_quotes;
+pub use set_fiat_quotes::*;
+pub mod advance_nonce;
+pub use advance_nonce::*;
+pub mod close_idempotency_record;
+pub use close_idempotency_record::*;
+pub mod extend_deadline;
+pub use extend_deadline::*;
+pub mod set_payment_memo_required;
+pub use set_payment_memo_required::*;
+pub mod match_offers;
+pub use match_offers::*;
+pub mod set_partner_config;
+pub use set_partner_config::*;
+pub mod claim_partner_fees;
+pub use claim_partner_fees::*;
+pub mod publish_settlement;
+pub use publish_settlement::*;
+pub mod write_off_yield;
+pub use write_off_yield::*;
+#[cfg(feature = "test-clock")]
+pub mod warp_clock;
+#[cfg(feature = "test-clock")]
+pub use warp_clock::*;
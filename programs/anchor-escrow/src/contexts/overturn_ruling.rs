@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::audit::record_admin_action;
+use crate::clock;
+use crate::constants::BPS_DENOMINATOR;
+use crate::errors::EscrowError;
+use crate::events::RulingOverturned;
+use crate::fees::pro_rata;
+use crate::states::{
+    AdminAction, ArbitrationPolicy, Arbitrator, AuditLog, Config, MilestoneDispute,
+    OverturnedRuling,
+};
+
+// The admin upholds an appeal against a milestone ruling within the appeal
+// window. The tranche has already moved, so the wronged party is made whole
+// through `pay_claim`; the arbiter's slashed stake goes to the insurance
+// fund, and the case's reward is taken back if still unclaimed.
+#[derive(Accounts)]
+pub struct OverturnRuling<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(seeds = [b"arbitration"], bump = arbitration.bump)]
+    pub arbitration: Box<Account<'info, ArbitrationPolicy>>,
+    #[account(
+        constraint = dispute.resolved @ EscrowError::RulingNotAppealable,
+        constraint = dispute.arbiter != Pubkey::default() @ EscrowError::RulingNotAppealable,
+        seeds = [b"milestone_dispute", dispute.milestone.as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Box<Account<'info, MilestoneDispute>>,
+    #[account(
+        mut,
+        seeds = [b"arbitrator", dispute.arbiter.as_ref()],
+        bump = arbitrator.bump,
+    )]
+    pub arbitrator: Box<Account<'info, Arbitrator>>,
+    #[account(
+        address = arbitration.stake_mint @ EscrowError::InvalidStakeMint,
+        mint::token_program = token_program
+    )]
+    pub stake_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = arbitrator,
+        associated_token::token_program = token_program
+    )]
+    pub stake_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Data-less PDA that owns the insurance fund token accounts
+    #[account(seeds = [b"insurance"], bump = config.insurance_bump)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program
+    )]
+    pub insurance_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = admin,
+        space = OverturnedRuling::SPACE,
+        seeds = [b"overturned", dispute.key().as_ref()],
+        bump,
+    )]
+    pub overturned: Box<Account<'info, OverturnedRuling>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> OverturnRuling<'info> {
+    pub fn overturn_ruling(
+        &mut self,
+        bumps: &OverturnRulingBumps,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        let now = clock::now()?;
+        let appeal_closes_at = self
+            .dispute
+            .resolved_at
+            .checked_add(self.arbitration.appeal_window)
+            .ok_or(EscrowError::MathOverflow)?;
+        require!(now <= appeal_closes_at, EscrowError::AppealWindowClosed);
+
+        let slashed = pro_rata(
+            self.arbitrator.staked,
+            self.arbitration.slash_bps as u64,
+            BPS_DENOMINATOR as u64,
+        )?;
+        if slashed > 0 {
+            let wallet = self.arbitrator.wallet;
+            let signer_seeds: [&[&[u8]]; 1] =
+                [&[b"arbitrator", wallet.as_ref(), &[self.arbitrator.bump]]];
+            transfer_checked(
+                self.into_slash_context().with_signer(&signer_seeds),
+                slashed,
+                self.stake_mint.decimals,
+            )?;
+            self.arbitrator.staked -= slashed;
+        }
+        self.arbitrator.rulings_overturned = self.arbitrator.rulings_overturned.saturating_add(1);
+        self.arbitrator.pending_rewards = self
+            .arbitrator
+            .pending_rewards
+            .saturating_sub(self.arbitration.reward_per_case);
+
+        self.overturned.set_inner(OverturnedRuling {
+            version: OverturnedRuling::VERSION,
+            bump: bumps.overturned,
+            dispute: self.dispute.key(),
+            arbiter: self.dispute.arbiter,
+            slashed,
+            overturned_at: now,
+            reason_hash,
+            _reserved: [0; 32],
+        });
+
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::OverturnRuling,
+            self.admin.key(),
+            self.dispute.key(),
+            &reason_hash,
+        )?;
+
+        emit!(RulingOverturned {
+            dispute: self.dispute.key(),
+            arbiter: self.dispute.arbiter,
+            slashed,
+            reason_hash,
+        });
+        Ok(())
+    }
+
+    fn into_slash_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.stake_vault.to_account_info(),
+            mint: self.stake_mint.to_account_info(),
+            to: self.insurance_ata.to_account_info(),
+            authority: self.arbitrator.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
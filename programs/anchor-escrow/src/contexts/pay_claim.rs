@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::audit::record_admin_action;
+use crate::errors::EscrowError;
+use crate::events::InsuranceClaimPaid;
+use crate::states::{AdminAction, AuditLog, Config};
+
+#[derive(Accounts)]
+pub struct PayClaim<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Data-less PDA that owns the insurance fund token accounts
+    #[account(seeds = [b"insurance"], bump = config.insurance_bump)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program
+    )]
+    pub insurance_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program
+    )]
+    pub recipient_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PayClaim<'info> {
+    // `escrow` is the disputed escrow being compensated, recorded for the audit trail
+    pub fn pay_claim(&mut self, bumps: &PayClaimBumps, escrow: Pubkey, amount: u64) -> Result<()> {
+        require!(amount > 0, EscrowError::InvalidAmount);
+        let signer_seeds: [&[&[u8]]; 1] = [&[b"insurance", &[self.config.insurance_bump]]];
+
+        transfer_checked(
+            self.into_claim_context().with_signer(&signer_seeds),
+            amount,
+            self.mint.decimals,
+        )?;
+
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::PayClaim,
+            self.admin.key(),
+            escrow,
+            &(self.mint.key(), self.recipient_ata.owner, amount),
+        )?;
+
+        emit!(InsuranceClaimPaid {
+            escrow,
+            mint: self.mint.key(),
+            recipient: self.recipient_ata.owner,
+            amount,
+        });
+        Ok(())
+    }
+
+    fn into_claim_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.insurance_ata.to_account_info(),
+            mint: self.mint.to_account_info(),
+            to: self.recipient_ata.to_account_info(),
+            authority: self.insurance_fund.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
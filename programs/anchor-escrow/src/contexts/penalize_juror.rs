@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::clock;
+use crate::constants::BPS_DENOMINATOR;
+use crate::errors::EscrowError;
+use crate::events::JurorPenalized;
+use crate::fees::pro_rata;
+use crate::states::{ArbitrationPolicy, Arbitrator, Config, Jury, JuryVote};
+
+// Permissionless once the reveal phase is over: a juror who committed but
+// never revealed loses the policy's slash share of their stake to the
+// insurance fund, the same as for an overturned ruling
+#[derive(Accounts)]
+pub struct PenalizeJuror<'info> {
+    pub cranker: Signer<'info>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    #[account(seeds = [b"arbitration"], bump = arbitration.bump)]
+    pub arbitration: Box<Account<'info, ArbitrationPolicy>>,
+    #[account(seeds = [b"jury", jury.dispute.as_ref()], bump = jury.bump)]
+    pub jury: Box<Account<'info, Jury>>,
+    #[account(
+        mut,
+        has_one = jury,
+        constraint = !vote.revealed @ EscrowError::VoteAlreadyRevealed,
+        constraint = !vote.penalized @ EscrowError::JurorAlreadyPenalized,
+        seeds = [b"jury_vote", jury.key().as_ref(), vote.juror.as_ref()],
+        bump = vote.bump,
+    )]
+    pub vote: Box<Account<'info, JuryVote>>,
+    #[account(
+        mut,
+        seeds = [b"arbitrator", vote.juror.as_ref()],
+        bump = arbitrator.bump,
+    )]
+    pub arbitrator: Box<Account<'info, Arbitrator>>,
+    #[account(
+        address = arbitration.stake_mint @ EscrowError::InvalidStakeMint,
+        mint::token_program = token_program
+    )]
+    pub stake_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = arbitrator,
+        associated_token::token_program = token_program
+    )]
+    pub stake_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: Data-less PDA that owns the insurance fund token accounts
+    #[account(seeds = [b"insurance"], bump = config.insurance_bump)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program
+    )]
+    pub insurance_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> PenalizeJuror<'info> {
+    pub fn penalize_juror(&mut self) -> Result<()> {
+        require!(
+            clock::now()? >= self.jury.reveal_ends_at,
+            EscrowError::JuryPhaseClosed
+        );
+
+        let slashed = pro_rata(
+            self.arbitrator.staked,
+            self.arbitration.slash_bps as u64,
+            BPS_DENOMINATOR as u64,
+        )?;
+        if slashed > 0 {
+            let wallet = self.arbitrator.wallet;
+            let signer_seeds: [&[&[u8]]; 1] =
+                [&[b"arbitrator", wallet.as_ref(), &[self.arbitrator.bump]]];
+            transfer_checked(
+                self.into_slash_context().with_signer(&signer_seeds),
+                slashed,
+                self.stake_mint.decimals,
+            )?;
+            self.arbitrator.staked -= slashed;
+        }
+        self.vote.penalized = true;
+
+        emit!(JurorPenalized {
+            jury: self.jury.key(),
+            juror: self.vote.juror,
+            slashed,
+        });
+        Ok(())
+    }
+
+    fn into_slash_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.stake_vault.to_account_info(),
+            mint: self.stake_mint.to_account_info(),
+            to: self.insurance_ata.to_account_info(),
+            authority: self.arbitrator.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_DONATION_BPS;
+use crate::errors::EscrowError;
+use crate::events::DonationPledged;
+use crate::states::{CharityList, DonationPledge, Escrow};
+
+// The taker's opt-in, since `exchange` is signed on the maker's side. Can be
+// changed until the escrow settles; pledging 0 without rounding up gives
+// nothing.
+#[derive(Accounts)]
+pub struct PledgeDonation<'info> {
+    #[account(mut)]
+    pub taker: Signer<'info>,
+    #[account(
+        mut,
+        constraint = escrow.load()?.taker == taker.key() @ EscrowError::InvalidTaker,
+        // Only `exchange` pays pledges out
+        constraint = escrow.load()?.is_immediate() @ EscrowError::ReleaseModeMismatch,
+        constraint = !escrow.load()?.is_delegated() @ EscrowError::ReleaseModeMismatch,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        seeds = [b"charities"],
+        bump = charities.bump,
+        constraint = charities.contains(&charity.key()) @ EscrowError::CharityNotListed,
+    )]
+    pub charities: Box<Account<'info, CharityList>>,
+    pub charity: SystemAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = taker,
+        space = DonationPledge::SPACE,
+        seeds = [b"donation", escrow.key().as_ref()],
+        bump,
+    )]
+    pub donation: Box<Account<'info, DonationPledge>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PledgeDonation<'info> {
+    pub fn pledge_donation(
+        &mut self,
+        bumps: &PledgeDonationBumps,
+        donation_bps: u16,
+        round_up: bool,
+    ) -> Result<()> {
+        require!(
+            donation_bps <= MAX_DONATION_BPS,
+            EscrowError::InvalidDonation
+        );
+        self.donation.set_inner(DonationPledge {
+            version: DonationPledge::VERSION,
+            bump: bumps.donation,
+            escrow: self.escrow.key(),
+            taker: self.taker.key(),
+            charity: self.charity.key(),
+            donation_bps,
+            round_up,
+            _reserved: [0; 16],
+        });
+        self.escrow.load_mut()?.donation_pledged = 1;
+
+        emit!(DonationPledged {
+            escrow: self.escrow.key(),
+            taker: self.taker.key(),
+            charity: self.charity.key(),
+            donation_bps,
+            round_up,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,184 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::{BondPosted, TakerAssigned};
+use crate::gating::{require_gateway_pass, require_taker_attestations, require_taker_region};
+use crate::states::{
+    AttestationRequirements, Bond, BondState, Config, Escrow, RegionPolicy, TraderStats,
+};
+
+// Token bonds pass the mint, the taker's token account, the bond's associated
+// token account (created idempotently by the client) and the token program;
+// SOL bonds leave all four out.
+#[derive(Accounts)]
+pub struct PostBond<'info> {
+    #[account(mut)]
+    pub taker: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        constraint = escrow.load()?.is_funded() @ EscrowError::EscrowNotFunded,
+        constraint = escrow.load()?.is_bond_pending() @ EscrowError::BondNotRequired,
+        constraint = !escrow.load()?.has_hidden_price() @ EscrowError::PriceHidden,
+        constraint = escrow.load()?.has_taker() || !escrow.load()?.has_flag(Escrow::FLAG_FIAT_QUOTES) @ EscrowError::CurrencyNotSelected,
+        constraint = !escrow.load()?.has_taker() || escrow.load()?.taker == taker.key() @ EscrowError::InvalidTaker,
+        constraint = escrow.load()?.initializer != taker.key() @ EscrowError::InvalidTaker,
+        constraint = escrow.load()?.has_taker() || escrow.load()?.maker_deposit >= escrow.load()?.required_maker_deposit(config.maker_deposit_bps)? @ EscrowError::MakerDepositRequired,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        mut,
+        has_one = escrow,
+        seeds = [b"bond", escrow.key().as_ref()],
+        bump = bond.bump,
+    )]
+    pub bond: Box<Account<'info, Bond>>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Civic gateway token of the taker, verified when the market is gated
+    pub gateway_token: Option<UncheckedAccount<'info>>,
+    /// CHECK: Blocklist PDA of the taker; must not exist
+    #[account(
+        seeds = [b"blocked", taker.key().as_ref()],
+        bump,
+        constraint = taker_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub taker_block: UncheckedAccount<'info>,
+    #[account(seeds = [b"attestations"], bump = global_attestations.bump)]
+    pub global_attestations: Option<Box<Account<'info, AttestationRequirements>>>,
+    #[account(
+        seeds = [b"attestations", escrow.key().as_ref()],
+        bump = offer_attestations.bump,
+    )]
+    pub offer_attestations: Option<Box<Account<'info, AttestationRequirements>>>,
+    #[account(
+        seeds = [b"regions", escrow.key().as_ref()],
+        bump = region_policy.bump,
+    )]
+    pub region_policy: Option<Box<Account<'info, RegionPolicy>>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TraderStats::SPACE,
+        seeds = [b"trader_stats", taker.key().as_ref()],
+        bump,
+    )]
+    pub taker_stats: Box<Account<'info, TraderStats>>,
+    pub bond_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    #[account(mut)]
+    pub taker_bond_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub bond_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PostBond<'info> {
+    // Locks the bond and, for an open offer, accepts the escrow for the taker.
+    // Remaining accounts carry the taker's SAS attestations, if any are required.
+    pub fn post_bond(&mut self, bumps: &PostBondBumps, attestations: &[AccountInfo]) -> Result<()> {
+        require_gateway_pass(
+            &self.config,
+            self.gateway_token.as_deref(),
+            &self.taker.key(),
+        )?;
+        require_taker_attestations(
+            &self.config,
+            &*self.escrow.load()?,
+            self.global_attestations.as_deref(),
+            self.offer_attestations.as_deref(),
+            attestations,
+            &self.taker.key(),
+        )?;
+        require_taker_region(
+            &*self.escrow.load()?,
+            self.region_policy.as_deref(),
+            attestations,
+            &self.taker.key(),
+        )?;
+        let amount = self.bond.amount;
+        if self.bond.is_sol() {
+            transfer(self.into_sol_deposit_context(), amount)?;
+        } else {
+            let (mint, from, vault, token_program) = match (
+                &self.bond_mint,
+                &self.taker_bond_ata,
+                &self.bond_vault,
+                &self.token_program,
+            ) {
+                (Some(mint), Some(from), Some(vault), Some(token_program)) => {
+                    (mint, from, vault, token_program)
+                }
+                _ => return err!(EscrowError::InvalidBondAccounts),
+            };
+            require_keys_eq!(mint.key(), self.bond.mint, EscrowError::InvalidBondAccounts);
+            require_keys_eq!(
+                vault.key(),
+                get_associated_token_address_with_program_id(
+                    &self.bond.key(),
+                    &mint.key(),
+                    &token_program.key()
+                ),
+                EscrowError::InvalidBondAccounts
+            );
+
+            let cpi_accounts = TransferChecked {
+                from: from.to_account_info(),
+                mint: mint.to_account_info(),
+                to: vault.to_account_info(),
+                authority: self.taker.to_account_info(),
+            };
+            transfer_checked(
+                CpiContext::new(token_program.to_account_info(), cpi_accounts),
+                amount,
+                mint.decimals,
+            )?;
+        }
+
+        self.bond.posted = true;
+        self.bond.taker = self.taker.key();
+
+        let mut escrow = self.escrow.load_mut()?;
+        escrow.bond_state = BondState::Posted as u8;
+        if !escrow.has_taker() {
+            let now = clock::now()?;
+            self.taker_stats
+                .init_if_new(self.taker.key(), bumps.taker_stats);
+            self.taker_stats
+                .record_volume(escrow.initializer_amount, now, &self.config)?;
+            escrow.assign_taker(self.taker.key(), now);
+            emit!(TakerAssigned {
+                escrow: self.escrow.key(),
+                previous_taker: Pubkey::default(),
+                taker: self.taker.key(),
+            });
+        }
+
+        emit!(BondPosted {
+            escrow: self.escrow.key(),
+            taker: self.taker.key(),
+            mint: self.bond.mint,
+            amount,
+        });
+        Ok(())
+    }
+
+    fn into_sol_deposit_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.taker.to_account_info(),
+            to: self.bond.to_account_info(),
+        };
+        CpiContext::new(self.system_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::errors::EscrowError;
+use crate::events::MakerDepositPosted;
+use crate::states::{Config, Escrow, MakerDeposit};
+
+#[derive(Accounts)]
+pub struct PostMakerDeposit<'info> {
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
+    )]
+    pub initializer_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = mint_a,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        init,
+        payer = payer,
+        space = MakerDeposit::SPACE,
+        seeds = [b"maker_deposit", escrow.key().as_ref()],
+        bump,
+    )]
+    pub deposit: Box<Account<'info, MakerDeposit>>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint_a,
+        associated_token::authority = deposit,
+        associated_token::token_program = token_program
+    )]
+    pub deposit_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PostMakerDeposit<'info> {
+    // Stakes the deposit the config asks for against the escrow's current inventory
+    pub fn post_maker_deposit(&mut self, bumps: &PostMakerDepositBumps) -> Result<()> {
+        let amount = self
+            .escrow
+            .load()?
+            .required_maker_deposit(self.config.maker_deposit_bps)?;
+        require!(amount > 0, EscrowError::MakerDepositDisabled);
+
+        transfer_checked(self.into_deposit_context(), amount, self.mint_a.decimals)?;
+
+        self.deposit.set_inner(MakerDeposit {
+            version: MakerDeposit::VERSION,
+            bump: bumps.deposit,
+            forfeited: false,
+            escrow: self.escrow.key(),
+            maker: self.initializer.key(),
+            mint: self.mint_a.key(),
+            amount,
+            taker: Pubkey::default(),
+            _reserved: [0; 32],
+        });
+        self.escrow.load_mut()?.maker_deposit = amount;
+
+        emit!(MakerDepositPosted {
+            escrow: self.escrow.key(),
+            maker: self.initializer.key(),
+            amount,
+        });
+        Ok(())
+    }
+
+    fn into_deposit_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.initializer_ata_a.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.deposit_vault.to_account_info(),
+            authority: self.initializer.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
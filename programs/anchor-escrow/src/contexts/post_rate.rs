@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::RatePosted;
+use crate::states::RateFeed;
+
+#[derive(Accounts)]
+pub struct PostRate<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority @ EscrowError::Unauthorized,
+        seeds = [b"rate_feed", rate_feed.mint_a.as_ref(), rate_feed.mint_b.as_ref()],
+        bump = rate_feed.bump,
+    )]
+    pub rate_feed: Box<Account<'info, RateFeed>>,
+}
+
+impl<'info> PostRate<'info> {
+    pub fn post_rate(&mut self, rate: u64) -> Result<()> {
+        require!(rate > 0, EscrowError::InvalidRate);
+        self.rate_feed.rate = rate;
+        self.rate_feed.updated_at = clock::now()?;
+
+        emit!(RatePosted {
+            feed: self.rate_feed.key(),
+            rate,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::states::MakerRegistry;
+
+// Permissionless: drops escrows that were closed before every close path kept
+// the registry current. Remaining accounts are the escrows to drop, each of
+// which must no longer exist.
+#[derive(Accounts)]
+pub struct PruneRegistry<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry", maker_registry.maker.as_ref()],
+        bump = maker_registry.bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+}
+
+impl<'info> PruneRegistry<'info> {
+    pub fn prune_registry(&mut self, escrows: &[AccountInfo]) -> Result<()> {
+        for escrow in escrows {
+            require!(escrow.data_is_empty(), EscrowError::EscrowStillOpen);
+            self.maker_registry.remove(escrow.key);
+        }
+        Ok(())
+    }
+}
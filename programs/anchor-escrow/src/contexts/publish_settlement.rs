@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use std::convert::TryInto;
+
+use crate::constants::WORMHOLE_PROGRAM_ID;
+use crate::errors::EscrowError;
+use crate::events::SettlementPublished;
+use crate::states::{Config, Receipt};
+use crate::wormhole::{post_message, settlement_payload, PostMessageAccounts};
+
+// Posts a settled trade to Wormhole, so contracts on other chains (e.g. the
+// EVM leg of a cross-chain OTC deal) can learn from a guardian-signed VAA
+// that the Solana leg completed. Keyed on the trade's receipt like
+// `mint_badges`, so each settlement is published once; clients append it to
+// the settling transaction. Permissionless: the payer covers the bridge fee
+// and the message account's rent.
+#[derive(Accounts)]
+pub struct PublishSettlement<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        constraint = !receipt.wormhole_published @ EscrowError::SettlementAlreadyPublished,
+        seeds = [
+            b"receipt",
+            receipt.escrow.as_ref(),
+            receipt.payment_confirmed_at.to_le_bytes().as_ref(),
+        ],
+        bump = receipt.bump,
+    )]
+    pub receipt: Box<Account<'info, Receipt>>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.has_feature(Config::FEATURE_WORMHOLE) @ EscrowError::FeatureDisabled,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: The core bridge's config, checked by the bridge
+    #[account(mut, seeds = [b"Bridge"], bump, seeds::program = wormhole_program)]
+    pub wormhole_bridge: UncheckedAccount<'info>,
+    /// CHECK: Created by the bridge to hold the message, one per receipt
+    #[account(mut, seeds = [b"wormhole_message", receipt.key().as_ref()], bump)]
+    pub wormhole_message: UncheckedAccount<'info>,
+    /// CHECK: Data-less PDA every settlement message is emitted from
+    #[account(seeds = [b"emitter"], bump)]
+    pub wormhole_emitter: UncheckedAccount<'info>,
+    /// CHECK: The emitter's message counter, kept by the bridge
+    #[account(
+        mut,
+        seeds = [b"Sequence", wormhole_emitter.key().as_ref()],
+        bump,
+        seeds::program = wormhole_program,
+    )]
+    pub wormhole_sequence: UncheckedAccount<'info>,
+    /// CHECK: Collects the bridge's message fee
+    #[account(mut, seeds = [b"fee_collector"], bump, seeds::program = wormhole_program)]
+    pub wormhole_fee_collector: UncheckedAccount<'info>,
+    /// CHECK: The Wormhole core bridge
+    #[account(address = WORMHOLE_PROGRAM_ID)]
+    pub wormhole_program: UncheckedAccount<'info>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> PublishSettlement<'info> {
+    pub fn publish_settlement(&mut self, bumps: &PublishSettlementBumps) -> Result<()> {
+        // The bridge counts from 0 and creates the counter with the first message
+        let sequence = self
+            .wormhole_sequence
+            .try_borrow_data()?
+            .get(..8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u64::from_le_bytes)
+            .unwrap_or(0);
+        let receipt_key = self.receipt.key();
+        let payload = settlement_payload(&receipt_key, &self.receipt);
+        let signer_seeds: [&[&[u8]]; 2] = [
+            &[b"emitter", &[bumps.wormhole_emitter]],
+            &[
+                b"wormhole_message",
+                receipt_key.as_ref(),
+                &[bumps.wormhole_message],
+            ],
+        ];
+        post_message(
+            &PostMessageAccounts {
+                program: &self.wormhole_program,
+                bridge: &self.wormhole_bridge,
+                message: &self.wormhole_message,
+                emitter: &self.wormhole_emitter,
+                sequence: &self.wormhole_sequence,
+                payer: &self.payer,
+                fee_collector: &self.wormhole_fee_collector,
+                clock: &self.clock.to_account_info(),
+                rent: &self.rent.to_account_info(),
+                system_program: &self.system_program,
+            },
+            payload,
+            &signer_seeds,
+        )?;
+        self.receipt.wormhole_published = true;
+
+        emit!(SettlementPublished {
+            receipt: receipt_key,
+            escrow: self.receipt.escrow,
+            emitter: self.wormhole_emitter.key(),
+            sequence,
+        });
+        Ok(())
+    }
+}
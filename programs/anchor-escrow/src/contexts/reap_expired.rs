@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::clock;
+use crate::constants::PLATFORM_WALLET;
+use crate::errors::EscrowError;
+use crate::events::EscrowReaped;
+use crate::fees::forfeit_creation_fee;
+use crate::states::{Config, Escrow, MakerRegistry, TraderStats};
+
+// Permissionless so automation networks can crank it: no signer, and every
+// payout goes to the initializer, or the refund to a third-party funder.
+// Once reaped the escrow is gone, so a repeat call fails instead of paying
+// twice.
+#[derive(Accounts)]
+pub struct ReapExpired<'info> {
+    // Receives the refund and the vault and escrow rent
+    #[account(mut)]
+    pub initializer: SystemAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
+    )]
+    pub initializer_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    // Receives the refund instead when a third party funded the escrow
+    #[account(
+        mut,
+        token::mint = mint_a,
+        constraint = funder_ata_a.owner == escrow.load()?.funder @ EscrowError::InvalidFunder,
+    )]
+    pub funder_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = mint_a,
+        constraint = escrow.load()?.is_funded() @ EscrowError::EscrowNotFunded,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.has_partial_payment() @ EscrowError::PartialPaymentRecorded,
+        constraint = escrow.load()?.is_reapable(
+            clock::now()?,
+            config.reap_grace_period as i64,
+        ) @ EscrowError::EscrowNotExpired,
+        constraint = !escrow.load()?.is_frozen() @ EscrowError::EscrowFrozen,
+        constraint = !escrow.load()?.has_yield_position() @ EscrowError::YieldPositionOpen,
+        constraint = !escrow.load()?.is_dispute_paused() @ EscrowError::DisputeOpen,
+        close = initializer,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    // The initializer's profile, so the open-escrow count drops
+    #[account(
+        mut,
+        seeds = [b"trader_stats", escrow.load()?.initializer.as_ref()],
+        bump = initializer_stats.bump,
+    )]
+    pub initializer_stats: Box<Account<'info, TraderStats>>,
+    // The maker's registry, so the escrow drops off it
+    #[account(
+        mut,
+        seeds = [b"registry", escrow.load()?.initializer.as_ref()],
+        bump = maker_registry.bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    /// CHECK: This is the hardcoded platform wallet address, which keeps the
+    /// creation fee of offers that never settle
+    #[account(mut, address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ReapExpired<'info> {
+    // Compute is constant: one refund, one vault close, no loops
+    pub fn reap_expired(&mut self) -> Result<()> {
+        let escrow = *self.escrow.load()?;
+        let signer_seeds: [&[&[u8]]; 1] =
+            [&[b"state", &escrow.seed.to_le_bytes()[..], &[escrow.bump]]];
+
+        // Whatever is left, in case milestones already paid part of it out
+        let amount = self.vault.amount;
+        if amount > 0 {
+            transfer_checked(
+                self.into_refund_context(&escrow)?.with_signer(&signer_seeds),
+                amount,
+                self.mint_a.decimals,
+            )?;
+        }
+        close_account(self.into_close_context().with_signer(&signer_seeds))?;
+
+        if escrow.has_flag(Escrow::FLAG_CREATION_FEE_HELD) {
+            forfeit_creation_fee(
+                &self.escrow.to_account_info(),
+                &self.platform_wallet.to_account_info(),
+            )?;
+        }
+        self.initializer_stats.track_closed(1);
+        self.maker_registry.remove(&self.escrow.key());
+
+        emit!(EscrowReaped {
+            escrow: self.escrow.key(),
+            initializer: escrow.initializer,
+            amount,
+        });
+        Ok(())
+    }
+
+    fn into_refund_context(
+        &self,
+        escrow: &Escrow,
+    ) -> Result<CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>> {
+        let to = if escrow.has_funder() {
+            self.funder_ata_a
+                .as_ref()
+                .ok_or(EscrowError::InvalidFunder)?
+                .to_account_info()
+        } else {
+            self.initializer_ata_a.to_account_info()
+        };
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to,
+            authority: self.escrow.to_account_info(),
+        };
+        Ok(CpiContext::new(self.token_program.to_account_info(), cpi_accounts))
+    }
+
+    fn into_close_context(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        let cpi_accounts = CloseAccount {
+            account: self.vault.to_account_info(),
+            destination: self.initializer.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::constants::TAKER_REASSIGN_COOLDOWN_SECS;
+use crate::errors::EscrowError;
+use crate::events::TakerAssigned;
+use crate::states::Escrow;
+
+#[derive(Accounts)]
+pub struct ReassignTaker<'info> {
+    pub initializer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.has_partial_payment() @ EscrowError::PartialPaymentRecorded,
+        constraint = !escrow.load()?.is_bond_posted() @ EscrowError::BondLocked,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+}
+
+impl<'info> ReassignTaker<'info> {
+    // Pubkey::default() turns the escrow back into an open offer
+    pub fn reassign_taker(&mut self, new_taker: Pubkey) -> Result<()> {
+        let now = clock::now()?;
+        let mut escrow = self.escrow.load_mut()?;
+        require_keys_neq!(new_taker, escrow.initializer, EscrowError::InvalidTaker);
+
+        let previous_taker = escrow.taker;
+        if escrow.has_taker() {
+            require!(
+                now >= escrow.taker_assigned_at + TAKER_REASSIGN_COOLDOWN_SECS,
+                EscrowError::TakerReassignCooldown
+            );
+        }
+        escrow.assign_taker(new_taker, now);
+
+        emit!(TakerAssigned {
+            escrow: self.escrow.key(),
+            previous_taker,
+            taker: new_taker,
+        });
+        Ok(())
+    }
+}
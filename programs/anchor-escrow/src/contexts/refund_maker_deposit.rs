@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::errors::EscrowError;
+use crate::events::MakerDepositRefunded;
+use crate::states::MakerDeposit;
+
+// Permissionless once the escrow account is gone (settled or cancelled). The
+// recipient's associated token account must already exist.
+#[derive(Accounts)]
+pub struct RefundMakerDeposit<'info> {
+    /// CHECK: Must no longer hold escrow state
+    #[account(
+        address = deposit.escrow,
+        constraint = escrow.data_is_empty() @ EscrowError::EscrowNotSettled,
+    )]
+    pub escrow: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        has_one = maker,
+        has_one = mint,
+        close = maker,
+        seeds = [b"maker_deposit", escrow.key().as_ref()],
+        bump = deposit.bump,
+    )]
+    pub deposit: Box<Account<'info, MakerDeposit>>,
+    #[account(mut)]
+    pub maker: SystemAccount<'info>,
+    /// CHECK: Taker on forfeiture, the maker otherwise
+    #[account(address = deposit.recipient())]
+    pub recipient: UncheckedAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = deposit,
+        associated_token::token_program = token_program
+    )]
+    pub deposit_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = recipient,
+        associated_token::token_program = token_program
+    )]
+    pub recipient_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> RefundMakerDeposit<'info> {
+    pub fn refund_maker_deposit(&mut self) -> Result<()> {
+        let escrow_key = self.escrow.key();
+        let signer_seeds: [&[&[u8]]; 1] =
+            [&[b"maker_deposit", escrow_key.as_ref(), &[self.deposit.bump]]];
+
+        let amount = self.deposit_vault.amount;
+        if amount > 0 {
+            transfer_checked(
+                self.into_refund_context().with_signer(&signer_seeds),
+                amount,
+                self.mint.decimals,
+            )?;
+        }
+        close_account(self.into_close_context().with_signer(&signer_seeds))?;
+
+        emit!(MakerDepositRefunded {
+            escrow: escrow_key,
+            recipient: self.recipient.key(),
+            amount,
+        });
+        Ok(())
+    }
+
+    fn into_refund_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.deposit_vault.to_account_info(),
+            mint: self.mint.to_account_info(),
+            to: self.recipient_ata.to_account_info(),
+            authority: self.deposit.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_close_context(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        let cpi_accounts = CloseAccount {
+            account: self.deposit_vault.to_account_info(),
+            destination: self.maker.to_account_info(),
+            authority: self.deposit.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -0,0 +1,175 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::errors::EscrowError;
+use crate::events::BondReleased;
+use crate::states::Bond;
+
+// Permissionless once the escrow account is gone (settled or cancelled), or
+// as soon as a dispute ruling has forfeited the bond. Token bonds pass the
+// mint, the bond's token account, the recipient's and (for a slashed bond)
+// the arbiter's associated token accounts, and the token program.
+#[derive(Accounts)]
+pub struct ReleaseBond<'info> {
+    /// CHECK: Must no longer hold escrow state unless the bond was forfeited
+    #[account(
+        address = bond.escrow,
+        constraint = escrow.data_is_empty() || bond.forfeited @ EscrowError::EscrowNotSettled,
+    )]
+    pub escrow: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = initializer,
+        seeds = [b"bond", escrow.key().as_ref()],
+        bump = bond.bump,
+    )]
+    pub bond: Box<Account<'info, Bond>>,
+    #[account(mut, address = bond.initializer)]
+    pub initializer: SystemAccount<'info>,
+    #[account(mut, address = bond.recipient())]
+    pub recipient: SystemAccount<'info>,
+    #[account(mut, address = bond.arbiter)]
+    pub arbiter: Option<SystemAccount<'info>>,
+    pub bond_mint: Option<Box<InterfaceAccount<'info, Mint>>>,
+    #[account(mut)]
+    pub bond_vault: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub recipient_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(mut)]
+    pub arbiter_ata: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+impl<'info> ReleaseBond<'info> {
+    pub fn release_bond(&mut self) -> Result<()> {
+        let amount = if self.bond.posted {
+            self.bond.amount
+        } else {
+            0
+        };
+        let arbitration_fee = self.bond.arbitration_fee;
+        let payout = amount - arbitration_fee;
+
+        if arbitration_fee > 0 {
+            let arbiter = self
+                .arbiter
+                .as_ref()
+                .ok_or(EscrowError::InvalidBondAccounts)?
+                .to_account_info();
+            self.pay(arbiter, self.arbiter_ata.as_deref(), arbitration_fee)?;
+        }
+        if payout > 0 {
+            let recipient = self.recipient.to_account_info();
+            self.pay(recipient, self.recipient_ata.as_deref(), payout)?;
+        }
+        if amount > 0 && !self.bond.is_sol() {
+            self.close_vault()?;
+        }
+
+        emit!(BondReleased {
+            escrow: self.escrow.key(),
+            recipient: self.recipient.key(),
+            amount: payout,
+            forfeited: self.bond.forfeited,
+        });
+        Ok(())
+    }
+
+    // Moves `amount` of the bond to `to`, or to its associated token account
+    // for a token bond
+    fn pay(
+        &self,
+        to: AccountInfo<'info>,
+        to_ata: Option<&InterfaceAccount<'info, TokenAccount>>,
+        amount: u64,
+    ) -> Result<()> {
+        if self.bond.is_sol() {
+            self.bond.sub_lamports(amount)?;
+            to.add_lamports(amount)?;
+            return Ok(());
+        }
+
+        let (mint, vault, to_ata, token_program) = self.token_accounts(to_ata)?;
+        require_keys_eq!(
+            to_ata.key(),
+            get_associated_token_address_with_program_id(
+                &to.key(),
+                &mint.key(),
+                &token_program.key()
+            ),
+            EscrowError::InvalidBondAccounts
+        );
+
+        let escrow_key = self.escrow.key();
+        let signer_seeds: [&[&[u8]]; 1] = [&[b"bond", escrow_key.as_ref(), &[self.bond.bump]]];
+        let cpi_accounts = TransferChecked {
+            from: vault.to_account_info(),
+            mint: mint.to_account_info(),
+            to: to_ata.to_account_info(),
+            authority: self.bond.to_account_info(),
+        };
+        transfer_checked(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                cpi_accounts,
+                &signer_seeds,
+            ),
+            amount,
+            mint.decimals,
+        )
+    }
+
+    fn close_vault(&self) -> Result<()> {
+        let (_, vault, _, token_program) = self.token_accounts(self.recipient_ata.as_deref())?;
+        let escrow_key = self.escrow.key();
+        let signer_seeds: [&[&[u8]]; 1] = [&[b"bond", escrow_key.as_ref(), &[self.bond.bump]]];
+        let cpi_accounts = CloseAccount {
+            account: vault.to_account_info(),
+            destination: self.recipient.to_account_info(),
+            authority: self.bond.to_account_info(),
+        };
+        close_account(CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            cpi_accounts,
+            &signer_seeds,
+        ))
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn token_accounts<'a>(
+        &'a self,
+        to_ata: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    ) -> Result<(
+        &'a InterfaceAccount<'info, Mint>,
+        &'a InterfaceAccount<'info, TokenAccount>,
+        &'a InterfaceAccount<'info, TokenAccount>,
+        &'a Interface<'info, TokenInterface>,
+    )> {
+        let (mint, vault, to_ata, token_program) = match (
+            &self.bond_mint,
+            &self.bond_vault,
+            to_ata,
+            &self.token_program,
+        ) {
+            (Some(mint), Some(vault), Some(to_ata), Some(token_program)) => {
+                (mint, vault, to_ata, token_program)
+            }
+            _ => return err!(EscrowError::InvalidBondAccounts),
+        };
+        require_keys_eq!(mint.key(), self.bond.mint, EscrowError::InvalidBondAccounts);
+        require_keys_eq!(
+            vault.key(),
+            get_associated_token_address_with_program_id(
+                &self.bond.key(),
+                &mint.key(),
+                &token_program.key()
+            ),
+            EscrowError::InvalidBondAccounts
+        );
+        Ok((mint, vault, to_ata, token_program))
+    }
+}
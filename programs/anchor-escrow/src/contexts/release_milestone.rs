@@ -0,0 +1,289 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::clock;
+use crate::constants::PLATFORM_WALLET;
+use crate::errors::EscrowError;
+use crate::events::{InsuranceFunded, MilestoneReleased};
+use crate::fees::{FeeBreakdown, SettlementSplit};
+use crate::gating::require_compliance_approval;
+use crate::invariants::require_status_transition;
+use crate::payout::{pay_partner, PartnerAccounts, VaultAccounts};
+use crate::states::{
+    ComplianceApproval, Config, Escrow, EscrowStatus, MakerRegistry, Milestone, PartnerAccrual,
+    PartnerConfig, TraderStats,
+};
+
+#[derive(Accounts)]
+pub struct ReleaseMilestone<'info> {
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+    // Required when the milestone was created with arbiter sign-off
+    pub arbiter: Option<Signer<'info>>,
+    pub taker: SystemAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program
+    )]
+    pub taker_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = platform_wallet,
+        associated_token::token_program = token_program
+    )]
+    pub platform_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = mint_a,
+        has_one = taker @ EscrowError::InvalidTaker,
+        constraint = escrow.load()?.is_milestones() @ EscrowError::ReleaseModeMismatch,
+        constraint = !escrow.load()?.is_frozen() @ EscrowError::EscrowFrozen,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        mut,
+        has_one = escrow,
+        constraint = milestone.confirmed @ EscrowError::MilestoneNotConfirmed,
+        constraint = !milestone.is_settled() @ EscrowError::MilestoneAlreadyReleased,
+        constraint = !milestone.disputed @ EscrowError::MilestoneDisputed,
+        seeds = [b"milestone", escrow.key().as_ref(), &[milestone.index]],
+        bump = milestone.bump,
+    )]
+    pub milestone: Box<Account<'info, Milestone>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Data-less PDA that owns the insurance fund token accounts
+    #[account(seeds = [b"insurance"], bump = config.insurance_bump)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program
+    )]
+    pub insurance_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    // The initializer's profile, so the open-escrow count drops
+    #[account(
+        mut,
+        seeds = [b"trader_stats", escrow.load()?.initializer.as_ref()],
+        bump = initializer_stats.bump,
+    )]
+    pub initializer_stats: Box<Account<'info, TraderStats>>,
+    // The maker's registry, so the escrow drops off it
+    #[account(
+        mut,
+        seeds = [b"registry", escrow.load()?.initializer.as_ref()],
+        bump = maker_registry.bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    // Sign-off from `approve_release`, required while the config or the
+    // offer asks for compliance approval
+    #[account(
+        seeds = [b"compliance_approval", escrow.key().as_ref()],
+        bump = compliance_approval.bump,
+    )]
+    pub compliance_approval: Option<Box<Account<'info, ComplianceApproval>>>,
+    // The partner the offer was created through and the partner fee wallet's
+    // mint_a account, required when the escrow has a partner
+    #[account(
+        seeds = [b"partner", escrow.load()?.partner_id.to_le_bytes().as_ref()],
+        bump = partner_config.bump,
+    )]
+    pub partner_config: Option<Box<Account<'info, PartnerConfig>>>,
+    #[account(mut, token::mint = mint_a)]
+    pub partner_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    // Partner pool and the partner's accrual, required while the partner
+    // takes a share of the platform fee
+    /// CHECK: Data-less PDA that owns the partner pool token accounts
+    #[account(seeds = [b"partner_pool"], bump)]
+    pub partner_pool: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = partner_pool,
+        associated_token::token_program = token_program
+    )]
+    pub partner_pool_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        init_if_needed,
+        payer = initializer,
+        space = PartnerAccrual::SPACE,
+        seeds = [
+            b"partner_accrual",
+            escrow.load()?.partner_id.to_le_bytes().as_ref(),
+            mint_a.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub partner_accrual: Option<Box<Account<'info, PartnerAccrual>>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReleaseMilestone<'info> {
+    pub fn release_milestone(&mut self, bumps: &ReleaseMilestoneBumps) -> Result<()> {
+        let escrow = *self.escrow.load()?;
+        require_compliance_approval(&self.config, &escrow, self.compliance_approval.as_deref())?;
+        if self.milestone.requires_arbiter {
+            let arbiter = self
+                .arbiter
+                .as_ref()
+                .ok_or(EscrowError::ArbiterSignatureRequired)?;
+            require_keys_eq!(
+                arbiter.key(),
+                escrow.arbiter,
+                EscrowError::ArbiterSignatureRequired
+            );
+        }
+
+        let signer_seeds: [&[&[u8]]; 1] =
+            [&[b"state", &escrow.seed.to_le_bytes()[..], &[escrow.bump]]];
+
+        let now = clock::now()?;
+        let fees = FeeBreakdown::for_settlement(
+            &self.config,
+            escrow.category,
+            self.milestone.amount,
+            now,
+        )?;
+        // The insurance fund's slice comes out of the platform fee
+        let mut split =
+            SettlementSplit::for_payout(&self.config, fees.platform_fee, fees.net_amount)?;
+        if escrow.has_partner() {
+            let vault = VaultAccounts {
+                escrow: &self.escrow.to_account_info(),
+                vault: &self.vault.to_account_info(),
+                mint: &self.mint_a.to_account_info(),
+                decimals: self.mint_a.decimals,
+                token_program: &self.token_program.to_account_info(),
+            };
+            let partner = PartnerAccounts {
+                config: self.partner_config.as_deref(),
+                fee_ata: self.partner_ata_a.as_deref(),
+                pool_ata: self.partner_pool_ata_a.as_deref(),
+                accrual: self.partner_accrual.as_deref_mut(),
+                accrual_bump: bumps.partner_accrual,
+            };
+            split = split.with_partner(partner.config()?)?;
+            pay_partner(&vault, partner, &split, now, &signer_seeds)?;
+        }
+        let SettlementSplit {
+            platform_share,
+            insurance_share,
+            buyer_amount,
+            ..
+        } = split;
+        if insurance_share > 0 {
+            transfer_checked(
+                self.into_insurance_fee_context().with_signer(&signer_seeds),
+                insurance_share,
+                self.mint_a.decimals,
+            )?;
+            emit!(InsuranceFunded {
+                escrow: self.escrow.key(),
+                mint: self.mint_a.key(),
+                amount: insurance_share,
+            });
+        }
+        if platform_share > 0 {
+            transfer_checked(
+                self.into_platform_fee_context().with_signer(&signer_seeds),
+                platform_share,
+                self.mint_a.decimals,
+            )?;
+        }
+        if buyer_amount > 0 {
+            transfer_checked(
+                self.into_withdraw_context().with_signer(&signer_seeds),
+                buyer_amount,
+                self.mint_a.decimals,
+            )?;
+        }
+
+        self.milestone.released = true;
+        let milestones_released = escrow.milestones_released + 1;
+        {
+            let mut state = self.escrow.load_mut()?;
+            // Once a tranche is out the escrow can no longer be cancelled
+            state.status = EscrowStatus::Releasing as u8;
+            state.milestones_released = milestones_released;
+            state.released_amount += self.milestone.amount;
+            require_status_transition(escrow.status, &state)?;
+        }
+
+        emit!(MilestoneReleased {
+            escrow: self.escrow.key(),
+            index: self.milestone.index,
+            amount: buyer_amount,
+            platform_fee: fees.platform_fee,
+        });
+
+        if milestones_released == escrow.milestone_count {
+            close_account(self.into_close_context().with_signer(&signer_seeds))?;
+            self.escrow.close(self.initializer.to_account_info())?;
+            self.initializer_stats.track_closed(1);
+            self.maker_registry.remove(&self.escrow.key());
+        }
+        Ok(())
+    }
+
+    fn into_withdraw_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.taker_ata_a.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_platform_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.platform_ata_a.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_insurance_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.insurance_ata_a.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_close_context(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        let cpi_accounts = CloseAccount {
+            account: self.vault.to_account_info(),
+            destination: self.initializer.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::clock;
+use crate::constants::PLATFORM_WALLET;
+use crate::errors::EscrowError;
+use crate::events::{InsuranceFunded, TrancheReleased};
+use crate::fees::{FeeBreakdown, SettlementSplit};
+use crate::gating::require_compliance_approval;
+use crate::invariants::require_vault_backs_escrow;
+use crate::payout::{pay_partner, PartnerAccounts, VaultAccounts};
+use crate::states::{ComplianceApproval, Config, Escrow, PartnerAccrual, PartnerConfig};
+
+// The seller pays out the mint_a matching the fiat tranches the taker has
+// confirmed so far. The escrow keeps going on the remainder, and the tranche
+// that completes the payment settles through `exchange` as usual.
+#[derive(Accounts)]
+pub struct ReleaseTranche<'info> {
+    // Pays for the partner's accrual when a release opens it
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+    pub taker: SystemAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program
+    )]
+    pub taker_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = platform_wallet,
+        associated_token::token_program = token_program
+    )]
+    pub platform_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = mint_a,
+        has_one = taker @ EscrowError::InvalidTaker,
+        constraint = escrow.load()?.is_funded() @ EscrowError::EscrowNotFunded,
+        constraint = escrow.load()?.is_immediate() @ EscrowError::ReleaseModeMismatch,
+        constraint = escrow.load()?.releases_per_tranche() @ EscrowError::PartialPaymentsDisabled,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.is_frozen() @ EscrowError::EscrowFrozen,
+        constraint = !escrow.load()?.has_yield_position() @ EscrowError::YieldPositionOpen,
+        constraint = escrow.load()?.is_tranche_release_unlocked(clock::now()?) @ EscrowError::ReleaseDelayPending,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Data-less PDA that owns the insurance fund token accounts
+    #[account(seeds = [b"insurance"], bump = config.insurance_bump)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program
+    )]
+    pub insurance_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    // Sign-off from `approve_release`, required while the config or the
+    // offer asks for compliance approval
+    #[account(
+        seeds = [b"compliance_approval", escrow.key().as_ref()],
+        bump = compliance_approval.bump,
+    )]
+    pub compliance_approval: Option<Box<Account<'info, ComplianceApproval>>>,
+    // The partner the offer was created through and the partner fee wallet's
+    // mint_a account, required when the escrow has a partner
+    #[account(
+        seeds = [b"partner", escrow.load()?.partner_id.to_le_bytes().as_ref()],
+        bump = partner_config.bump,
+    )]
+    pub partner_config: Option<Box<Account<'info, PartnerConfig>>>,
+    #[account(mut, token::mint = mint_a)]
+    pub partner_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    // Partner pool and the partner's accrual, required while the partner
+    // takes a share of the platform fee
+    /// CHECK: Data-less PDA that owns the partner pool token accounts
+    #[account(seeds = [b"partner_pool"], bump)]
+    pub partner_pool: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = partner_pool,
+        associated_token::token_program = token_program
+    )]
+    pub partner_pool_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        init_if_needed,
+        payer = initializer,
+        space = PartnerAccrual::SPACE,
+        seeds = [
+            b"partner_accrual",
+            escrow.load()?.partner_id.to_le_bytes().as_ref(),
+            mint_a.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub partner_accrual: Option<Box<Account<'info, PartnerAccrual>>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ReleaseTranche<'info> {
+    pub fn release_tranche(&mut self, bumps: &ReleaseTrancheBumps) -> Result<()> {
+        let escrow = *self.escrow.load()?;
+        require_compliance_approval(&self.config, &escrow, self.compliance_approval.as_deref())?;
+        let signer_seeds: [&[&[u8]]; 1] =
+            [&[b"state", &escrow.seed.to_le_bytes()[..], &[escrow.bump]]];
+
+        let gross = escrow.tranche_release_amount()?;
+        require!(gross > 0, EscrowError::NothingToClaim);
+        let now = clock::now()?;
+        let FeeBreakdown {
+            platform_fee,
+            net_amount,
+            ..
+        } = FeeBreakdown::for_settlement(&self.config, escrow.category, gross, now)?;
+
+        // The insurance fund's slice comes out of the platform fee
+        let mut split = SettlementSplit::for_payout(&self.config, platform_fee, net_amount)?;
+        if escrow.has_partner() {
+            let vault = VaultAccounts {
+                escrow: &self.escrow.to_account_info(),
+                vault: &self.vault.to_account_info(),
+                mint: &self.mint_a.to_account_info(),
+                decimals: self.mint_a.decimals,
+                token_program: &self.token_program.to_account_info(),
+            };
+            let partner = PartnerAccounts {
+                config: self.partner_config.as_deref(),
+                fee_ata: self.partner_ata_a.as_deref(),
+                pool_ata: self.partner_pool_ata_a.as_deref(),
+                accrual: self.partner_accrual.as_deref_mut(),
+                accrual_bump: bumps.partner_accrual,
+            };
+            split = split.with_partner(partner.config()?)?;
+            pay_partner(&vault, partner, &split, now, &signer_seeds)?;
+        }
+        let SettlementSplit {
+            platform_share,
+            insurance_share,
+            buyer_amount,
+            ..
+        } = split;
+        if insurance_share > 0 {
+            transfer_checked(
+                self.into_insurance_fee_context().with_signer(&signer_seeds),
+                insurance_share,
+                self.mint_a.decimals,
+            )?;
+            emit!(InsuranceFunded {
+                escrow: self.escrow.key(),
+                mint: self.mint_a.key(),
+                amount: insurance_share,
+            });
+        }
+        if platform_share > 0 {
+            transfer_checked(
+                self.into_platform_fee_context().with_signer(&signer_seeds),
+                platform_share,
+                self.mint_a.decimals,
+            )?;
+        }
+        if buyer_amount > 0 {
+            transfer_checked(
+                self.into_withdraw_context().with_signer(&signer_seeds),
+                buyer_amount,
+                self.mint_a.decimals,
+            )?;
+        }
+
+        let mut state = self.escrow.load_mut()?;
+        state.settle_tranche(gross);
+        require_vault_backs_escrow(&state, &mut self.vault)?;
+        emit!(TrancheReleased {
+            escrow: self.escrow.key(),
+            taker: self.taker.key(),
+            fiat_amount: escrow.confirmed_amount,
+            amount: buyer_amount,
+            platform_fee,
+            remaining_amount: state.initializer_amount,
+        });
+        Ok(())
+    }
+
+    fn into_withdraw_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.taker_ata_a.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_platform_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.platform_ata_a.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_insurance_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.insurance_ata_a.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::EscrowRepriced;
+use crate::states::{Escrow, MakerSession};
+
+#[derive(Accounts)]
+pub struct Reprice<'info> {
+    // The maker, or a session key acting for them
+    pub authority: Signer<'info>,
+    pub initializer: SystemAccount<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    // The signer's session, required unless the initializer signs
+    #[account(
+        seeds = [b"session", initializer.key().as_ref(), authority.key().as_ref()],
+        bump = session.bump,
+    )]
+    pub session: Option<Box<Account<'info, MakerSession>>>,
+}
+
+impl<'info> Reprice<'info> {
+    pub fn reprice(&mut self, taker_amount: u64) -> Result<()> {
+        MakerSession::authorize(
+            &self.initializer.key(),
+            &self.authority.key(),
+            self.session.as_deref(),
+            MakerSession::SCOPE_MANAGE_OFFERS,
+        )?;
+        let mut escrow = self.escrow.load_mut()?;
+        let old_taker_amount = escrow.taker_amount;
+        escrow.taker_amount = taker_amount;
+        // A new public price replaces a hidden one
+        escrow.price_commitment = [0; 32];
+
+        emit!(EscrowRepriced {
+            escrow: self.escrow.key(),
+            old_taker_amount,
+            new_taker_amount: taker_amount,
+        });
+        Ok(())
+    }
+}
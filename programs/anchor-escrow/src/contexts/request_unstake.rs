@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::ArbitratorUnstakeRequested;
+use crate::states::{ArbitrationPolicy, Arbitrator};
+
+// Takes the arbitrator out of assignment straight away; the stake stays put
+// for one appeal window so their latest rulings can still be slashed
+#[derive(Accounts)]
+pub struct RequestUnstake<'info> {
+    pub wallet: Signer<'info>,
+    #[account(
+        mut,
+        has_one = wallet,
+        constraint = arbitrator.staked > 0 @ EscrowError::ArbitratorNotStaked,
+        seeds = [b"arbitrator", wallet.key().as_ref()],
+        bump = arbitrator.bump,
+    )]
+    pub arbitrator: Box<Account<'info, Arbitrator>>,
+    #[account(seeds = [b"arbitration"], bump = arbitration.bump)]
+    pub arbitration: Box<Account<'info, ArbitrationPolicy>>,
+}
+
+impl<'info> RequestUnstake<'info> {
+    pub fn request_unstake(&mut self) -> Result<()> {
+        let unlock_at = clock::now()?
+            .checked_add(self.arbitration.appeal_window)
+            .ok_or(EscrowError::MathOverflow)?;
+        self.arbitrator.unlock_at = unlock_at;
+
+        emit!(ArbitratorUnstakeRequested {
+            arbitrator: self.wallet.key(),
+            unlock_at,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::states::{Bond, BondState, Escrow};
+
+#[derive(Accounts)]
+pub struct RequireBond<'info> {
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        init,
+        payer = payer,
+        space = Bond::SPACE,
+        seeds = [b"bond", escrow.key().as_ref()],
+        bump,
+    )]
+    pub bond: Box<Account<'info, Bond>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RequireBond<'info> {
+    // `mint` is Pubkey::default() for a bond in SOL
+    pub fn require_bond(
+        &mut self,
+        bumps: &RequireBondBumps,
+        mint: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, EscrowError::InvalidAmount);
+        self.bond.set_inner(Bond {
+            version: Bond::VERSION,
+            bump: bumps.bond,
+            posted: false,
+            forfeited: false,
+            escrow: self.escrow.key(),
+            initializer: self.initializer.key(),
+            taker: Pubkey::default(),
+            mint,
+            amount,
+            arbiter: Pubkey::default(),
+            arbitration_fee: 0,
+            _reserved: [0; 32],
+        });
+        self.escrow.load_mut()?.bond_state = BondState::Required as u8;
+        Ok(())
+    }
+}
@@ -0,0 +1,520 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer_checked, CloseAccount, Mint, TokenAccount, TokenInterface,
+    TransferChecked,
+};
+
+use crate::audit::record_admin_action;
+use crate::clock;
+use crate::constants::{BPS_DENOMINATOR, PLATFORM_WALLET};
+use crate::errors::EscrowError;
+use crate::events::{BondSlashed, DisputeFeeSettled, InsuranceFunded, MilestoneDisputeResolved};
+use crate::fees::{pro_rata, FeeBreakdown, SettlementSplit};
+use crate::invariants::require_status_transition;
+use crate::payout::{pay_partner, PartnerAccounts, VaultAccounts};
+use crate::states::{
+    AdminAction, ArbitrationPolicy, Arbitrator, AuditLog, Bond, BondState, Config, Escrow,
+    EscrowStatus, GovernanceAuthority, Jury, MakerRegistry, Milestone, MilestoneDispute,
+    PartnerAccrual, PartnerConfig, TraderStats,
+};
+
+// The arbiter settles a disputed tranche: paid to the taker (minus the
+// platform fee) or refunded to the initializer. The dispute fee goes back to
+// the party who opened the dispute if they win, and otherwise pays the
+// arbiter (or, for a jury or the DAO, the platform). Once a jury has been
+// convened on the dispute, `tally_and_resolve` executes its majority verdict
+// through the same accounts instead, and once a party has escalated it, only
+// `governance_resolve` can rule.
+#[derive(Accounts)]
+pub struct ResolveMilestoneDispute<'info> {
+    // The escrow's arbiter, anyone cranking a jury verdict, or the governance
+    // authority
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+    #[account(mut)]
+    pub initializer: SystemAccount<'info>,
+    #[account(mut)]
+    pub taker: SystemAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = taker,
+        associated_token::token_program = token_program
+    )]
+    pub taker_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
+    )]
+    pub initializer_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = platform_wallet,
+        associated_token::token_program = token_program
+    )]
+    pub platform_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = mint_a,
+        has_one = taker @ EscrowError::InvalidTaker,
+        constraint = !escrow.load()?.is_frozen() @ EscrowError::EscrowFrozen,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        mut,
+        has_one = escrow,
+        constraint = milestone.disputed @ EscrowError::MilestoneNotDisputed,
+        seeds = [b"milestone", escrow.key().as_ref(), &[milestone.index]],
+        bump = milestone.bump,
+    )]
+    pub milestone: Box<Account<'info, Milestone>>,
+    #[account(
+        mut,
+        has_one = milestone,
+        seeds = [b"milestone_dispute", milestone.key().as_ref()],
+        bump = dispute.bump,
+    )]
+    pub dispute: Box<Account<'info, MilestoneDispute>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(mut, address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    /// CHECK: Data-less PDA that owns the insurance fund token accounts
+    #[account(seeds = [b"insurance"], bump = config.insurance_bump)]
+    pub insurance_fund: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = insurance_fund,
+        associated_token::token_program = token_program
+    )]
+    pub insurance_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    // Required when the taker posted a bond, which a refund ruling forfeits
+    #[account(
+        mut,
+        seeds = [b"bond", escrow.key().as_ref()],
+        bump = bond.bump,
+    )]
+    pub bond: Option<Box<Account<'info, Bond>>>,
+    #[account(
+        init_if_needed,
+        payer = arbiter,
+        space = TraderStats::SPACE,
+        seeds = [b"trader_stats", initializer.key().as_ref()],
+        bump,
+    )]
+    pub initializer_stats: Box<Account<'info, TraderStats>>,
+    // The maker's registry, so the escrow drops off it
+    #[account(
+        mut,
+        seeds = [b"registry", escrow.load()?.initializer.as_ref()],
+        bump = maker_registry.bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    #[account(
+        init_if_needed,
+        payer = arbiter,
+        space = TraderStats::SPACE,
+        seeds = [b"trader_stats", taker.key().as_ref()],
+        bump,
+    )]
+    pub taker_stats: Box<Account<'info, TraderStats>>,
+    /// CHECK: The arbiter's registry profile, updated when it exists
+    #[account(mut, seeds = [b"arbitrator", arbiter.key().as_ref()], bump)]
+    pub arbitrator: UncheckedAccount<'info>,
+    /// CHECK: Arbitration policy PDA; may not exist yet
+    #[account(seeds = [b"arbitration"], bump)]
+    pub arbitration: UncheckedAccount<'info>,
+    /// CHECK: The dispute's jury PDA; exists only if one was convened
+    #[account(seeds = [b"jury", dispute.key().as_ref()], bump)]
+    pub jury: UncheckedAccount<'info>,
+    /// CHECK: The dispute's escalation PDA; exists only if a party escalated it
+    #[account(seeds = [b"escalation", dispute.key().as_ref()], bump)]
+    pub escalation: UncheckedAccount<'info>,
+    // Required by `governance_resolve`
+    #[account(seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Option<Box<Account<'info, GovernanceAuthority>>>,
+    // The partner the offer was created through and the partner fee wallet's
+    // mint_a account, required when the escrow has a partner
+    #[account(
+        seeds = [b"partner", escrow.load()?.partner_id.to_le_bytes().as_ref()],
+        bump = partner_config.bump,
+    )]
+    pub partner_config: Option<Box<Account<'info, PartnerConfig>>>,
+    #[account(mut, token::mint = mint_a)]
+    pub partner_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    // Partner pool and the partner's accrual, required while the partner
+    // takes a share of the platform fee
+    /// CHECK: Data-less PDA that owns the partner pool token accounts
+    #[account(seeds = [b"partner_pool"], bump)]
+    pub partner_pool: Option<UncheckedAccount<'info>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = partner_pool,
+        associated_token::token_program = token_program
+    )]
+    pub partner_pool_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    #[account(
+        init_if_needed,
+        payer = arbiter,
+        space = PartnerAccrual::SPACE,
+        seeds = [
+            b"partner_accrual",
+            escrow.load()?.partner_id.to_le_bytes().as_ref(),
+            mint_a.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub partner_accrual: Option<Box<Account<'info, PartnerAccrual>>>,
+    pub token_program: Interface<'info, TokenInterface>,
+    #[account(
+        init_if_needed,
+        payer = arbiter,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ResolveMilestoneDispute<'info> {
+    pub fn resolve_milestone_dispute(
+        &mut self,
+        bumps: &ResolveMilestoneDisputeBumps,
+        release_to_taker: bool,
+    ) -> Result<()> {
+        require_keys_eq!(
+            self.arbiter.key(),
+            self.escrow.load()?.arbiter,
+            EscrowError::InvalidArbiter
+        );
+        require!(self.jury.data_is_empty(), EscrowError::JuryConvened);
+        require!(
+            self.escalation.data_is_empty(),
+            EscrowError::DisputeEscalated
+        );
+        self.resolve(bumps, release_to_taker, None)
+    }
+
+    // Permissionless once the jury has a verdict
+    pub fn tally_and_resolve(&mut self, bumps: &ResolveMilestoneDisputeBumps) -> Result<()> {
+        require!(!self.jury.data_is_empty(), EscrowError::NoJury);
+        require!(
+            self.escalation.data_is_empty(),
+            EscrowError::DisputeEscalated
+        );
+        let jury = {
+            let data = self.jury.try_borrow_data()?;
+            Jury::try_deserialize(&mut &data[..])?
+        };
+        let release_to_taker = jury.verdict(clock::now()?).ok_or(EscrowError::JuryUndecided)?;
+        self.resolve(bumps, release_to_taker, Some(self.jury.key()))
+    }
+
+    // Executed by an SPL Governance proposal on an escalated dispute
+    pub fn governance_resolve(
+        &mut self,
+        bumps: &ResolveMilestoneDisputeBumps,
+        release_to_taker: bool,
+    ) -> Result<()> {
+        require!(
+            !self.escalation.data_is_empty(),
+            EscrowError::DisputeNotEscalated
+        );
+        let authority = self
+            .governance
+            .as_ref()
+            .ok_or(EscrowError::GovernanceNotSet)?
+            .authority;
+        require_keys_eq!(
+            self.arbiter.key(),
+            authority,
+            EscrowError::NotGovernanceAuthority
+        );
+        self.resolve(bumps, release_to_taker, Some(authority))
+    }
+
+    // `panel` is the jury or governance authority ruling in place of the
+    // escrow's arbiter, None for the arbiter's own ruling
+    fn resolve(
+        &mut self,
+        bumps: &ResolveMilestoneDisputeBumps,
+        release_to_taker: bool,
+        panel: Option<Pubkey>,
+    ) -> Result<()> {
+        let escrow = *self.escrow.load()?;
+        self.initializer_stats
+            .init_if_new(self.initializer.key(), bumps.initializer_stats);
+        self.taker_stats
+            .init_if_new(self.taker.key(), bumps.taker_stats);
+        let signer_seeds: [&[&[u8]]; 1] =
+            [&[b"state", &escrow.seed.to_le_bytes()[..], &[escrow.bump]]];
+
+        let amount = self.milestone.amount;
+        if release_to_taker {
+            let now = clock::now()?;
+            let fees = FeeBreakdown::for_settlement(&self.config, escrow.category, amount, now)?;
+            // The insurance fund's slice comes out of the platform fee
+            let mut split =
+                SettlementSplit::for_payout(&self.config, fees.platform_fee, fees.net_amount)?;
+            if escrow.has_partner() {
+                let vault = VaultAccounts {
+                    escrow: &self.escrow.to_account_info(),
+                    vault: &self.vault.to_account_info(),
+                    mint: &self.mint_a.to_account_info(),
+                    decimals: self.mint_a.decimals,
+                    token_program: &self.token_program.to_account_info(),
+                };
+                let partner = PartnerAccounts {
+                    config: self.partner_config.as_deref(),
+                    fee_ata: self.partner_ata_a.as_deref(),
+                    pool_ata: self.partner_pool_ata_a.as_deref(),
+                    accrual: self.partner_accrual.as_deref_mut(),
+                    accrual_bump: bumps.partner_accrual,
+                };
+                split = split.with_partner(partner.config()?)?;
+                pay_partner(&vault, partner, &split, now, &signer_seeds)?;
+            }
+            let SettlementSplit {
+                platform_share,
+                insurance_share,
+                buyer_amount,
+                ..
+            } = split;
+            if insurance_share > 0 {
+                transfer_checked(
+                    self.into_transfer_context(self.insurance_ata_a.to_account_info())
+                        .with_signer(&signer_seeds),
+                    insurance_share,
+                    self.mint_a.decimals,
+                )?;
+                emit!(InsuranceFunded {
+                    escrow: self.escrow.key(),
+                    mint: self.mint_a.key(),
+                    amount: insurance_share,
+                });
+            }
+            if platform_share > 0 {
+                transfer_checked(
+                    self.into_transfer_context(self.platform_ata_a.to_account_info())
+                        .with_signer(&signer_seeds),
+                    platform_share,
+                    self.mint_a.decimals,
+                )?;
+            }
+            if buyer_amount > 0 {
+                transfer_checked(
+                    self.into_transfer_context(self.taker_ata_a.to_account_info())
+                        .with_signer(&signer_seeds),
+                    buyer_amount,
+                    self.mint_a.decimals,
+                )?;
+            }
+            self.milestone.released = true;
+            self.taker_stats.disputes_won += 1;
+            self.initializer_stats.disputes_lost += 1;
+        } else {
+            transfer_checked(
+                self.into_transfer_context(self.initializer_ata_a.to_account_info())
+                    .with_signer(&signer_seeds),
+                amount,
+                self.mint_a.decimals,
+            )?;
+            self.milestone.refunded = true;
+            self.initializer_stats.disputes_won += 1;
+            self.taker_stats.disputes_lost += 1;
+            if escrow.is_bond_posted() {
+                self.slash_bond(panel.is_none())?;
+            }
+        }
+        self.milestone.disputed = false;
+
+        let now = clock::now()?;
+        self.dispute.resolved = true;
+        self.dispute.released_to_taker = release_to_taker;
+        self.dispute.resolved_at = now;
+        // A panel's ruling has no single arbiter to credit or slash
+        let ruled_by = match panel {
+            Some(panel) => panel,
+            None => {
+                self.dispute.arbiter = self.arbiter.key();
+                self.record_ruling(now)?;
+                self.arbiter.key()
+            }
+        };
+        self.settle_dispute_fee(release_to_taker, panel.is_some())?;
+
+        let milestones_released = escrow.milestones_released + 1;
+        {
+            let mut state = self.escrow.load_mut()?;
+            state.status = EscrowStatus::Releasing as u8;
+            state.milestones_released = milestones_released;
+            if release_to_taker {
+                state.released_amount += amount;
+            }
+            state.resume_after_dispute(now);
+            require_status_transition(escrow.status, &state)?;
+        }
+
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::ResolveDispute,
+            ruled_by,
+            self.milestone.key(),
+            &release_to_taker,
+        )?;
+
+        emit!(MilestoneDisputeResolved {
+            escrow: self.escrow.key(),
+            index: self.milestone.index,
+            arbiter: ruled_by,
+            released_to_taker: release_to_taker,
+        });
+
+        if milestones_released == escrow.milestone_count {
+            close_account(self.into_close_context().with_signer(&signer_seeds))?;
+            self.escrow.close(self.initializer.to_account_info())?;
+            self.initializer_stats.track_closed(1);
+            self.maker_registry.remove(&self.escrow.key());
+        }
+        Ok(())
+    }
+
+    // The losing taker's bond goes to the initializer minus the arbiter's cut,
+    // which a panel's ruling doesn't take; `release_bond` pays it out
+    fn slash_bond(&mut self, arbiter_cut: bool) -> Result<()> {
+        let arbitration_fee_bps = if arbiter_cut {
+            self.config.arbitration_fee_bps
+        } else {
+            0
+        };
+        let bond = self.bond.as_mut().ok_or(EscrowError::InvalidBondAccounts)?;
+        let arbitration_fee = pro_rata(
+            bond.amount,
+            arbitration_fee_bps as u64,
+            BPS_DENOMINATOR as u64,
+        )?;
+        bond.forfeited = true;
+        bond.arbiter = self.arbiter.key();
+        bond.arbitration_fee = arbitration_fee;
+
+        self.escrow.load_mut()?.bond_state = BondState::Forfeited as u8;
+        self.taker_stats.bonds_forfeited += 1;
+
+        emit!(BondSlashed {
+            escrow: self.escrow.key(),
+            taker: bond.taker,
+            winner: bond.initializer,
+            amount: bond.amount - arbitration_fee,
+            arbitration_fee,
+        });
+        Ok(())
+    }
+
+    // Whatever the dispute account holds above its rent is the opener's fee
+    fn settle_dispute_fee(&mut self, release_to_taker: bool, by_panel: bool) -> Result<()> {
+        let info = self.dispute.to_account_info();
+        let rent = Rent::get()?.minimum_balance(info.data_len());
+        let fee = info.lamports().saturating_sub(rent);
+        if fee == 0 {
+            return Ok(());
+        }
+        let opener = self.dispute.opened_by;
+        let opener_won = (opener == self.taker.key()) == release_to_taker;
+        let recipient = if opener_won {
+            if opener == self.taker.key() {
+                self.taker.to_account_info()
+            } else {
+                self.initializer.to_account_info()
+            }
+        } else if by_panel {
+            self.platform_wallet.to_account_info()
+        } else {
+            self.arbiter.to_account_info()
+        };
+        info.sub_lamports(fee)?;
+        recipient.add_lamports(fee)?;
+
+        emit!(DisputeFeeSettled {
+            escrow: self.escrow.key(),
+            index: self.milestone.index,
+            recipient: recipient.key(),
+            amount: fee,
+            refunded: opener_won,
+        });
+        Ok(())
+    }
+
+    // A registered arbiter's track record counts the case. Under an
+    // arbitration policy they also earn its case reward, and a pending unstake
+    // is held back until this ruling's appeal window closes.
+    fn record_ruling(&mut self, now: i64) -> Result<()> {
+        if self.arbitrator.data_is_empty() {
+            return Ok(());
+        }
+        let mut arbitrator = {
+            let data = self.arbitrator.try_borrow_data()?;
+            Arbitrator::try_deserialize(&mut &data[..])?
+        };
+        arbitrator.track_resolved(now - self.dispute.opened_at);
+        if !self.arbitration.data_is_empty() {
+            let policy = {
+                let data = self.arbitration.try_borrow_data()?;
+                ArbitrationPolicy::try_deserialize(&mut &data[..])?
+            };
+            arbitrator.pending_rewards = arbitrator
+                .pending_rewards
+                .checked_add(policy.reward_per_case)
+                .ok_or(EscrowError::MathOverflow)?;
+            if arbitrator.unlock_at != 0 {
+                let appeal_closes_at = now
+                    .checked_add(policy.appeal_window)
+                    .ok_or(EscrowError::MathOverflow)?;
+                arbitrator.unlock_at = arbitrator.unlock_at.max(appeal_closes_at);
+            }
+        }
+        let mut data = self.arbitrator.try_borrow_mut_data()?;
+        arbitrator.try_serialize(&mut &mut data[..])
+    }
+
+    fn into_transfer_context(
+        &self,
+        to: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to,
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    fn into_close_context(&self) -> CpiContext<'_, '_, '_, 'info, CloseAccount<'info>> {
+        let cpi_accounts = CloseAccount {
+            account: self.vault.to_account_info(),
+            destination: self.initializer.to_account_info(),
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::JuryVoteRevealed;
+use crate::states::{Jury, JuryVote};
+
+#[derive(Accounts)]
+pub struct RevealJuryVote<'info> {
+    pub juror: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"jury", jury.dispute.as_ref()],
+        bump = jury.bump,
+    )]
+    pub jury: Box<Account<'info, Jury>>,
+    #[account(
+        mut,
+        has_one = jury,
+        has_one = juror,
+        constraint = !vote.revealed @ EscrowError::VoteAlreadyRevealed,
+        seeds = [b"jury_vote", jury.key().as_ref(), juror.key().as_ref()],
+        bump = vote.bump,
+    )]
+    pub vote: Box<Account<'info, JuryVote>>,
+}
+
+impl<'info> RevealJuryVote<'info> {
+    pub fn reveal_jury_vote(&mut self, release_to_taker: bool, salt: [u8; 32]) -> Result<()> {
+        require!(
+            self.jury.is_revealing(clock::now()?),
+            EscrowError::JuryPhaseClosed
+        );
+        let commitment =
+            JuryVote::commitment(&self.jury.key(), &self.juror.key(), release_to_taker, &salt);
+        require!(
+            commitment == self.vote.commitment,
+            EscrowError::VoteCommitmentMismatch
+        );
+
+        self.vote.revealed = true;
+        self.vote.release_to_taker = release_to_taker;
+        if release_to_taker {
+            self.jury.votes_for_taker += 1;
+        } else {
+            self.jury.votes_for_initializer += 1;
+        }
+
+        emit!(JuryVoteRevealed {
+            jury: self.jury.key(),
+            juror: self.juror.key(),
+            release_to_taker,
+        });
+        Ok(())
+    }
+}
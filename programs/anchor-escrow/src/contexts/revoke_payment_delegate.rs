@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::events::PaymentDelegateRevoked;
+use crate::states::PaymentDelegate;
+
+#[derive(Accounts)]
+pub struct RevokePaymentDelegate<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    #[account(
+        mut,
+        has_one = owner,
+        close = owner,
+        seeds = [b"payment_delegate", owner.key().as_ref()],
+        bump = payment_delegate.bump,
+    )]
+    pub payment_delegate: Box<Account<'info, PaymentDelegate>>,
+}
+
+impl<'info> RevokePaymentDelegate<'info> {
+    pub fn revoke_payment_delegate(&mut self) -> Result<()> {
+        emit!(PaymentDelegateRevoked {
+            owner: self.owner.key(),
+            delegate: self.payment_delegate.delegate,
+        });
+        Ok(())
+    }
+}
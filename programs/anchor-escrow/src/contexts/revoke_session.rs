@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::events::SessionRevoked;
+use crate::states::MakerSession;
+
+#[derive(Accounts)]
+pub struct RevokeSession<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    #[account(
+        mut,
+        has_one = maker,
+        close = maker,
+        seeds = [b"session", maker.key().as_ref(), session.session_key.as_ref()],
+        bump = session.bump,
+    )]
+    pub session: Box<Account<'info, MakerSession>>,
+}
+
+impl<'info> RevokeSession<'info> {
+    pub fn revoke_session(&mut self) -> Result<()> {
+        emit!(SessionRevoked {
+            maker: self.maker.key(),
+            session_key: self.session.session_key,
+        });
+        Ok(())
+    }
+}
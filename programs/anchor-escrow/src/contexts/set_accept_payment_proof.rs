@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::PaymentProofAcceptanceSet;
+use crate::states::Escrow;
+
+// Part of the offer's terms, so it is fixed once a taker is in
+#[derive(Accounts)]
+pub struct SetAcceptPaymentProof<'info> {
+    pub initializer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+}
+
+impl<'info> SetAcceptPaymentProof<'info> {
+    pub fn set_accept_payment_proof(&mut self, accept: bool) -> Result<()> {
+        let mut escrow = self.escrow.load_mut()?;
+        if accept {
+            escrow.flags |= Escrow::FLAG_ACCEPTS_PAYMENT_PROOF;
+        } else {
+            escrow.flags &= !Escrow::FLAG_ACCEPTS_PAYMENT_PROOF;
+        }
+
+        emit!(PaymentProofAcceptanceSet {
+            escrow: self.escrow.key(),
+            accept,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::states::Escrow;
+
+#[derive(Accounts)]
+pub struct SetArbiter<'info> {
+    pub initializer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+}
+
+impl<'info> SetArbiter<'info> {
+    // Pubkey::default() removes the arbiter
+    pub fn set_arbiter(&mut self, arbiter: Pubkey) -> Result<()> {
+        let mut escrow = self.escrow.load_mut()?;
+        require_keys_neq!(arbiter, escrow.initializer, EscrowError::InvalidArbiter);
+        escrow.arbiter = arbiter;
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::constants::BPS_DENOMINATOR;
+use crate::errors::EscrowError;
+use crate::states::{AdminAction, ArbitrationPolicy, AuditLog, Config};
+
+#[derive(Accounts)]
+pub struct SetArbitrationPolicy<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ArbitrationPolicy::SPACE,
+        seeds = [b"arbitration"],
+        bump,
+    )]
+    pub arbitration: Box<Account<'info, ArbitrationPolicy>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetArbitrationPolicy<'info> {
+    // The stake mint is fixed once set, since stakes already sit in vaults of it
+    pub fn set_arbitration_policy(
+        &mut self,
+        bumps: &SetArbitrationPolicyBumps,
+        stake_mint: Pubkey,
+        min_stake: u64,
+        reward_per_case: u64,
+        slash_bps: u16,
+        appeal_window: i64,
+    ) -> Result<()> {
+        require!(
+            self.arbitration.version == 0 || self.arbitration.stake_mint == stake_mint,
+            EscrowError::InvalidStakeMint
+        );
+        require!(slash_bps <= BPS_DENOMINATOR, EscrowError::InvalidFeeBps);
+        require!(appeal_window >= 0, EscrowError::InvalidAppealWindow);
+        self.arbitration.set_inner(ArbitrationPolicy {
+            version: ArbitrationPolicy::VERSION,
+            bump: bumps.arbitration,
+            stake_mint,
+            min_stake,
+            reward_per_case,
+            slash_bps,
+            appeal_window,
+            _reserved: [0; 32],
+        });
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::SetArbitrationPolicy,
+            self.admin.key(),
+            self.arbitration.key(),
+            &(
+                stake_mint,
+                min_stake,
+                reward_per_case,
+                slash_bps,
+                appeal_window,
+            ),
+        )
+    }
+}
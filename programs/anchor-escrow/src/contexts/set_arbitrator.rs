@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::constants::{MAX_ARBITRATOR_LANGUAGES, MAX_ARBITRATOR_REGIONS};
+use crate::errors::EscrowError;
+use crate::states::{AdminAction, Arbitrator, AuditLog, Config};
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct SetArbitrator<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = Arbitrator::SPACE,
+        seeds = [b"arbitrator", wallet.as_ref()],
+        bump,
+    )]
+    pub arbitrator: Box<Account<'info, Arbitrator>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetArbitrator<'info> {
+    // Deactivating only stops new assignments; escrows already naming the
+    // arbitrator keep them
+    pub fn set_arbitrator(
+        &mut self,
+        bumps: &SetArbitratorBumps,
+        wallet: Pubkey,
+        is_active: bool,
+        regions: Vec<[u8; 2]>,
+        languages: Vec<[u8; 2]>,
+    ) -> Result<()> {
+        require!(
+            regions.len() <= MAX_ARBITRATOR_REGIONS,
+            EscrowError::TooManyRegions
+        );
+        require!(
+            languages.len() <= MAX_ARBITRATOR_LANGUAGES,
+            EscrowError::TooManyLanguages
+        );
+        self.arbitrator.version = Arbitrator::VERSION;
+        self.arbitrator.bump = bumps.arbitrator;
+        self.arbitrator.wallet = wallet;
+        self.arbitrator.is_active = is_active;
+        self.arbitrator.set_tags(&regions, &languages);
+
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::SetArbitrator,
+            self.admin.key(),
+            wallet,
+            &(is_active, regions, languages),
+        )
+    }
+}
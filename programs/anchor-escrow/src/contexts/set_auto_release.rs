@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::AutoReleaseSet;
+use crate::states::Escrow;
+
+// Part of the offer's terms, so it is fixed once a taker is in
+#[derive(Accounts)]
+pub struct SetAutoRelease<'info> {
+    pub initializer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+}
+
+impl<'info> SetAutoRelease<'info> {
+    // Zero falls back to the config default
+    pub fn set_auto_release(&mut self, auto_release_after: i64) -> Result<()> {
+        require!(auto_release_after >= 0, EscrowError::InvalidAutoRelease);
+
+        self.escrow.load_mut()?.auto_release_after = auto_release_after;
+
+        emit!(AutoReleaseSet {
+            escrow: self.escrow.key(),
+            auto_release_after,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::errors::EscrowError;
+use crate::states::{AdminAction, AuditLog, CharityList, Config};
+
+#[derive(Accounts)]
+pub struct SetCharity<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = CharityList::SPACE,
+        seeds = [b"charities"],
+        bump,
+    )]
+    pub charities: Box<Account<'info, CharityList>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetCharity<'info> {
+    // Delisting only stops new pledges; existing ones are still paid out
+    pub fn set_charity(
+        &mut self,
+        bumps: &SetCharityBumps,
+        wallet: Pubkey,
+        listed: bool,
+    ) -> Result<()> {
+        self.charities.init_if_new(bumps.charities);
+        if listed {
+            self.charities.add(wallet)?;
+        } else {
+            self.charities.remove(&wallet);
+        }
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::SetCharity,
+            self.admin.key(),
+            wallet,
+            &(wallet, listed),
+        )
+    }
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::errors::EscrowError;
+use crate::states::{AdminAction, AuditLog, ComplianceDesk, Config};
+
+#[derive(Accounts)]
+pub struct SetComplianceDesk<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ComplianceDesk::SPACE,
+        seeds = [b"compliance"],
+        bump,
+    )]
+    pub compliance: Box<Account<'info, ComplianceDesk>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetComplianceDesk<'info> {
+    // Takes effect for approvals given from now on; existing ones stand
+    pub fn set_compliance_desk(
+        &mut self,
+        bumps: &SetComplianceDeskBumps,
+        signer: Pubkey,
+    ) -> Result<()> {
+        self.compliance.set_inner(ComplianceDesk {
+            version: ComplianceDesk::VERSION,
+            bump: bumps.compliance,
+            signer,
+            _reserved: [0; 32],
+        });
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::SetComplianceDesk,
+            self.admin.key(),
+            self.compliance.key(),
+            &signer,
+        )
+    }
+}
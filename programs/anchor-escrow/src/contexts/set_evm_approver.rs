@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::events::EvmApproverSet;
+use crate::states::Escrow;
+
+// The approver can only release to the escrow's taker, so the maker may name
+// or replace one at any point before settlement
+#[derive(Accounts)]
+pub struct SetEvmApprover<'info> {
+    pub initializer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+}
+
+impl<'info> SetEvmApprover<'info> {
+    pub fn set_evm_approver(&mut self, evm_approver: [u8; 20]) -> Result<()> {
+        self.escrow.load_mut()?.evm_approver = evm_approver;
+
+        emit!(EvmApproverSet {
+            escrow: self.escrow.key(),
+            evm_approver,
+        });
+        Ok(())
+    }
+}
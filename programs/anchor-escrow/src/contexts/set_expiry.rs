@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::ExpirySet;
+use crate::states::{Escrow, MakerSession};
+
+// Only while the offer is open; after that it takes `extend_deadline`
+#[derive(Accounts)]
+pub struct SetExpiry<'info> {
+    // The maker, or a session key acting for them
+    pub authority: Signer<'info>,
+    pub initializer: SystemAccount<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    // The signer's session, required unless the initializer signs
+    #[account(
+        seeds = [b"session", initializer.key().as_ref(), authority.key().as_ref()],
+        bump = session.bump,
+    )]
+    pub session: Option<Box<Account<'info, MakerSession>>>,
+}
+
+impl<'info> SetExpiry<'info> {
+    // Zero removes the deadline
+    pub fn set_expiry(&mut self, expires_at: i64) -> Result<()> {
+        MakerSession::authorize(
+            &self.initializer.key(),
+            &self.authority.key(),
+            self.session.as_deref(),
+            MakerSession::SCOPE_MANAGE_OFFERS,
+        )?;
+        require!(
+            expires_at == 0 || expires_at > clock::now()?,
+            EscrowError::InvalidExpiry
+        );
+
+        let mut escrow = self.escrow.load_mut()?;
+        escrow.expires_at = expires_at;
+        escrow.next_action_at = expires_at;
+
+        emit!(ExpirySet {
+            escrow: self.escrow.key(),
+            expires_at,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::audit::record_admin_action;
+use crate::errors::EscrowError;
+use crate::states::{AdminAction, AuditLog, Config, FeeConverter};
+
+#[derive(Accounts)]
+pub struct SetFeeConverter<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    pub settlement_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = FeeConverter::SPACE,
+        seeds = [b"fee_converter"],
+        bump,
+    )]
+    pub fee_converter: Box<Account<'info, FeeConverter>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetFeeConverter<'info> {
+    pub fn set_fee_converter(
+        &mut self,
+        bumps: &SetFeeConverterBumps,
+        keeper: Pubkey,
+    ) -> Result<()> {
+        let settlement_mint = self.settlement_mint.key();
+        self.fee_converter.set_inner(FeeConverter {
+            version: FeeConverter::VERSION,
+            bump: bumps.fee_converter,
+            settlement_mint,
+            keeper,
+            _reserved: [0; 32],
+        });
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::SetFeeConverter,
+            self.admin.key(),
+            self.fee_converter.key(),
+            &(settlement_mint, keeper),
+        )
+    }
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_FIAT_QUOTES;
+use crate::errors::EscrowError;
+use crate::events::FiatQuotesSet;
+use crate::states::{Escrow, FiatQuote, FiatQuotes};
+
+#[derive(Accounts)]
+pub struct SetFiatQuotes<'info> {
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        constraint = !escrow.load()?.has_hidden_price() @ EscrowError::PriceHidden,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = FiatQuotes::SPACE,
+        seeds = [b"fiat_quotes", escrow.key().as_ref()],
+        bump,
+    )]
+    pub fiat_quotes: Box<Account<'info, FiatQuotes>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetFiatQuotes<'info> {
+    // An empty list goes back to the offer's single `taker_amount`
+    pub fn set_fiat_quotes(
+        &mut self,
+        bumps: &SetFiatQuotesBumps,
+        quotes: Vec<FiatQuote>,
+    ) -> Result<()> {
+        require!(
+            quotes.len() <= MAX_FIAT_QUOTES,
+            EscrowError::TooManyFiatQuotes
+        );
+        for (i, quote) in quotes.iter().enumerate() {
+            require!(
+                quote.currency != 0
+                    && quote.taker_amount > 0
+                    && quotes[..i].iter().all(|q| q.currency != quote.currency),
+                EscrowError::InvalidFiatQuote
+            );
+        }
+        self.fiat_quotes.version = FiatQuotes::VERSION;
+        self.fiat_quotes.bump = bumps.fiat_quotes;
+        self.fiat_quotes.escrow = self.escrow.key();
+        self.fiat_quotes.set(&quotes);
+
+        let mut escrow = self.escrow.load_mut()?;
+        if quotes.is_empty() {
+            escrow.flags &= !Escrow::FLAG_FIAT_QUOTES;
+        } else {
+            escrow.flags |= Escrow::FLAG_FIAT_QUOTES;
+        }
+
+        emit!(FiatQuotesSet {
+            escrow: self.escrow.key(),
+            quotes,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::constants::MAX_ATTESTATION_SCHEMAS;
+use crate::errors::EscrowError;
+use crate::states::{AdminAction, AttestationRequirements, AuditLog, Config};
+
+#[derive(Accounts)]
+pub struct SetGlobalAttestations<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AttestationRequirements::SPACE,
+        seeds = [b"attestations"],
+        bump,
+    )]
+    pub requirements: Box<Account<'info, AttestationRequirements>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetGlobalAttestations<'info> {
+    // An empty schema list switches global attestation checks off
+    pub fn set_global_attestations(
+        &mut self,
+        bumps: &SetGlobalAttestationsBumps,
+        credential: Pubkey,
+        schemas: Vec<Pubkey>,
+    ) -> Result<()> {
+        require!(
+            schemas.len() <= MAX_ATTESTATION_SCHEMAS,
+            EscrowError::TooManySchemas
+        );
+        self.requirements.version = AttestationRequirements::VERSION;
+        self.requirements.bump = bumps.requirements;
+        self.requirements.set(credential, &schemas);
+        self.config.attestations_required = !schemas.is_empty();
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::SetGlobalAttestations,
+            self.admin.key(),
+            self.requirements.key(),
+            &(credential, schemas),
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::errors::EscrowError;
+use crate::states::{AdminAction, AuditLog, Config, GovernanceAuthority};
+
+#[derive(Accounts)]
+pub struct SetGovernance<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = GovernanceAuthority::SPACE,
+        seeds = [b"governance"],
+        bump,
+    )]
+    pub governance: Box<Account<'info, GovernanceAuthority>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetGovernance<'info> {
+    // Open escalations go to whichever authority is set when they are ruled on
+    pub fn set_governance(&mut self, bumps: &SetGovernanceBumps, authority: Pubkey) -> Result<()> {
+        self.governance.set_inner(GovernanceAuthority {
+            version: GovernanceAuthority::VERSION,
+            bump: bumps.governance,
+            authority,
+            _reserved: [0; 32],
+        });
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::SetGovernance,
+            self.admin.key(),
+            self.governance.key(),
+            &authority,
+        )
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::NoteSet;
+use crate::states::Escrow;
+
+// The taker accepts the offer as they see it, so the note is fixed once they
+// are in
+#[derive(Accounts)]
+pub struct SetNote<'info> {
+    pub initializer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+}
+
+impl<'info> SetNote<'info> {
+    pub fn set_note(&mut self, note: [u8; 64]) -> Result<()> {
+        self.escrow.load_mut()?.note = note;
+
+        emit!(NoteSet {
+            escrow: self.escrow.key(),
+            note,
+        });
+        Ok(())
+    }
+}
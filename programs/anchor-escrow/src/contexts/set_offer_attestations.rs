@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_ATTESTATION_SCHEMAS;
+use crate::errors::EscrowError;
+use crate::states::{AttestationRequirements, Escrow};
+
+#[derive(Accounts)]
+pub struct SetOfferAttestations<'info> {
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = AttestationRequirements::SPACE,
+        seeds = [b"attestations", escrow.key().as_ref()],
+        bump,
+    )]
+    pub requirements: Box<Account<'info, AttestationRequirements>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetOfferAttestations<'info> {
+    // An empty schema list lifts the offer's own requirements
+    pub fn set_offer_attestations(
+        &mut self,
+        bumps: &SetOfferAttestationsBumps,
+        credential: Pubkey,
+        schemas: Vec<Pubkey>,
+    ) -> Result<()> {
+        require!(
+            schemas.len() <= MAX_ATTESTATION_SCHEMAS,
+            EscrowError::TooManySchemas
+        );
+        self.requirements.version = AttestationRequirements::VERSION;
+        self.requirements.bump = bumps.requirements;
+        self.requirements.escrow = self.escrow.key();
+        self.requirements.set(credential, &schemas);
+
+        let mut escrow = self.escrow.load_mut()?;
+        if schemas.is_empty() {
+            escrow.flags &= !Escrow::FLAG_OFFER_ATTESTATIONS;
+        } else {
+            escrow.flags |= Escrow::FLAG_OFFER_ATTESTATIONS;
+        }
+        Ok(())
+    }
+}
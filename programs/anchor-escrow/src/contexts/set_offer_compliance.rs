@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::OfferComplianceSet;
+use crate::states::Escrow;
+
+// Part of the offer's terms, so it is fixed once a taker is in
+#[derive(Accounts)]
+pub struct SetOfferCompliance<'info> {
+    pub initializer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+}
+
+impl<'info> SetOfferCompliance<'info> {
+    // Pubkey::default() leaves the release to the platform desk alone
+    pub fn set_offer_compliance(&mut self, signer: Pubkey) -> Result<()> {
+        self.escrow.load_mut()?.compliance_signer = signer;
+
+        emit!(OfferComplianceSet {
+            escrow: self.escrow.key(),
+            signer,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_REGION_CODES;
+use crate::errors::EscrowError;
+use crate::states::{Escrow, RegionPolicy};
+
+#[derive(Accounts)]
+pub struct SetOfferRegions<'info> {
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = RegionPolicy::SPACE,
+        seeds = [b"regions", escrow.key().as_ref()],
+        bump,
+    )]
+    pub policy: Box<Account<'info, RegionPolicy>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetOfferRegions<'info> {
+    // An empty block list lifts the restriction
+    pub fn set_offer_regions(
+        &mut self,
+        bumps: &SetOfferRegionsBumps,
+        credential: Pubkey,
+        schema: Pubkey,
+        allow_list: bool,
+        codes: Vec<[u8; 2]>,
+    ) -> Result<()> {
+        require!(codes.len() <= MAX_REGION_CODES, EscrowError::TooManyRegions);
+        self.policy.version = RegionPolicy::VERSION;
+        self.policy.bump = bumps.policy;
+        self.policy.escrow = self.escrow.key();
+        self.policy.credential = credential;
+        self.policy.schema = schema;
+        self.policy.set(allow_list, &codes);
+
+        let mut escrow = self.escrow.load_mut()?;
+        if !allow_list && codes.is_empty() {
+            escrow.flags &= !Escrow::FLAG_REGION_POLICY;
+        } else {
+            escrow.flags |= Escrow::FLAG_REGION_POLICY;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::PartialPaymentsSet;
+use crate::states::{Config, Escrow, PartialPayments};
+
+// Part of the offer's terms, so it is fixed once a taker is in
+#[derive(Accounts)]
+pub struct SetPartialPayments<'info> {
+    pub initializer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.has_feature(Config::FEATURE_PARTIAL_PAYMENTS) @ EscrowError::FeatureDisabled,
+    )]
+    pub config: Box<Account<'info, Config>>,
+}
+
+impl<'info> SetPartialPayments<'info> {
+    pub fn set_partial_payments(&mut self, policy: PartialPayments) -> Result<()> {
+        let mut escrow = self.escrow.load_mut()?;
+        // Tranche releases pay out of the vault, which delegated escrows don't have
+        require!(
+            policy != PartialPayments::PerTranche || !escrow.is_delegated(),
+            EscrowError::PartialPaymentsDisabled
+        );
+        escrow.partial_payments = policy as u8;
+
+        emit!(PartialPaymentsSet {
+            escrow: self.escrow.key(),
+            policy: policy as u8,
+        });
+        Ok(())
+    }
+}
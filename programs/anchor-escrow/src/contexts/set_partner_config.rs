@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::constants::{BPS_DENOMINATOR, MAX_PARTNER_FEE_BPS};
+use crate::errors::EscrowError;
+use crate::states::{AdminAction, AuditLog, Config, PartnerConfig};
+
+#[derive(Accounts)]
+#[instruction(partner_id: u16)]
+pub struct SetPartnerConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PartnerConfig::SPACE,
+        seeds = [b"partner", partner_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub partner_config: Box<Account<'info, PartnerConfig>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetPartnerConfig<'info> {
+    // Registers the partner, or updates its terms; a new fee applies to
+    // every open escrow created through it from the next settlement on
+    pub fn set_partner_config(
+        &mut self,
+        bumps: &SetPartnerConfigBumps,
+        partner_id: u16,
+        fee_bps: u16,
+        fee_wallet: Pubkey,
+        branding_id: [u8; 32],
+        revenue_share_bps: u16,
+    ) -> Result<()> {
+        require!(
+            partner_id != 0
+                && fee_bps <= MAX_PARTNER_FEE_BPS
+                && revenue_share_bps <= BPS_DENOMINATOR,
+            EscrowError::InvalidPartnerConfig
+        );
+        self.partner_config.set_inner(PartnerConfig {
+            version: PartnerConfig::VERSION,
+            bump: bumps.partner_config,
+            partner_id,
+            fee_bps,
+            fee_wallet,
+            branding_id,
+            revenue_share_bps,
+            _reserved: [0; 30],
+        });
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::SetPartnerConfig,
+            self.admin.key(),
+            self.partner_config.key(),
+            &(
+                partner_id,
+                fee_bps,
+                fee_wallet,
+                branding_id,
+                revenue_share_bps,
+            ),
+        )
+    }
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::events::PaymentDelegateSet;
+use crate::states::PaymentDelegate;
+
+#[derive(Accounts)]
+pub struct SetPaymentDelegate<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PaymentDelegate::SPACE,
+        seeds = [b"payment_delegate", owner.key().as_ref()],
+        bump,
+    )]
+    pub payment_delegate: Box<Account<'info, PaymentDelegate>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetPaymentDelegate<'info> {
+    // Replaces any previous delegate
+    pub fn set_payment_delegate(
+        &mut self,
+        bumps: &SetPaymentDelegateBumps,
+        delegate: Pubkey,
+    ) -> Result<()> {
+        self.payment_delegate.set_inner(PaymentDelegate {
+            version: PaymentDelegate::VERSION,
+            bump: bumps.payment_delegate,
+            owner: self.owner.key(),
+            delegate,
+            _reserved: [0; 32],
+        });
+
+        emit!(PaymentDelegateSet {
+            owner: self.owner.key(),
+            delegate,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::constants::MAX_PAYMENT_URI_LEN;
+use crate::errors::EscrowError;
+use crate::events::PaymentDetailsSet;
+use crate::states::{Escrow, PaymentDetails};
+
+// Only once a taker is in, since the details are encrypted to them
+#[derive(Accounts)]
+pub struct SetPaymentDetails<'info> {
+    #[account(mut)]
+    pub initializer: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        has_one = initializer,
+        constraint = escrow.load()?.has_taker() @ EscrowError::InvalidTaker,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PaymentDetails::SPACE,
+        seeds = [b"payment_details", escrow.key().as_ref()],
+        bump,
+    )]
+    pub details: Box<Account<'info, PaymentDetails>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetPaymentDetails<'info> {
+    // Overwrites earlier details, e.g. after a reassignment to another taker
+    pub fn set_payment_details(
+        &mut self,
+        bumps: &SetPaymentDetailsBumps,
+        uri: String,
+        details_hash: [u8; 32],
+    ) -> Result<()> {
+        require!(
+            !uri.is_empty() && uri.len() <= MAX_PAYMENT_URI_LEN,
+            EscrowError::InvalidPaymentDetails
+        );
+        self.details.version = PaymentDetails::VERSION;
+        self.details.bump = bumps.details;
+        self.details.escrow = self.escrow.key();
+        self.details.set_uri(uri.as_bytes());
+        self.details.details_hash = details_hash;
+        self.details.updated_at = clock::now()?;
+
+        emit!(PaymentDetailsSet {
+            escrow: self.escrow.key(),
+            taker: self.escrow.load()?.taker,
+            uri,
+            details_hash,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::PaymentMemoRequirementSet;
+use crate::states::Escrow;
+
+// Part of the offer's terms, so it is fixed once a taker is in
+#[derive(Accounts)]
+pub struct SetPaymentMemoRequired<'info> {
+    pub initializer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+}
+
+impl<'info> SetPaymentMemoRequired<'info> {
+    pub fn set_payment_memo_required(&mut self, required: bool) -> Result<()> {
+        self.escrow.load_mut()?.payment_memo_required = required as u8;
+
+        emit!(PaymentMemoRequirementSet {
+            escrow: self.escrow.key(),
+            required,
+        });
+        Ok(())
+    }
+}
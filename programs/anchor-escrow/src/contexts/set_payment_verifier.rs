@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::errors::EscrowError;
+use crate::states::{AdminAction, AuditLog, Config, PaymentVerifier};
+
+#[derive(Accounts)]
+pub struct SetPaymentVerifier<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PaymentVerifier::SPACE,
+        seeds = [b"payment_verifier"],
+        bump,
+    )]
+    pub payment_verifier: Box<Account<'info, PaymentVerifier>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetPaymentVerifier<'info> {
+    // Pubkey::default() switches proof-based confirmation off
+    pub fn set_payment_verifier(
+        &mut self,
+        bumps: &SetPaymentVerifierBumps,
+        program: Pubkey,
+    ) -> Result<()> {
+        self.payment_verifier.set_inner(PaymentVerifier {
+            version: PaymentVerifier::VERSION,
+            bump: bumps.payment_verifier,
+            program,
+            _reserved: [0; 32],
+        });
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::SetPaymentVerifier,
+            self.admin.key(),
+            self.payment_verifier.key(),
+            &program,
+        )
+    }
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::audit::record_admin_action;
+use crate::errors::EscrowError;
+use crate::states::{AdminAction, AuditLog, Config, RateFeed};
+
+#[derive(Accounts)]
+pub struct SetRateFeed<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    pub mint_b: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = RateFeed::SPACE,
+        seeds = [b"rate_feed", mint_a.key().as_ref(), mint_b.key().as_ref()],
+        bump,
+    )]
+    pub rate_feed: Box<Account<'info, RateFeed>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetRateFeed<'info> {
+    // Names the oracle key that posts the market's rate. A new authority
+    // starts from a blank rate, so nothing it didn't post is ever trusted.
+    pub fn set_rate_feed(&mut self, bumps: &SetRateFeedBumps, authority: Pubkey) -> Result<()> {
+        self.rate_feed.set_inner(RateFeed {
+            version: RateFeed::VERSION,
+            bump: bumps.rate_feed,
+            mint_a: self.mint_a.key(),
+            mint_b: self.mint_b.key(),
+            authority,
+            rate: 0,
+            updated_at: 0,
+            _reserved: [0; 32],
+        });
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::SetRateFeed,
+            self.admin.key(),
+            self.rate_feed.key(),
+            &authority,
+        )
+    }
+}
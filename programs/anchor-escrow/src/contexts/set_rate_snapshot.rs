@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::RateSnapshotSet;
+use crate::pricing::current_rate;
+use crate::states::{Escrow, RateFeed};
+
+// Part of the offer's terms, so it is fixed once a taker is in
+#[derive(Accounts)]
+pub struct SetRateSnapshot<'info> {
+    pub initializer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    // The market's feed, required unless the maker states the rate
+    #[account(
+        seeds = [b"rate_feed", escrow.load()?.mint_a.as_ref(), escrow.load()?.mint_b.as_ref()],
+        bump = rate_feed.bump,
+    )]
+    pub rate_feed: Option<Box<Account<'info, RateFeed>>>,
+}
+
+impl<'info> SetRateSnapshot<'info> {
+    // None snapshots the feed's current rate; Some(0) removes the guard
+    pub fn set_rate_snapshot(&mut self, rate: Option<u64>) -> Result<()> {
+        let (rate, from_feed) = match rate {
+            Some(rate) => (rate, false),
+            None => {
+                let feed = self
+                    .rate_feed
+                    .as_deref()
+                    .ok_or(EscrowError::RateFeedMissing)?;
+                (current_rate(feed, clock::now()?)?, true)
+            }
+        };
+        self.escrow.load_mut()?.rate_snapshot = rate;
+
+        emit!(RateSnapshotSet {
+            escrow: self.escrow.key(),
+            rate,
+            from_feed,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_RELEASE_DELAY;
+use crate::errors::EscrowError;
+use crate::events::ReleaseDelaySet;
+use crate::states::Escrow;
+
+// Part of the offer's terms, so it is fixed once a taker is in
+#[derive(Accounts)]
+pub struct SetReleaseDelay<'info> {
+    pub initializer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+}
+
+impl<'info> SetReleaseDelay<'info> {
+    // Capped so a maker can't hold a paid taker's tokens indefinitely
+    pub fn set_release_delay(&mut self, release_delay: i64) -> Result<()> {
+        require!(
+            (0..=MAX_RELEASE_DELAY).contains(&release_delay),
+            EscrowError::InvalidReleaseDelay
+        );
+
+        self.escrow.load_mut()?.release_delay = release_delay;
+
+        emit!(ReleaseDelaySet {
+            escrow: self.escrow.key(),
+            release_delay,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+use crate::events::ReleaseScheduleSet;
+use crate::states::{Escrow, ReleaseMode};
+
+#[derive(Accounts)]
+pub struct SetReleaseSchedule<'info> {
+    pub initializer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        constraint = !escrow.load()?.is_milestones() @ EscrowError::ReleaseModeMismatch,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+}
+
+impl<'info> SetReleaseSchedule<'info> {
+    // A zero duration switches the escrow back to immediate release
+    pub fn set_release_schedule(&mut self, start: i64, cliff: i64, duration: i64) -> Result<()> {
+        require!(
+            start >= 0 && cliff >= 0 && duration >= 0 && cliff <= duration,
+            EscrowError::InvalidReleaseSchedule
+        );
+        require!(
+            duration > 0 || (start == 0 && cliff == 0),
+            EscrowError::InvalidReleaseSchedule
+        );
+
+        let mut escrow = self.escrow.load_mut()?;
+        escrow.release_mode = if duration > 0 {
+            ReleaseMode::Vesting as u8
+        } else {
+            ReleaseMode::Immediate as u8
+        };
+        escrow.release_start = start;
+        escrow.release_cliff = cliff;
+        escrow.release_duration = duration;
+
+        emit!(ReleaseScheduleSet {
+            escrow: self.escrow.key(),
+            start,
+            cliff,
+            duration,
+        });
+        Ok(())
+    }
+
+    // Streams the escrow to the taker over `duration` seconds from payment confirmation
+    pub fn set_stream(&mut self, duration: i64) -> Result<()> {
+        require!(duration > 0, EscrowError::InvalidReleaseSchedule);
+
+        let mut escrow = self.escrow.load_mut()?;
+        escrow.release_mode = ReleaseMode::Streaming as u8;
+        escrow.release_start = 0;
+        escrow.release_cliff = 0;
+        escrow.release_duration = duration;
+
+        emit!(ReleaseScheduleSet {
+            escrow: self.escrow.key(),
+            start: 0,
+            cliff: 0,
+            duration,
+        });
+        Ok(())
+    }
+}
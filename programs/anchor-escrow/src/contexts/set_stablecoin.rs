@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::errors::EscrowError;
+use crate::states::{AdminAction, AuditLog, Config, StablecoinList};
+
+#[derive(Accounts)]
+pub struct SetStablecoin<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = StablecoinList::SPACE,
+        seeds = [b"stablecoins"],
+        bump,
+    )]
+    pub stablecoins: Box<Account<'info, StablecoinList>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetStablecoin<'info> {
+    // Delisting only stops new offers; open ones in the mint are left alone
+    pub fn set_stablecoin(
+        &mut self,
+        bumps: &SetStablecoinBumps,
+        mint: Pubkey,
+        listed: bool,
+    ) -> Result<()> {
+        self.stablecoins.init_if_new(bumps.stablecoins);
+        if listed {
+            self.stablecoins.add(mint)?;
+        } else {
+            self.stablecoins.remove(&mint);
+        }
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::SetStablecoin,
+            self.admin.key(),
+            mint,
+            &(mint, listed),
+        )
+    }
+}
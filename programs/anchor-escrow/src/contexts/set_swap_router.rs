@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::errors::EscrowError;
+use crate::states::{AdminAction, AuditLog, Config, SwapRouter};
+
+#[derive(Accounts)]
+pub struct SetSwapRouter<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = SwapRouter::SPACE,
+        seeds = [b"swap_router"],
+        bump,
+    )]
+    pub swap_router: Box<Account<'info, SwapRouter>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetSwapRouter<'info> {
+    // Pubkey::default() switches swap-on-settlement off
+    pub fn set_swap_router(&mut self, bumps: &SetSwapRouterBumps, program: Pubkey) -> Result<()> {
+        self.swap_router.set_inner(SwapRouter {
+            version: SwapRouter::VERSION,
+            bump: bumps.swap_router,
+            program,
+            _reserved: [0; 32],
+        });
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::SetSwapRouter,
+            self.admin.key(),
+            self.swap_router.key(),
+            &program,
+        )
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::events::TemplateSet;
+use crate::states::{OfferTemplate, TemplateTerms};
+
+#[derive(Accounts)]
+#[instruction(template_id: u16)]
+pub struct SetTemplate<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+    // Pays rent for the accounts created here, so a relayer can sponsor the
+    // transaction; the same key as the signer above when self-funded
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub mint_a: InterfaceAccount<'info, Mint>,
+    pub mint_b: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = OfferTemplate::SPACE,
+        seeds = [b"template", maker.key().as_ref(), &template_id.to_le_bytes()],
+        bump,
+    )]
+    pub template: Box<Account<'info, OfferTemplate>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetTemplate<'info> {
+    // Overwrites an existing template with the same id
+    pub fn set_template(
+        &mut self,
+        bumps: &SetTemplateBumps,
+        template_id: u16,
+        terms: TemplateTerms,
+    ) -> Result<()> {
+        terms.validate()?;
+        self.template.set_inner(OfferTemplate {
+            version: OfferTemplate::VERSION,
+            bump: bumps.template,
+            template_id,
+            maker: self.maker.key(),
+            mint_a: self.mint_a.key(),
+            mint_b: self.mint_b.key(),
+            terms,
+            _reserved: [0; 32],
+        });
+
+        emit!(TemplateSet {
+            template: self.template.key(),
+            maker: self.maker.key(),
+            template_id,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::errors::EscrowError;
+use crate::states::{AdminAction, AuditLog, Config, TraderStats};
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct SetVolumeTier<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = TraderStats::SPACE,
+        seeds = [b"trader_stats", wallet.as_ref()],
+        bump,
+    )]
+    pub stats: Box<Account<'info, TraderStats>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetVolumeTier<'info> {
+    pub fn set_volume_tier(
+        &mut self,
+        bumps: &SetVolumeTierBumps,
+        wallet: Pubkey,
+        tier: u8,
+    ) -> Result<()> {
+        self.stats.init_if_new(wallet, bumps.stats);
+        self.stats.volume_tier = tier;
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::SetVolumeTier,
+            self.admin.key(),
+            wallet,
+            &tier,
+        )?;
+        Ok(())
+    }
+}
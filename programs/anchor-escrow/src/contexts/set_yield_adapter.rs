@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::errors::EscrowError;
+use crate::states::{AdminAction, AuditLog, Config, YieldAdapter};
+
+#[derive(Accounts)]
+pub struct SetYieldAdapter<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = YieldAdapter::SPACE,
+        seeds = [b"yield_adapter"],
+        bump,
+    )]
+    pub yield_adapter: Box<Account<'info, YieldAdapter>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SetYieldAdapter<'info> {
+    // Positions can only be withdrawn through the program holding them, so
+    // it can't be replaced while any are open; Config::FEATURE_YIELD is the
+    // way to stop new deposits in the meantime.
+    pub fn set_yield_adapter(
+        &mut self,
+        bumps: &SetYieldAdapterBumps,
+        program: Pubkey,
+    ) -> Result<()> {
+        let adapter = &mut self.yield_adapter;
+        require!(
+            adapter.open_positions == 0 || adapter.program == program,
+            EscrowError::YieldPositionOpen
+        );
+        adapter.version = YieldAdapter::VERSION;
+        adapter.bump = bumps.yield_adapter;
+        adapter.program = program;
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::SetYieldAdapter,
+            self.admin.key(),
+            self.yield_adapter.key(),
+            &program,
+        )
+    }
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BPS_DENOMINATOR;
+use crate::errors::EscrowError;
+use crate::events::YieldSplitSet;
+use crate::states::Escrow;
+
+// Part of the offer's terms, so it is fixed once a taker is in
+#[derive(Accounts)]
+pub struct SetYieldSplit<'info> {
+    pub initializer: Signer<'info>,
+    #[account(
+        mut,
+        has_one = initializer,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = !escrow.load()?.has_taker() @ EscrowError::TakerAlreadyAssigned,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+}
+
+impl<'info> SetYieldSplit<'info> {
+    pub fn set_yield_split(&mut self, taker_share_bps: u16) -> Result<()> {
+        require!(
+            taker_share_bps <= BPS_DENOMINATOR,
+            EscrowError::InvalidFeeBps
+        );
+
+        self.escrow.load_mut()?.yield_taker_share_bps = taker_share_bps;
+
+        emit!(YieldSplitSet {
+            escrow: self.escrow.key(),
+            taker_share_bps,
+        });
+        Ok(())
+    }
+}
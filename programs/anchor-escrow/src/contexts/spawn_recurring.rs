@@ -0,0 +1,190 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::clock;
+use crate::constants::PLATFORM_WALLET;
+use crate::errors::EscrowError;
+use crate::events::RecurringEscrowSpawned;
+use crate::fees::charge_creation_fee;
+use crate::gating::require_stablecoin_market;
+use crate::invariants::require_deposit_received;
+use crate::states::{
+    Config, Escrow, EscrowStatus, MakerRegistry, RecurringSchedule, StablecoinList, TraderStats,
+};
+
+// Permissionless crank: posts the schedule's next escrow once it is due. The
+// cranker fronts the rent and any creation fee; the rent goes back to the
+// maker when the escrow closes, so this is usually the maker's or the
+// taker's own bot.
+#[derive(Accounts)]
+pub struct SpawnRecurring<'info> {
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    pub maker: SystemAccount<'info>,
+    #[account(
+        mut,
+        has_one = maker,
+        has_one = mint_a,
+        constraint = !schedule.is_complete() @ EscrowError::RecurringScheduleComplete,
+        seeds = [b"recurring", maker.key().as_ref(), &schedule.schedule_id.to_le_bytes()],
+        bump = schedule.bump,
+    )]
+    pub schedule: Box<Account<'info, RecurringSchedule>>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = schedule,
+        associated_token::token_program = token_program
+    )]
+    pub schedule_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init,
+        payer = cranker,
+        space = Escrow::SPACE,
+        seeds = [
+            b"state".as_ref(),
+            &schedule.next_escrow_seed(&schedule.key()).to_le_bytes(),
+        ],
+        bump
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        associated_token::mint = mint_a,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.has_feature(Config::FEATURE_RECURRING) @ EscrowError::FeatureDisabled,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    // Required while the config is in stablecoin-only mode
+    #[account(seeds = [b"stablecoins"], bump = stablecoins.bump)]
+    pub stablecoins: Option<Box<Account<'info, StablecoinList>>>,
+    /// CHECK: Blocklist PDA of the maker; must not exist
+    #[account(
+        seeds = [b"blocked", maker.key().as_ref()],
+        bump,
+        constraint = maker_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub maker_block: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = TraderStats::SPACE,
+        seeds = [b"trader_stats", maker.key().as_ref()],
+        bump,
+    )]
+    pub maker_stats: Box<Account<'info, TraderStats>>,
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = MakerRegistry::SPACE,
+        seeds = [b"registry", maker.key().as_ref()],
+        bump,
+    )]
+    pub maker_registry: Box<Account<'info, MakerRegistry>>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(mut, address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SpawnRecurring<'info> {
+    pub fn spawn_recurring(&mut self, bumps: &SpawnRecurringBumps) -> Result<()> {
+        let now = clock::now()?;
+        let schedule = &self.schedule;
+        require!(now >= schedule.next_run_at, EscrowError::RecurringRunNotDue);
+        // The mode may have been switched on after the schedule was set up
+        require_stablecoin_market(
+            &self.config,
+            self.stablecoins.as_deref(),
+            &schedule.mint_a,
+            &schedule.mint_b,
+        )?;
+        let seed = schedule.next_escrow_seed(&schedule.key());
+
+        self.maker_stats
+            .init_if_new(self.maker.key(), bumps.maker_stats);
+        self.maker_stats.track_opened(1, &self.config)?;
+        self.maker_registry
+            .init_if_new(self.maker.key(), bumps.maker_registry);
+        self.maker_registry.add(self.escrow.key())?;
+        let fee_held = charge_creation_fee(
+            &self.config,
+            self.cranker.to_account_info(),
+            self.escrow.to_account_info(),
+            self.platform_wallet.to_account_info(),
+            self.system_program.to_account_info(),
+        )?;
+
+        let schedule = &self.schedule;
+        let signer_seeds: [&[&[u8]]; 1] = [&[
+            b"recurring",
+            schedule.maker.as_ref(),
+            &schedule.schedule_id.to_le_bytes(),
+            &[schedule.bump],
+        ]];
+        let before = self.vault.amount;
+        transfer_checked(
+            self.into_fund_context().with_signer(&signer_seeds),
+            schedule.initializer_amount,
+            self.mint_a.decimals,
+        )?;
+        let received =
+            require_deposit_received(&mut self.vault, before, schedule.initializer_amount)?;
+
+        let mut escrow = Escrow::new(
+            seed,
+            bumps.escrow,
+            schedule.maker,
+            schedule.mint_a,
+            schedule.mint_b,
+            schedule.initializer_amount,
+            schedule.taker_amount,
+        );
+        escrow.settle_deposit(received)?;
+        escrow.status = EscrowStatus::Funded as u8;
+        escrow.assign_taker(schedule.taker, now);
+        if fee_held {
+            escrow.flags |= Escrow::FLAG_CREATION_FEE_HELD;
+        }
+        *self.escrow.load_init()? = escrow;
+
+        let run = self.schedule.runs_done;
+        self.schedule.runs_done += 1;
+        // Keeps the cadence even if the crank ran late
+        self.schedule.next_run_at = self
+            .schedule
+            .next_run_at
+            .saturating_add(self.schedule.interval);
+
+        emit!(RecurringEscrowSpawned {
+            schedule: self.schedule.key(),
+            escrow: self.escrow.key(),
+            run,
+        });
+        Ok(())
+    }
+
+    fn into_fund_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.schedule_vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to: self.vault.to_account_info(),
+            authority: self.schedule.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+
+use crate::errors::EscrowError;
+use crate::events::ArbitratorStaked;
+use crate::states::{ArbitrationPolicy, Arbitrator};
+
+// Adding to the stake also calls off a pending unstake
+#[derive(Accounts)]
+pub struct StakeArbitrator<'info> {
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    #[account(
+        mut,
+        has_one = wallet,
+        seeds = [b"arbitrator", wallet.key().as_ref()],
+        bump = arbitrator.bump,
+    )]
+    pub arbitrator: Box<Account<'info, Arbitrator>>,
+    #[account(seeds = [b"arbitration"], bump = arbitration.bump)]
+    pub arbitration: Box<Account<'info, ArbitrationPolicy>>,
+    #[account(
+        address = arbitration.stake_mint @ EscrowError::InvalidStakeMint,
+        mint::token_program = token_program
+    )]
+    pub stake_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = wallet,
+        associated_token::token_program = token_program
+    )]
+    pub wallet_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        init_if_needed,
+        payer = wallet,
+        associated_token::mint = stake_mint,
+        associated_token::authority = arbitrator,
+        associated_token::token_program = token_program
+    )]
+    pub stake_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> StakeArbitrator<'info> {
+    pub fn stake_arbitrator(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, EscrowError::InvalidAmount);
+        transfer_checked(self.into_stake_context(), amount, self.stake_mint.decimals)?;
+
+        self.arbitrator.staked = self
+            .arbitrator
+            .staked
+            .checked_add(amount)
+            .ok_or(EscrowError::MathOverflow)?;
+        self.arbitrator.unlock_at = 0;
+
+        emit!(ArbitratorStaked {
+            arbitrator: self.wallet.key(),
+            amount,
+            staked: self.arbitrator.staked,
+        });
+        Ok(())
+    }
+
+    fn into_stake_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.wallet_ata.to_account_info(),
+            mint: self.stake_mint.to_account_info(),
+            to: self.stake_vault.to_account_info(),
+            authority: self.wallet.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
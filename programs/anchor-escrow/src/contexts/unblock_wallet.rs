@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::errors::EscrowError;
+use crate::events::WalletUnblocked;
+use crate::states::{AdminAction, AuditLog, BlockedWallet, Config};
+
+#[derive(Accounts)]
+pub struct UnblockWallet<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        mut,
+        close = admin,
+        seeds = [b"blocked", blocked.wallet.as_ref()],
+        bump = blocked.bump,
+    )]
+    pub blocked: Box<Account<'info, BlockedWallet>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UnblockWallet<'info> {
+    pub fn unblock_wallet(&mut self, bumps: &UnblockWalletBumps) -> Result<()> {
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::UnblockWallet,
+            self.admin.key(),
+            self.blocked.wallet,
+            &(),
+        )?;
+        emit!(WalletUnblocked {
+            wallet: self.blocked.wallet,
+            admin: self.admin.key(),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::errors::EscrowError;
+use crate::events::EscrowUnfrozen;
+use crate::states::{AdminAction, AuditLog, Config, Escrow};
+
+#[derive(Accounts)]
+pub struct UnfreezeEscrow<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        mut,
+        constraint = escrow.load()?.is_frozen() @ EscrowError::EscrowNotFrozen,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UnfreezeEscrow<'info> {
+    pub fn unfreeze_escrow(&mut self, bumps: &UnfreezeEscrowBumps) -> Result<()> {
+        self.escrow.load_mut()?.flags &= !Escrow::FLAG_FROZEN;
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::UnfreezeEscrow,
+            self.admin.key(),
+            self.escrow.key(),
+            &(),
+        )?;
+
+        emit!(EscrowUnfrozen {
+            escrow: self.escrow.key(),
+            admin: self.admin.key(),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,226 @@
+use anchor_lang::prelude::*;
+
+use crate::audit::record_admin_action;
+use crate::constants::{
+    BPS_DENOMINATOR, MAX_FEE_CATEGORIES, MAX_REAP_GRACE_PERIOD, PLATFORM_FEE_PERCENTAGE,
+};
+use crate::errors::EscrowError;
+use crate::states::{AdminAction, AuditLog, Config};
+
+// Fee for offers in `category`; None goes back to the standard fee
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct CategoryFee {
+    pub category: u8,
+    pub fee_percentage: Option<u8>,
+}
+
+// Fields left as None keep their current value
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct ConfigUpdate {
+    pub admin: Option<Pubkey>,
+    pub insurance_fee_bps: Option<u16>,
+    pub arbitration_fee_bps: Option<u16>,
+    pub maker_deposit_bps: Option<u16>,
+    pub max_cancel_strikes: Option<u8>,
+    pub gatekeeper_network: Option<Pubkey>,
+    pub daily_volume_limit: Option<u64>,
+    pub weekly_volume_limit: Option<u64>,
+    pub max_open_escrows: Option<u16>,
+    pub creation_fee_lamports: Option<u32>,
+    pub refund_creation_fee: Option<bool>,
+    pub auto_release_after_hours: Option<u16>,
+    pub promo_fee_percentage: Option<u8>,
+    pub promo_starts_at: Option<i64>,
+    pub promo_ends_at: Option<i64>,
+    pub maker_rebate_bps: Option<u16>,
+    pub rebate_mint: Option<Pubkey>,
+    pub risk_premium_max_bps: Option<u16>,
+    pub gc_bounty_bps: Option<u16>,
+    pub category_fee: Option<CategoryFee>,
+    // Replaces the whole Config::FEATURE_* set
+    pub features: Option<u64>,
+    pub max_rate_deviation_bps: Option<u16>,
+    pub stablecoin_only: Option<bool>,
+    pub yield_maker_share_bps: Option<u16>,
+    pub compliance_required: Option<bool>,
+    pub dispute_fee: Option<u64>,
+    pub reap_grace_period: Option<u32>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin @ EscrowError::Unauthorized,
+        seeds = [b"config"],
+        bump = config.bump,
+    )]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = AuditLog::SPACE,
+        seeds = [b"audit_log"],
+        bump,
+    )]
+    pub audit_log: Box<Account<'info, AuditLog>>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> UpdateConfig<'info> {
+    pub fn update_config(&mut self, bumps: &UpdateConfigBumps, update: ConfigUpdate) -> Result<()> {
+        record_admin_action(
+            &mut self.audit_log,
+            bumps.audit_log,
+            AdminAction::UpdateConfig,
+            self.admin.key(),
+            self.config.key(),
+            &update,
+        )?;
+        if let Some(insurance_fee_bps) = update.insurance_fee_bps {
+            require!(
+                insurance_fee_bps <= BPS_DENOMINATOR,
+                EscrowError::InvalidFeeBps
+            );
+            self.config.insurance_fee_bps = insurance_fee_bps;
+        }
+        if let Some(arbitration_fee_bps) = update.arbitration_fee_bps {
+            require!(
+                arbitration_fee_bps <= BPS_DENOMINATOR,
+                EscrowError::InvalidFeeBps
+            );
+            self.config.arbitration_fee_bps = arbitration_fee_bps;
+        }
+        if let Some(maker_deposit_bps) = update.maker_deposit_bps {
+            require!(
+                maker_deposit_bps <= BPS_DENOMINATOR,
+                EscrowError::InvalidFeeBps
+            );
+            self.config.maker_deposit_bps = maker_deposit_bps;
+        }
+        if let Some(max_cancel_strikes) = update.max_cancel_strikes {
+            self.config.max_cancel_strikes = max_cancel_strikes;
+        }
+        if let Some(gatekeeper_network) = update.gatekeeper_network {
+            self.config.gatekeeper_network = gatekeeper_network;
+        }
+        if let Some(daily_volume_limit) = update.daily_volume_limit {
+            self.config.daily_volume_limit = daily_volume_limit;
+        }
+        if let Some(weekly_volume_limit) = update.weekly_volume_limit {
+            self.config.weekly_volume_limit = weekly_volume_limit;
+        }
+        if let Some(max_open_escrows) = update.max_open_escrows {
+            self.config.max_open_escrows = max_open_escrows;
+        }
+        if let Some(creation_fee_lamports) = update.creation_fee_lamports {
+            self.config.creation_fee_lamports = creation_fee_lamports;
+        }
+        if let Some(refund_creation_fee) = update.refund_creation_fee {
+            self.config.refund_creation_fee = refund_creation_fee;
+        }
+        if let Some(auto_release_after_hours) = update.auto_release_after_hours {
+            self.config.auto_release_after_hours = auto_release_after_hours;
+        }
+        if let Some(promo_fee_percentage) = update.promo_fee_percentage {
+            // A promotion may only lower the fee
+            require!(
+                promo_fee_percentage <= PLATFORM_FEE_PERCENTAGE,
+                EscrowError::InvalidFeePromo
+            );
+            self.config.promo_fee_percentage = promo_fee_percentage;
+        }
+        if let Some(promo_starts_at) = update.promo_starts_at {
+            self.config.promo_starts_at = promo_starts_at;
+        }
+        if let Some(promo_ends_at) = update.promo_ends_at {
+            self.config.promo_ends_at = promo_ends_at;
+        }
+        require!(
+            self.config.promo_starts_at <= self.config.promo_ends_at,
+            EscrowError::InvalidFeePromo
+        );
+        if let Some(maker_rebate_bps) = update.maker_rebate_bps {
+            require!(
+                maker_rebate_bps <= BPS_DENOMINATOR,
+                EscrowError::InvalidFeeBps
+            );
+            self.config.maker_rebate_bps = maker_rebate_bps;
+        }
+        if let Some(rebate_mint) = update.rebate_mint {
+            self.config.rebate_mint = rebate_mint;
+        }
+        if let Some(risk_premium_max_bps) = update.risk_premium_max_bps {
+            require!(
+                risk_premium_max_bps <= BPS_DENOMINATOR,
+                EscrowError::InvalidFeeBps
+            );
+            self.config.risk_premium_max_bps = risk_premium_max_bps;
+        }
+        if let Some(gc_bounty_bps) = update.gc_bounty_bps {
+            require!(
+                gc_bounty_bps <= BPS_DENOMINATOR,
+                EscrowError::InvalidFeeBps
+            );
+            self.config.gc_bounty_bps = gc_bounty_bps;
+        }
+        if let Some(CategoryFee {
+            category,
+            fee_percentage,
+        }) = update.category_fee
+        {
+            let index = category as usize;
+            require!(index < MAX_FEE_CATEGORIES, EscrowError::InvalidCategoryFee);
+            match fee_percentage {
+                Some(fee_percentage) => {
+                    require!(fee_percentage <= 100, EscrowError::InvalidCategoryFee);
+                    self.config.category_fee_overrides |= 1 << index;
+                    self.config.category_fee_percentages[index] = fee_percentage;
+                }
+                None => {
+                    self.config.category_fee_overrides &= !(1 << index);
+                    self.config.category_fee_percentages[index] = 0;
+                }
+            }
+        }
+        if let Some(max_rate_deviation_bps) = update.max_rate_deviation_bps {
+            require!(
+                max_rate_deviation_bps <= BPS_DENOMINATOR,
+                EscrowError::InvalidFeeBps
+            );
+            self.config.max_rate_deviation_bps = max_rate_deviation_bps;
+        }
+        if let Some(yield_maker_share_bps) = update.yield_maker_share_bps {
+            require!(
+                yield_maker_share_bps <= BPS_DENOMINATOR,
+                EscrowError::InvalidFeeBps
+            );
+            self.config.yield_maker_share_bps = yield_maker_share_bps;
+        }
+        if let Some(stablecoin_only) = update.stablecoin_only {
+            self.config.stablecoin_only = stablecoin_only;
+        }
+        if let Some(compliance_required) = update.compliance_required {
+            self.config.compliance_required = compliance_required;
+        }
+        if let Some(dispute_fee) = update.dispute_fee {
+            self.config.dispute_fee = dispute_fee;
+        }
+        if let Some(reap_grace_period) = update.reap_grace_period {
+            require!(
+                reap_grace_period as i64 <= MAX_REAP_GRACE_PERIOD,
+                EscrowError::InvalidGracePeriod
+            );
+            self.config.reap_grace_period = reap_grace_period;
+        }
+        if let Some(features) = update.features {
+            self.config.features = features;
+        }
+        if let Some(admin) = update.admin {
+            self.config.admin = admin;
+        }
+        Ok(())
+    }
+}
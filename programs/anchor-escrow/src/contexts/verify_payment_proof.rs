@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::PaymentProven;
+use crate::payment_proof::{verify_payment_claim, PaymentClaim};
+use crate::states::{Config, Escrow, PaymentVerifier};
+
+// Confirms the taker's payment from a proof the configured verifier accepts,
+// in place of the taker's own `confirm_payment`. Permissionless: the taker,
+// a relayer or a keeper may submit it. Proven escrows can be force-released
+// straight away since there is nothing left for the seller to check.
+#[derive(Accounts)]
+pub struct VerifyPaymentProof<'info> {
+    pub payer: Signer<'info>,
+    #[account(
+        mut,
+        constraint = escrow.load()?.is_funded() @ EscrowError::EscrowNotFunded,
+        constraint = !escrow.load()?.is_payment_confirmed() @ EscrowError::PaymentAlreadyConfirmed,
+        constraint = escrow.load()?.has_taker() @ EscrowError::InvalidTaker,
+        constraint = !escrow.load()?.is_bond_pending() @ EscrowError::BondNotPosted,
+        constraint = escrow.load()?.has_flag(Escrow::FLAG_ACCEPTS_PAYMENT_PROOF) @ EscrowError::PaymentProofNotAccepted,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    #[account(
+        seeds = [b"payment_verifier"],
+        bump = payment_verifier.bump,
+        constraint = payment_verifier.is_enabled() @ EscrowError::PaymentVerifierNotSet,
+    )]
+    pub payment_verifier: Box<Account<'info, PaymentVerifier>>,
+    /// CHECK: The configured verifier program, CPI'd with the proof
+    #[account(executable, address = payment_verifier.program)]
+    pub verifier_program: UncheckedAccount<'info>,
+    /// CHECK: Blocklist PDA of the taker; must not exist
+    #[account(
+        seeds = [b"blocked", escrow.load()?.taker.as_ref()],
+        bump,
+        constraint = taker_block.data_is_empty() @ EscrowError::WalletBlocked,
+    )]
+    pub taker_block: UncheckedAccount<'info>,
+}
+
+impl<'info> VerifyPaymentProof<'info> {
+    // Remaining accounts are handed to the verifier after the escrow
+    pub fn verify_payment_proof(
+        &mut self,
+        proof: Vec<u8>,
+        proof_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        let escrow = *self.escrow.load()?;
+        let claim = PaymentClaim {
+            escrow: self.escrow.key(),
+            taker: escrow.taker,
+            initializer: escrow.initializer,
+            mint_a: escrow.mint_a,
+            initializer_amount: escrow.initializer_amount,
+            taker_amount: escrow.taker_amount,
+            proof,
+        };
+        verify_payment_claim(
+            &self.verifier_program.to_account_info(),
+            &self.escrow.to_account_info(),
+            proof_accounts,
+            &claim,
+        )?;
+
+        let mut escrow = self.escrow.load_mut()?;
+        escrow.flags |= Escrow::FLAG_PAYMENT_PROVEN;
+        escrow.mark_payment_confirmed(clock::now()?, &self.config);
+
+        emit!(PaymentProven {
+            escrow: self.escrow.key(),
+            taker: escrow.taker,
+            verifier: self.verifier_program.key(),
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::fees::{pro_rata, Quote, SettlementSplit};
+use crate::states::{Config, DonationPledge, Escrow, PartnerConfig, TraderStats};
+
+#[derive(Accounts)]
+pub struct ViewQuote<'info> {
+    #[account(
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(address = escrow.load()?.mint_a)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    // The maker's record, which prices the risk premium; none yet is a clean
+    // record
+    #[account(
+        seeds = [b"trader_stats", escrow.load()?.initializer.as_ref()],
+        bump = initializer_stats.bump,
+    )]
+    pub initializer_stats: Option<Box<Account<'info, TraderStats>>>,
+    // Required when the escrow has a partner
+    #[account(
+        seeds = [b"partner", escrow.load()?.partner_id.to_le_bytes().as_ref()],
+        bump = partner_config.bump,
+    )]
+    pub partner_config: Option<Box<Account<'info, PartnerConfig>>>,
+    // Required when the taker pledged a donation
+    #[account(
+        seeds = [b"donation", escrow.key().as_ref()],
+        bump = donation.bump,
+    )]
+    pub donation: Option<Box<Account<'info, DonationPledge>>>,
+}
+
+impl<'info> ViewQuote<'info> {
+    pub fn view_quote(&self, amount: u64) -> Result<Quote> {
+        let escrow = *self.escrow.load()?;
+        require!(
+            amount <= escrow.initializer_amount,
+            EscrowError::AmountExceedsEscrow
+        );
+
+        // Price the hypothetical amount against the escrow's own terms
+        let taker_amount = pro_rata(escrow.taker_amount, amount, escrow.initializer_amount)?;
+        // and split it the way `exchange` would
+        let loss_rate_bps = self
+            .initializer_stats
+            .as_ref()
+            .map_or(0, |stats| stats.dispute_loss_rate_bps());
+        let split = SettlementSplit::compute(
+            &self.config,
+            &self.mint_a.key(),
+            escrow.category,
+            amount,
+            loss_rate_bps,
+            clock::now()?,
+        )?
+        .with_escrow_extras(
+            &escrow,
+            self.partner_config.as_deref().map(|config| &**config),
+            self.donation.as_deref().map(|pledge| &**pledge),
+            self.mint_a.decimals,
+        )?;
+
+        Ok(Quote {
+            escrow: self.escrow.key(),
+            amount,
+            taker_amount,
+            split,
+        })
+    }
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+// Only compiled with `test-clock`; see `crate::clock::now`
+#[derive(Accounts)]
+pub struct WarpClock<'info> {
+    pub signer: Signer<'info>,
+}
+
+impl<'info> WarpClock<'info> {
+    // Nothing to store: later instructions in the transaction read the
+    // timestamp straight from this instruction's data
+    pub fn warp_clock(&self, unix_timestamp: i64) -> Result<()> {
+        msg!("test clock: {}", unix_timestamp);
+        Ok(())
+    }
+}
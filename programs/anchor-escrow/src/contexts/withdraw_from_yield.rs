@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::constants::PLATFORM_WALLET;
+use crate::errors::EscrowError;
+use crate::events::YieldWithdrawn;
+use crate::fees::split_yield;
+use crate::lending::{withdraw_from_adapter, AdapterAccounts};
+use crate::states::{Config, Escrow, YieldAdapter};
+
+// Permissionless, so whoever settles, cancels or reaps the escrow can put it
+// in front of that instruction. Not feature-gated: a switched-off subsystem
+// still has to give back what it holds.
+#[derive(Accounts)]
+pub struct WithdrawFromYield<'info> {
+    pub payer: Signer<'info>,
+    pub initializer: SystemAccount<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = mint_a,
+        constraint = escrow.load()?.has_yield_position() @ EscrowError::NoYieldPosition,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = initializer,
+        associated_token::token_program = token_program
+    )]
+    pub initializer_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    // Required once a taker is in and the offer passes them part of the yield
+    #[account(
+        mut,
+        token::mint = mint_a,
+        constraint = taker_ata_a.owner == escrow.load()?.taker @ EscrowError::InvalidTaker,
+    )]
+    pub taker_ata_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    /// CHECK: This is the hardcoded platform wallet address
+    #[account(address = PLATFORM_WALLET)]
+    pub platform_wallet: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = platform_wallet,
+        associated_token::token_program = token_program
+    )]
+    pub platform_ata_a: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Box<Account<'info, Config>>,
+    #[account(mut, seeds = [b"yield_adapter"], bump = yield_adapter.bump)]
+    pub yield_adapter: Box<Account<'info, YieldAdapter>>,
+    /// CHECK: The configured adapter program, which holds every open position
+    #[account(executable, address = yield_adapter.program)]
+    pub adapter_program: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawFromYield<'info> {
+    // Redeems the escrow's position back into the vault and splits whatever
+    // it earned between the platform, the maker and, per the offer, the
+    // taker; with no taker yet the maker keeps their whole cut. For escrowed
+    // SOL the adapter unstakes its LST and hands back wrapped SOL, so the
+    // vault pays out in SOL as before. Coming back short, e.g.
+    // while the protocol lacks the liquidity, fails and leaves the position
+    // as it was, so the call can simply be retried later; a loss for good is
+    // the maker's to take with `write_off_yield`.
+    pub fn withdraw_from_yield(&mut self, protocol_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let escrow = *self.escrow.load()?;
+        let balance_before = self.vault.amount;
+        let signer_seeds: [&[&[u8]]; 1] = [&[
+            b"state",
+            &escrow.seed.to_le_bytes()[..],
+            &[escrow.bump],
+        ]];
+        withdraw_from_adapter(
+            &AdapterAccounts {
+                adapter: &self.adapter_program.to_account_info(),
+                owner: &self.escrow.to_account_info(),
+                token_account: &self.vault.to_account_info(),
+                mint: &self.mint_a.to_account_info(),
+                token_program: &self.token_program.to_account_info(),
+                protocol_accounts,
+            },
+            &signer_seeds,
+        )?;
+
+        self.vault.reload()?;
+        let redeemed = self.vault.amount.saturating_sub(balance_before);
+        require!(
+            redeemed >= escrow.yield_principal,
+            EscrowError::YieldShortfall
+        );
+        let earned = redeemed - escrow.yield_principal;
+        let taker_share_bps = if escrow.has_taker() {
+            escrow.yield_taker_share_bps
+        } else {
+            0
+        };
+        let (platform_share, maker_share, taker_share) = split_yield(
+            earned,
+            self.config.yield_maker_share_bps,
+            taker_share_bps,
+        )?;
+        if taker_share > 0 {
+            let taker_ata_a = self
+                .taker_ata_a
+                .as_ref()
+                .ok_or(EscrowError::InvalidTaker)?
+                .to_account_info();
+            transfer_checked(
+                self.into_yield_context(taker_ata_a).with_signer(&signer_seeds),
+                taker_share,
+                self.mint_a.decimals,
+            )?;
+        }
+        if maker_share > 0 {
+            transfer_checked(
+                self.into_yield_context(self.initializer_ata_a.to_account_info())
+                    .with_signer(&signer_seeds),
+                maker_share,
+                self.mint_a.decimals,
+            )?;
+        }
+        if platform_share > 0 {
+            transfer_checked(
+                self.into_yield_context(self.platform_ata_a.to_account_info())
+                    .with_signer(&signer_seeds),
+                platform_share,
+                self.mint_a.decimals,
+            )?;
+        }
+
+        self.escrow.load_mut()?.yield_principal = 0;
+        self.yield_adapter.open_positions -= 1;
+
+        emit!(YieldWithdrawn {
+            escrow: self.escrow.key(),
+            adapter: self.adapter_program.key(),
+            principal: escrow.yield_principal,
+            maker_share,
+            taker_share,
+            platform_share,
+        });
+        Ok(())
+    }
+
+    fn into_yield_context(
+        &self,
+        to: AccountInfo<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.vault.to_account_info(),
+            mint: self.mint_a.to_account_info(),
+            to,
+            authority: self.escrow.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
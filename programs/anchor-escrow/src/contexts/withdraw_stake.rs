@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::clock;
+use crate::errors::EscrowError;
+use crate::events::ArbitratorStakeWithdrawn;
+use crate::states::{ArbitrationPolicy, Arbitrator};
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    pub wallet: Signer<'info>,
+    #[account(
+        mut,
+        has_one = wallet,
+        seeds = [b"arbitrator", wallet.key().as_ref()],
+        bump = arbitrator.bump,
+    )]
+    pub arbitrator: Box<Account<'info, Arbitrator>>,
+    #[account(seeds = [b"arbitration"], bump = arbitration.bump)]
+    pub arbitration: Box<Account<'info, ArbitrationPolicy>>,
+    #[account(
+        address = arbitration.stake_mint @ EscrowError::InvalidStakeMint,
+        mint::token_program = token_program
+    )]
+    pub stake_mint: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = wallet,
+        associated_token::token_program = token_program
+    )]
+    pub wallet_ata: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(
+        mut,
+        associated_token::mint = stake_mint,
+        associated_token::authority = arbitrator,
+        associated_token::token_program = token_program
+    )]
+    pub stake_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawStake<'info> {
+    // Only once `request_unstake`'s appeal window has run out
+    pub fn withdraw_stake(&mut self, amount: u64) -> Result<()> {
+        let unlock_at = self.arbitrator.unlock_at;
+        require!(
+            unlock_at != 0 && clock::now()? >= unlock_at,
+            EscrowError::StakeLocked
+        );
+        require!(
+            amount > 0 && amount <= self.arbitrator.staked,
+            EscrowError::InvalidAmount
+        );
+
+        let wallet = self.wallet.key();
+        let signer_seeds: [&[&[u8]]; 1] =
+            [&[b"arbitrator", wallet.as_ref(), &[self.arbitrator.bump]]];
+        transfer_checked(
+            self.into_withdraw_context().with_signer(&signer_seeds),
+            amount,
+            self.stake_mint.decimals,
+        )?;
+        self.arbitrator.staked -= amount;
+
+        emit!(ArbitratorStakeWithdrawn {
+            arbitrator: wallet,
+            amount,
+            staked: self.arbitrator.staked,
+        });
+        Ok(())
+    }
+
+    fn into_withdraw_context(&self) -> CpiContext<'_, '_, '_, 'info, TransferChecked<'info>> {
+        let cpi_accounts = TransferChecked {
+            from: self.stake_vault.to_account_info(),
+            mint: self.stake_mint.to_account_info(),
+            to: self.wallet_ata.to_account_info(),
+            authority: self.arbitrator.to_account_info(),
+        };
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
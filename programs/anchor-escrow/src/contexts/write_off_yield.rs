@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::EscrowError;
+use crate::events::YieldWrittenOff;
+use crate::fees::pro_rata;
+use crate::invariants::require_vault_backs_escrow;
+use crate::lending::{withdraw_from_adapter, AdapterAccounts};
+use crate::states::{Escrow, YieldAdapter};
+
+// The maker's way out when the lending venue lost part of the principal and
+// `withdraw_from_yield` keeps failing short: takes back whatever redeems, pays
+// no yield and shrinks the escrow to it, so it can be settled or cancelled
+// again. Not feature-gated, like `withdraw_from_yield`.
+#[derive(Accounts)]
+pub struct WriteOffYield<'info> {
+    pub initializer: Signer<'info>,
+    #[account(mint::token_program = token_program)]
+    pub mint_a: Box<InterfaceAccount<'info, Mint>>,
+    #[account(
+        mut,
+        has_one = initializer,
+        has_one = mint_a,
+        constraint = escrow.load()?.has_yield_position() @ EscrowError::NoYieldPosition,
+        seeds=[b"state", escrow.load()?.seed.to_le_bytes().as_ref()],
+        bump = escrow.load()?.bump,
+    )]
+    pub escrow: AccountLoader<'info, Escrow>,
+    #[account(
+        mut,
+        associated_token::mint = mint_a,
+        associated_token::authority = escrow,
+        associated_token::token_program = token_program
+    )]
+    pub vault: Box<InterfaceAccount<'info, TokenAccount>>,
+    #[account(mut, seeds = [b"yield_adapter"], bump = yield_adapter.bump)]
+    pub yield_adapter: Box<Account<'info, YieldAdapter>>,
+    /// CHECK: The configured adapter program, which holds every open position
+    #[account(executable, address = yield_adapter.program)]
+    pub adapter_program: UncheckedAccount<'info>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WriteOffYield<'info> {
+    pub fn write_off_yield(&mut self, protocol_accounts: &[AccountInfo<'info>]) -> Result<()> {
+        let escrow = *self.escrow.load()?;
+        let balance_before = self.vault.amount;
+        let signer_seeds: [&[&[u8]]; 1] =
+            [&[b"state", &escrow.seed.to_le_bytes()[..], &[escrow.bump]]];
+        withdraw_from_adapter(
+            &AdapterAccounts {
+                adapter: &self.adapter_program.to_account_info(),
+                owner: &self.escrow.to_account_info(),
+                token_account: &self.vault.to_account_info(),
+                mint: &self.mint_a.to_account_info(),
+                token_program: &self.token_program.to_account_info(),
+                protocol_accounts,
+            },
+            &signer_seeds,
+        )?;
+
+        self.vault.reload()?;
+        let redeemed = self.vault.amount.saturating_sub(balance_before);
+        // A position that came back whole goes through `withdraw_from_yield`,
+        // which shares what it earned
+        require!(
+            redeemed < escrow.yield_principal,
+            EscrowError::NoYieldShortfall
+        );
+        let loss = escrow.yield_principal - redeemed;
+        let new_amount = escrow.initializer_amount.saturating_sub(loss);
+
+        let mut state = self.escrow.load_mut()?;
+        // Keep the unit price: taker_amount shrinks with what was lost
+        if escrow.initializer_amount > 0 {
+            state.taker_amount =
+                pro_rata(escrow.taker_amount, new_amount, escrow.initializer_amount)?;
+        }
+        state.initializer_amount = new_amount;
+        state.yield_principal = 0;
+        self.yield_adapter.open_positions -= 1;
+
+        emit!(YieldWrittenOff {
+            escrow: self.escrow.key(),
+            adapter: self.adapter_program.key(),
+            principal: escrow.yield_principal,
+            redeemed,
+            initializer_amount: new_amount,
+        });
+        require_vault_backs_escrow(&state, &mut self.vault)
+    }
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::errors::EscrowError;
+use crate::states::Escrow;
+
+// Whether the maker's ATA still backs a delegated escrow: the escrow PDA is
+// the delegate for at least the escrowed amount, and the tokens are there.
+// SPL accounts hold a single delegate, so approving anything else, revoking,
+// or spending the balance all break it.
+pub fn is_backed(escrow_key: &Pubkey, escrow: &Escrow, maker_ata: &TokenAccount) -> bool {
+    maker_ata.owner == escrow.initializer
+        && maker_ata.mint == escrow.mint_a
+        && maker_ata.delegate == COption::Some(*escrow_key)
+        && maker_ata.delegated_amount >= escrow.initializer_amount
+        && maker_ata.amount >= escrow.initializer_amount
+}
+
+// Vault escrows are always backed; delegated ones need the maker's ATA passed in
+pub fn require_backing(
+    escrow_key: &Pubkey,
+    escrow: &Escrow,
+    maker_ata: Option<&InterfaceAccount<TokenAccount>>,
+) -> Result<()> {
+    if !escrow.is_delegated() {
+        return Ok(());
+    }
+    let maker_ata = maker_ata.ok_or(EscrowError::DelegationBroken)?;
+    require!(
+        is_backed(escrow_key, escrow, maker_ata),
+        EscrowError::DelegationBroken
+    );
+    Ok(())
+}
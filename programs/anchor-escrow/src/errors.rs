@@ -0,0 +1,347 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum EscrowError {
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Requested amount exceeds the escrowed amount")]
+    AmountExceedsEscrow,
+    #[msg("Batch must contain between one and MAX_BATCH_SIZE entries")]
+    InvalidBatchSize,
+    #[msg("Remaining accounts do not match the batch entries")]
+    BatchAccountsMismatch,
+    #[msg("Escrow account does not match the derived address")]
+    InvalidEscrowAddress,
+    #[msg("Escrow account layout is not recognised")]
+    UnknownEscrowLayout,
+    #[msg("Escrow is already on the current layout")]
+    EscrowAlreadyMigrated,
+    #[msg("Escrow vault has not been funded")]
+    EscrowNotFunded,
+    #[msg("Escrow vault is already funded")]
+    EscrowAlreadyFunded,
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+    #[msg("Payment has already been confirmed")]
+    PaymentAlreadyConfirmed,
+    #[msg("A taker has already accepted this escrow")]
+    TakerAlreadyAssigned,
+    #[msg("Signer is not the escrow's taker")]
+    InvalidTaker,
+    #[msg("The taker was assigned too recently to be reassigned")]
+    TakerReassignCooldown,
+    #[msg("Vesting schedule is invalid")]
+    InvalidReleaseSchedule,
+    #[msg("Instruction does not match the escrow's release mode")]
+    ReleaseModeMismatch,
+    #[msg("Escrow is not being released")]
+    EscrowNotReleasing,
+    #[msg("Nothing has vested since the last claim")]
+    NothingToClaim,
+    #[msg("Escrow already has the maximum number of milestones")]
+    TooManyMilestones,
+    #[msg("Milestone amounts would exceed the escrowed amount")]
+    MilestonesExceedEscrow,
+    #[msg("Milestone amounts must add up to the escrowed amount")]
+    MilestonesIncomplete,
+    #[msg("Milestone has not been confirmed by the taker")]
+    MilestoneNotConfirmed,
+    #[msg("Milestone has already been confirmed")]
+    MilestoneAlreadyConfirmed,
+    #[msg("Milestone has already been released")]
+    MilestoneAlreadyReleased,
+    #[msg("The arbiter must sign this release")]
+    ArbiterSignatureRequired,
+    #[msg("Arbiter must be a neutral third party")]
+    InvalidArbiter,
+    #[msg("Milestone is under dispute and can only be settled by the arbiter")]
+    MilestoneDisputed,
+    #[msg("Milestone is not under dispute")]
+    MilestoneNotDisputed,
+    #[msg("Signer is not a party to this escrow")]
+    NotEscrowParty,
+    #[msg("Signer is not the protocol admin")]
+    Unauthorized,
+    #[msg("Fee share must be at most 10000 basis points")]
+    InvalidFeeBps,
+    #[msg("Escrow requires a taker bond; accept it with post_bond")]
+    BondRequired,
+    #[msg("Escrow does not require a taker bond")]
+    BondNotRequired,
+    #[msg("Taker bond has not been posted")]
+    BondNotPosted,
+    #[msg("Taker bond is locked until the escrow settles")]
+    BondLocked,
+    #[msg("Bond accounts are missing or do not match the bond")]
+    InvalidBondAccounts,
+    #[msg("Escrow has not been settled yet")]
+    EscrowNotSettled,
+    #[msg("Open offers need a maker deposit before they can be taken")]
+    MakerDepositRequired,
+    #[msg("Maker deposit is not enabled in the protocol config")]
+    MakerDepositDisabled,
+    #[msg("Escrows with a confirmed payment must be cancelled one at a time")]
+    CancelAfterConfirmation,
+    #[msg("This market requires an identity pass")]
+    GatewayPassRequired,
+    #[msg("Identity pass is invalid, inactive or expired")]
+    InvalidGatewayPass,
+    #[msg("Too many attestation schemas")]
+    TooManySchemas,
+    #[msg("Attestation requirements account is missing or does not match")]
+    InvalidAttestationRequirements,
+    #[msg("Taker is missing a required attestation")]
+    AttestationMissing,
+    #[msg("Too many region codes")]
+    TooManyRegions,
+    #[msg("Offer is not available in the taker's region")]
+    RegionNotPermitted,
+    #[msg("Wallet is blocked from trading")]
+    WalletBlocked,
+    #[msg("Trade would exceed the wallet's volume limit")]
+    VolumeLimitExceeded,
+    #[msg("Maker already has the maximum number of open escrows")]
+    TooManyOpenEscrows,
+    #[msg("Expiry must be in the future, or zero to remove it")]
+    InvalidExpiry,
+    #[msg("Escrow has not expired yet")]
+    EscrowNotExpired,
+    #[msg("Auto-release window must not be negative")]
+    InvalidAutoRelease,
+    #[msg("The seller's release window has not lapsed")]
+    AutoReleaseNotDue,
+    #[msg("Escrow is frozen pending review")]
+    EscrowFrozen,
+    #[msg("Escrow is not frozen")]
+    EscrowNotFrozen,
+    #[msg("Session key is missing, expired or not allowed this instruction")]
+    InvalidSession,
+    #[msg("Session must expire in the future and within MAX_SESSION_DURATION")]
+    InvalidSessionExpiry,
+    #[msg("Session must grant at least one known scope")]
+    InvalidSessionScope,
+    #[msg("Release approval signature is missing or does not match the escrow")]
+    InvalidReleaseApproval,
+    #[msg("Release approval has expired")]
+    ReleaseApprovalExpired,
+    #[msg("Escrow has no EVM approver")]
+    EvmApproverNotSet,
+    #[msg("Escrow does not accept payment proofs")]
+    PaymentProofNotAccepted,
+    #[msg("No payment verifier is configured")]
+    PaymentVerifierNotSet,
+    #[msg("Template terms are invalid")]
+    InvalidTemplate,
+    #[msg("Amount is outside the template's limits")]
+    AmountOutsideTemplateLimits,
+    #[msg("Recurring schedule terms are invalid")]
+    InvalidRecurringSchedule,
+    #[msg("The next recurring escrow is not due yet")]
+    RecurringRunNotDue,
+    #[msg("Recurring schedule has no runs left")]
+    RecurringScheduleComplete,
+    #[msg("Payment details URI must be between 1 and MAX_PAYMENT_URI_LEN bytes")]
+    InvalidPaymentDetails,
+    #[msg("Message root must cover at least as many messages as the last one")]
+    InvalidMessageRoot,
+    #[msg("Promo fee must not exceed the platform fee and its window must not end before it starts")]
+    InvalidFeePromo,
+    #[msg("Config account layout is not recognised")]
+    UnknownConfigLayout,
+    #[msg("Config is already on the current layout")]
+    ConfigAlreadyMigrated,
+    #[msg("Maker rebate accounts are required while rebates are enabled")]
+    MakerRebateAccountsMissing,
+    #[msg("No maker rebate has accrued since the last claim")]
+    NoRebateAccrued,
+    #[msg("Permit signature is missing or does not match the escrow parameters")]
+    InvalidPermit,
+    #[msg("Permit has expired")]
+    PermitExpired,
+    #[msg("Delegated escrow is no longer backed by the maker's token account")]
+    DelegationBroken,
+    #[msg("Delegated escrow is still backed by the maker's token account")]
+    DelegationIntact,
+    #[msg("Escrow is not in delegated mode")]
+    NotDelegated,
+    #[msg("Payment has not been confirmed")]
+    PaymentNotConfirmed,
+    #[msg("Escrow and vault are not an orphaned pair this instruction can recover")]
+    InvalidOrphan,
+    #[msg("Account is not a leftover auxiliary PDA of this escrow")]
+    InvalidGcAccount,
+    #[msg("Maker registry is full; cancel or settle an open escrow first")]
+    RegistryFull,
+    #[msg("Escrow is still open")]
+    EscrowStillOpen,
+    #[msg("Fee category must be below MAX_FEE_CATEGORIES and its fee at most 100%")]
+    InvalidCategoryFee,
+    #[msg("The offer does not take this kind of partial payment")]
+    PartialPaymentsDisabled,
+    #[msg("Tranche must be positive and keep the confirmed total within taker_amount")]
+    InvalidPaymentTranche,
+    #[msg("The taker has already confirmed part of the payment")]
+    PartialPaymentRecorded,
+    #[msg("Release delay must be between zero and MAX_RELEASE_DELAY")]
+    InvalidReleaseDelay,
+    #[msg("The buyer-protection delay after payment confirmation has not passed yet")]
+    ReleaseDelayPending,
+    #[msg("Signer is neither the maker nor the taker of the trade")]
+    NotTradeParty,
+    #[msg("Badges were already minted for this trade")]
+    BadgesAlreadyMinted,
+    #[msg("Trade history tree is full")]
+    TradeHistoryFull,
+    #[msg("This feature is switched off")]
+    FeatureDisabled,
+    #[msg("Rate must be positive")]
+    InvalidRate,
+    #[msg("The market's rate feed is required")]
+    RateFeedMissing,
+    #[msg("The market's rate has not been posted recently enough")]
+    StaleRate,
+    #[msg("The market rate moved too far since the offer was priced; both parties must accept the move")]
+    RateDeviationExceeded,
+    #[msg("Only listed stablecoins may be traded")]
+    NotAStablecoin,
+    #[msg("The stablecoin list is required while stablecoin-only mode is on")]
+    StablecoinListMissing,
+    #[msg("Stablecoin list is full")]
+    StablecoinListFull,
+    #[msg("No swap router is configured")]
+    SwapRouterNotSet,
+    #[msg("Swap program or output account doesn't match the configured router and taker")]
+    InvalidSwapRoute,
+    #[msg("The swap paid out less than the minimum output")]
+    SwapSlippageExceeded,
+    #[msg("No yield adapter is configured")]
+    YieldAdapterNotSet,
+    #[msg("The escrow's vault is lent out; withdraw it from the yield adapter first")]
+    YieldPositionOpen,
+    #[msg("The escrow has nothing deposited for yield")]
+    NoYieldPosition,
+    #[msg("The yield adapter returned less than was deposited")]
+    YieldShortfall,
+    #[msg("Charity list is full")]
+    CharityListFull,
+    #[msg("Charity is not on the platform's list")]
+    CharityNotListed,
+    #[msg("Donation must be at most MAX_DONATION_BPS of the payout, to the pledged charity")]
+    InvalidDonation,
+    #[msg("The taker's donation pledge and the charity's token account are required")]
+    DonationPledgeMissing,
+    #[msg("Escrow was funded by a third party; refunds go to the funder's token account")]
+    InvalidFunder,
+    #[msg("Release needs the compliance desk's, and the offer's compliance key's, approval")]
+    ComplianceApprovalMissing,
+    #[msg("Signer is neither the compliance desk nor the offer's compliance key")]
+    NotComplianceSigner,
+    #[msg("Too many languages")]
+    TooManyLanguages,
+    #[msg("None of the candidate arbitrators is active and independent of the maker")]
+    NoArbitratorAvailable,
+    #[msg("Stake mint doesn't match the arbitration policy's")]
+    InvalidStakeMint,
+    #[msg("Appeal window must not be negative")]
+    InvalidAppealWindow,
+    #[msg("Arbitrator has nothing staked")]
+    ArbitratorNotStaked,
+    #[msg("Stake is locked until an unstake request's appeal window has passed")]
+    StakeLocked,
+    #[msg("Ruling is unresolved, a jury verdict, or predates recorded rulings")]
+    RulingNotAppealable,
+    #[msg("The ruling's appeal window has closed")]
+    AppealWindowClosed,
+    #[msg("A jury needs an odd number of distinct, eligible arbitrators outside the trade")]
+    InvalidJury,
+    #[msg("Signer is not on this jury")]
+    NotAJuror,
+    #[msg("A jury was convened on this dispute; its verdict resolves it")]
+    JuryConvened,
+    #[msg("No jury was convened on this dispute")]
+    NoJury,
+    #[msg("The jury has no majority yet")]
+    JuryUndecided,
+    #[msg("Jury commit and reveal periods must be positive")]
+    InvalidJuryPeriod,
+    #[msg("This jury phase is not open")]
+    JuryPhaseClosed,
+    #[msg("Vote was already revealed")]
+    VoteAlreadyRevealed,
+    #[msg("Revealed vote and salt don't match the commitment")]
+    VoteCommitmentMismatch,
+    #[msg("Juror was already penalized for this vote")]
+    JurorAlreadyPenalized,
+    #[msg("Jury can only be dissolved once the reveal phase ended without a verdict")]
+    JuryNotHung,
+    #[msg("No governance authority is configured")]
+    GovernanceNotSet,
+    #[msg("Signer is not the governance authority")]
+    NotGovernanceAuthority,
+    #[msg("Dispute was escalated to governance; only governance_resolve can rule")]
+    DisputeEscalated,
+    #[msg("Dispute has not been escalated to governance")]
+    DisputeNotEscalated,
+    #[msg("Vault holds less than the escrow still owes")]
+    VaultUnderfunded,
+    #[msg("Escrow status can only move forward one step")]
+    IllegalStatusTransition,
+    #[msg("Price commitment must not be zero")]
+    InvalidPriceCommitment,
+    #[msg("Offer price is hidden; accept it with accept_hidden_offer")]
+    PriceHidden,
+    #[msg("Offer price is not hidden")]
+    PriceNotHidden,
+    #[msg("Revealed price and salt don't match the commitment")]
+    PriceCommitmentMismatch,
+    #[msg("Too many fiat quotes for one offer")]
+    TooManyFiatQuotes,
+    #[msg("Fiat quotes need a nonzero currency and price, each currency once")]
+    InvalidFiatQuote,
+    #[msg("Offer is not quoted in this currency")]
+    CurrencyNotQuoted,
+    #[msg("Offer is quoted in several currencies; accept it with accept_in_currency")]
+    CurrencyNotSelected,
+    #[msg("Signed with a nonce other than the wallet's current one")]
+    NonceMismatch,
+    #[msg("Signed approvals need the signer's nonce account")]
+    NonceAccountRequired,
+    #[msg("An idempotency key needs its record account, and the record a nonzero key")]
+    InvalidIdempotencyKey,
+    #[msg("Idempotency record can't be closed before its key expires")]
+    IdempotencyKeyLive,
+    #[msg("A deadline can only be pushed later, and into the future")]
+    InvalidDeadlineExtension,
+    #[msg("Reap grace period must be at most MAX_REAP_GRACE_PERIOD")]
+    InvalidGracePeriod,
+    #[msg("Offer requires the payment reference, backed by a Memo instruction")]
+    PaymentMemoRequired,
+    #[msg("Payment reference must be between 1 and MAX_PAYMENT_REFERENCE_LEN bytes")]
+    InvalidPaymentReference,
+    #[msg("No Memo instruction before this one carries the payment reference")]
+    PaymentMemoMissing,
+    #[msg("Only funded, untaken offers settling in full and free of holds can be matched")]
+    OfferNotMatchable,
+    #[msg("Offers don't trade opposite sides of one market at crossing prices")]
+    OffersDoNotCross,
+    #[msg("Partner id must be nonzero, its fee at most MAX_PARTNER_FEE_BPS and its share at most 10000")]
+    InvalidPartnerConfig,
+    #[msg("Escrows created through a partner need its config and fee account to settle")]
+    PartnerAccountsMissing,
+    #[msg("Partner has no fees to claim in this mint")]
+    NoPartnerFeesAccrued,
+    #[msg("This trade was already published to Wormhole")]
+    SettlementAlreadyPublished,
+    #[msg("Vault received nothing, or more than was deposited")]
+    DepositMismatch,
+    #[msg("Taker's token account is frozen; it must be thawed before the release")]
+    TakerAccountFrozen,
+    #[msg("Platform's token account is frozen; it must be thawed before the release")]
+    PlatformAccountFrozen,
+    #[msg("A milestone dispute is open on this escrow")]
+    DisputeOpen,
+    #[msg("Yield position came back whole; use withdraw_from_yield")]
+    NoYieldShortfall,
+    #[msg("The maker can't take their own offer")]
+    SelfTrade,
+}
@@ -0,0 +1,741 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{AdminAction, FiatQuote, SettledTrade};
+
+#[event]
+pub struct EscrowRepriced {
+    pub escrow: Pubkey,
+    pub old_taker_amount: u64,
+    pub new_taker_amount: u64,
+}
+
+#[event]
+pub struct EscrowRelisted {
+    // Escrow whose terms were copied
+    pub source: Pubkey,
+    pub escrow: Pubkey,
+    pub initializer: Pubkey,
+}
+
+#[event]
+pub struct TemplateSet {
+    pub template: Pubkey,
+    pub maker: Pubkey,
+    pub template_id: u16,
+}
+
+#[event]
+pub struct EscrowPostedFromTemplate {
+    pub template: Pubkey,
+    pub escrow: Pubkey,
+    pub initializer: Pubkey,
+    pub taker_amount: u64,
+}
+
+#[event]
+pub struct TakerAssigned {
+    pub escrow: Pubkey,
+    pub previous_taker: Pubkey,
+    pub taker: Pubkey,
+}
+
+#[event]
+pub struct ReleaseScheduleSet {
+    pub escrow: Pubkey,
+    pub start: i64,
+    pub cliff: i64,
+    pub duration: i64,
+}
+
+#[event]
+pub struct VestedClaimed {
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    pub amount: u64,
+    pub released_amount: u64,
+}
+
+#[event]
+pub struct MilestoneReleased {
+    pub escrow: Pubkey,
+    pub index: u8,
+    pub amount: u64,
+    pub platform_fee: u64,
+}
+
+#[event]
+pub struct MilestoneDisputeOpened {
+    pub escrow: Pubkey,
+    pub index: u8,
+    pub opened_by: Pubkey,
+    pub reason_hash: [u8; 32],
+}
+
+#[event]
+pub struct MilestoneDisputeResolved {
+    pub escrow: Pubkey,
+    pub index: u8,
+    pub arbiter: Pubkey,
+    pub released_to_taker: bool,
+}
+
+#[event]
+pub struct StreamClaimed {
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    pub amount: u64,
+    pub platform_fee: u64,
+    pub released_amount: u64,
+}
+
+#[event]
+pub struct InsuranceFunded {
+    pub escrow: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct InsuranceClaimPaid {
+    pub escrow: Pubkey,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BondPosted {
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BondReleased {
+    pub escrow: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub forfeited: bool,
+}
+
+#[event]
+pub struct BondSlashed {
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    pub winner: Pubkey,
+    pub amount: u64,
+    pub arbitration_fee: u64,
+}
+
+#[event]
+pub struct MakerDepositPosted {
+    pub escrow: Pubkey,
+    pub maker: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MakerDepositForfeited {
+    pub escrow: Pubkey,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub amount: u64,
+    pub strikes: u32,
+}
+
+#[event]
+pub struct MakerDepositRefunded {
+    pub escrow: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct WalletBlocked {
+    pub wallet: Pubkey,
+    pub admin: Pubkey,
+    pub reason_hash: [u8; 32],
+}
+
+#[event]
+pub struct WalletUnblocked {
+    pub wallet: Pubkey,
+    pub admin: Pubkey,
+}
+
+#[event]
+pub struct ExpirySet {
+    pub escrow: Pubkey,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct EscrowReaped {
+    pub escrow: Pubkey,
+    pub initializer: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct AutoReleaseSet {
+    pub escrow: Pubkey,
+    pub auto_release_after: i64,
+}
+
+#[event]
+pub struct ForceReleased {
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct EscrowFrozen {
+    pub escrow: Pubkey,
+    pub admin: Pubkey,
+    pub reason_hash: [u8; 32],
+}
+
+#[event]
+pub struct EscrowUnfrozen {
+    pub escrow: Pubkey,
+    pub admin: Pubkey,
+}
+
+// One entry of the admin audit trail; `chain_hash` is the [b"audit_log"] head
+// after this entry
+#[event]
+pub struct AdminActionRecorded {
+    pub sequence: u64,
+    pub action: AdminAction,
+    pub actor: Pubkey,
+    // Wallet, escrow or config the action was applied to
+    pub target: Pubkey,
+    pub timestamp: i64,
+    pub params: Vec<u8>,
+    pub chain_hash: [u8; 32],
+}
+
+#[event]
+pub struct PaymentDelegateSet {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+}
+
+#[event]
+pub struct PaymentDelegateRevoked {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+}
+
+#[event]
+pub struct SessionCreated {
+    pub maker: Pubkey,
+    pub session_key: Pubkey,
+    pub expires_at: i64,
+    pub scopes: u8,
+}
+
+#[event]
+pub struct SessionRevoked {
+    pub maker: Pubkey,
+    pub session_key: Pubkey,
+}
+
+#[event]
+pub struct EvmApproverSet {
+    pub escrow: Pubkey,
+    pub evm_approver: [u8; 20],
+}
+
+#[event]
+pub struct PaymentProofAcceptanceSet {
+    pub escrow: Pubkey,
+    pub accept: bool,
+}
+
+#[event]
+pub struct PaymentProven {
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    pub verifier: Pubkey,
+}
+
+#[event]
+pub struct RecurringScheduleCreated {
+    pub schedule: Pubkey,
+    pub maker: Pubkey,
+    pub taker: Pubkey,
+    pub runs: u32,
+    pub interval: i64,
+}
+
+#[event]
+pub struct RecurringEscrowSpawned {
+    pub schedule: Pubkey,
+    pub escrow: Pubkey,
+    // Zero-based index of the run
+    pub run: u32,
+}
+
+#[event]
+pub struct RecurringScheduleCancelled {
+    pub schedule: Pubkey,
+    pub runs_done: u32,
+    pub refunded: u64,
+}
+
+#[event]
+pub struct PaymentDetailsSet {
+    pub escrow: Pubkey,
+    // Whom the details are encrypted to
+    pub taker: Pubkey,
+    pub uri: String,
+    pub details_hash: [u8; 32],
+}
+
+#[event]
+pub struct MessageRootAnchored {
+    pub escrow: Pubkey,
+    pub author: Pubkey,
+    pub root: [u8; 32],
+    pub message_count: u64,
+    pub chain_hash: [u8; 32],
+}
+
+#[event]
+pub struct MakerRebateAccrued {
+    pub escrow: Pubkey,
+    pub maker: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MakerRebateClaimed {
+    pub maker: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RiskPremiumCharged {
+    pub escrow: Pubkey,
+    pub initializer: Pubkey,
+    pub loss_rate_bps: u16,
+    pub amount: u64,
+}
+
+#[event]
+pub struct EscrowCreatedWithPermit {
+    pub escrow: Pubkey,
+    pub maker: Pubkey,
+    pub relayer: Pubkey,
+}
+
+#[event]
+pub struct DelegatedEscrowInvalidated {
+    pub escrow: Pubkey,
+    pub initializer: Pubkey,
+}
+
+#[event]
+pub struct OrphanClosed {
+    pub seed: u64,
+    // The orphaned vault or escrow state that was closed
+    pub account: Pubkey,
+    // Owner of the token account swept vault tokens went to, if any
+    pub recipient: Pubkey,
+    pub rent_recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct EscrowGarbageCollected {
+    pub escrow: Pubkey,
+    pub cranker: Pubkey,
+    // Auxiliary accounts closed
+    pub accounts: u8,
+    // Rent reclaimed, bounty included
+    pub reclaimed: u64,
+    pub bounty: u64,
+}
+
+#[event]
+pub struct PartialPaymentsSet {
+    pub escrow: Pubkey,
+    // PartialPayments
+    pub policy: u8,
+}
+
+#[event]
+pub struct PartialPaymentConfirmed {
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    pub amount: u64,
+    // Confirmed so far and not yet released against
+    pub confirmed_amount: u64,
+    pub taker_amount: u64,
+}
+
+#[event]
+pub struct TrancheReleased {
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    // Fiat the release pays for
+    pub fiat_amount: u64,
+    // mint_a to the taker, net of the platform fee
+    pub amount: u64,
+    pub platform_fee: u64,
+    // mint_a left in escrow
+    pub remaining_amount: u64,
+}
+
+#[event]
+pub struct ReleaseDelaySet {
+    pub escrow: Pubkey,
+    pub release_delay: i64,
+}
+
+#[event]
+pub struct ReceiptClosed {
+    pub receipt: Pubkey,
+    pub escrow: Pubkey,
+    pub closed_by: Pubkey,
+}
+
+#[event]
+pub struct BadgesMinted {
+    pub receipt: Pubkey,
+    pub initializer: Pubkey,
+    pub taker: Pubkey,
+}
+
+#[event]
+pub struct TradeRecorded {
+    pub trader: Pubkey,
+    // Position of the trade's leaf in the trader's history tree
+    pub leaf_index: u64,
+    // History root with the leaf in
+    pub root: [u8; 32],
+    pub trade: SettledTrade,
+}
+
+#[event]
+pub struct RatePosted {
+    pub feed: Pubkey,
+    pub rate: u64,
+}
+
+#[event]
+pub struct RateSnapshotSet {
+    pub escrow: Pubkey,
+    pub rate: u64,
+    // Taken from the market's feed rather than stated by the maker
+    pub from_feed: bool,
+}
+
+#[event]
+pub struct RateMoveAccepted {
+    pub escrow: Pubkey,
+    pub previous_rate: u64,
+    pub rate: u64,
+}
+
+#[event]
+pub struct SettlementSwapped {
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    pub router: Pubkey,
+    pub mint_in: Pubkey,
+    pub amount_in: u64,
+    pub mint_out: Pubkey,
+    pub amount_out: u64,
+}
+
+#[event]
+pub struct FeesConverted {
+    pub caller: Pubkey,
+    pub router: Pubkey,
+    pub mint_in: Pubkey,
+    pub amount_in: u64,
+    pub mint_out: Pubkey,
+    pub amount_out: u64,
+}
+
+#[event]
+pub struct YieldDeposited {
+    pub escrow: Pubkey,
+    pub adapter: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct YieldWithdrawn {
+    pub escrow: Pubkey,
+    pub adapter: Pubkey,
+    pub principal: u64,
+    pub maker_share: u64,
+    pub taker_share: u64,
+    pub platform_share: u64,
+}
+
+#[event]
+pub struct YieldSplitSet {
+    pub escrow: Pubkey,
+    pub taker_share_bps: u16,
+}
+
+#[event]
+pub struct DonationPledged {
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    pub charity: Pubkey,
+    pub donation_bps: u16,
+    pub round_up: bool,
+}
+
+#[event]
+pub struct DonationMade {
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    pub charity: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct OfferComplianceSet {
+    pub escrow: Pubkey,
+    pub signer: Pubkey,
+}
+
+#[event]
+pub struct ReleaseApproved {
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    pub approver: Pubkey,
+    // Signed as the platform desk rather than the offer's own key
+    pub desk: bool,
+}
+
+#[event]
+pub struct NoteSet {
+    pub escrow: Pubkey,
+    pub note: [u8; 64],
+}
+
+#[event]
+pub struct ArbiterAssigned {
+    pub escrow: Pubkey,
+    pub arbiter: Pubkey,
+    // The arbiter serves one of the offer's allowed regions
+    pub region_match: bool,
+}
+
+#[event]
+pub struct ArbitratorStaked {
+    pub arbitrator: Pubkey,
+    pub amount: u64,
+    pub staked: u64,
+}
+
+#[event]
+pub struct ArbitratorUnstakeRequested {
+    pub arbitrator: Pubkey,
+    pub unlock_at: i64,
+}
+
+#[event]
+pub struct ArbitratorStakeWithdrawn {
+    pub arbitrator: Pubkey,
+    pub amount: u64,
+    pub staked: u64,
+}
+
+#[event]
+pub struct ArbitrationRewardsClaimed {
+    pub arbitrator: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RulingOverturned {
+    pub dispute: Pubkey,
+    pub arbiter: Pubkey,
+    pub slashed: u64,
+    pub reason_hash: [u8; 32],
+}
+
+#[event]
+pub struct JuryConvened {
+    pub escrow: Pubkey,
+    pub index: u8,
+    pub jury: Pubkey,
+    pub juror_count: u8,
+    pub commit_ends_at: i64,
+    pub reveal_ends_at: i64,
+}
+
+#[event]
+pub struct JuryVoteCommitted {
+    pub jury: Pubkey,
+    pub juror: Pubkey,
+}
+
+#[event]
+pub struct JuryVoteRevealed {
+    pub jury: Pubkey,
+    pub juror: Pubkey,
+    pub release_to_taker: bool,
+}
+
+#[event]
+pub struct JurorPenalized {
+    pub jury: Pubkey,
+    pub juror: Pubkey,
+    pub slashed: u64,
+}
+
+#[event]
+pub struct JuryDissolved {
+    pub jury: Pubkey,
+    pub dispute: Pubkey,
+}
+
+#[event]
+pub struct DisputeFeeSettled {
+    pub escrow: Pubkey,
+    pub index: u8,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    // Returned to the party who opened the dispute and won it
+    pub refunded: bool,
+}
+
+#[event]
+pub struct DisputeEscalated {
+    pub escrow: Pubkey,
+    pub index: u8,
+    pub escalated_by: Pubkey,
+    pub proposal: Pubkey,
+}
+
+#[event]
+pub struct PriceHidden {
+    pub escrow: Pubkey,
+    pub commitment: [u8; 32],
+}
+
+#[event]
+pub struct PriceRevealed {
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    pub taker_amount: u64,
+}
+
+#[event]
+pub struct FiatQuotesSet {
+    pub escrow: Pubkey,
+    pub quotes: Vec<FiatQuote>,
+}
+
+#[event]
+pub struct CurrencySelected {
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    pub currency: u16,
+    pub taker_amount: u64,
+}
+
+#[event]
+pub struct NonceAdvanced {
+    pub wallet: Pubkey,
+    // The nonce the wallet's next signature must carry
+    pub nonce: u64,
+}
+
+#[event]
+pub struct IdempotencyRecordClosed {
+    pub maker: Pubkey,
+    pub escrow: Pubkey,
+    pub closed_by: Pubkey,
+}
+
+#[event]
+pub struct DeadlineExtended {
+    pub escrow: Pubkey,
+    pub old_expires_at: i64,
+    pub new_expires_at: i64,
+}
+
+#[event]
+pub struct PaymentMemoRequirementSet {
+    pub escrow: Pubkey,
+    pub required: bool,
+}
+
+#[event]
+pub struct PaymentReferenceConfirmed {
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    pub reference: String,
+}
+
+#[event]
+pub struct OffersMatched {
+    pub matcher: Pubkey,
+    pub offer_a: Pubkey,
+    pub offer_b: Pubkey,
+    // Net amounts each maker received: offer_a's mint_a went to offer_b's
+    // maker and the other way round
+    pub mint_a: Pubkey,
+    pub amount_a: u64,
+    pub mint_b: Pubkey,
+    pub amount_b: u64,
+}
+
+#[event]
+pub struct PartnerFeePaid {
+    pub escrow: Pubkey,
+    pub partner_id: u16,
+    pub fee_wallet: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PartnerShareAccrued {
+    pub escrow: Pubkey,
+    pub partner_id: u16,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PartnerFeesClaimed {
+    pub partner_id: u16,
+    pub fee_wallet: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SettlementPublished {
+    pub receipt: Pubkey,
+    pub escrow: Pubkey,
+    // Emitter and sequence of the Wormhole message, which locate its VAA
+    pub emitter: Pubkey,
+    pub sequence: u64,
+}
+
+#[event]
+pub struct YieldWrittenOff {
+    pub escrow: Pubkey,
+    pub adapter: Pubkey,
+    pub principal: u64,
+    pub redeemed: u64,
+    // What the escrow is left holding for the taker
+    pub initializer_amount: u64,
+}
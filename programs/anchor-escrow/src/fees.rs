@@ -0,0 +1,340 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::constants::BPS_DENOMINATOR;
+use crate::errors::EscrowError;
+use crate::states::{Config, DonationPledge, Escrow, PartnerConfig};
+
+// value * numerator / denominator without intermediate overflow
+pub fn pro_rata(value: u64, numerator: u64, denominator: u64) -> Result<u64> {
+    if denominator == 0 {
+        return Ok(0);
+    }
+    let scaled = (value as u128) * (numerator as u128) / (denominator as u128);
+    if scaled > u64::MAX as u128 {
+        return err!(EscrowError::MathOverflow);
+    }
+    Ok(scaled as u64)
+}
+
+// Splits a platform fee into (platform share, insurance fund share)
+pub fn split_insurance(platform_fee: u64, insurance_fee_bps: u16) -> Result<(u64, u64)> {
+    let insurance_fee = pro_rata(
+        platform_fee,
+        insurance_fee_bps as u64,
+        BPS_DENOMINATOR as u64,
+    )?;
+    Ok((platform_fee - insurance_fee, insurance_fee))
+}
+
+// Maker rebate on a fill of `amount`, paid out of the platform's share of the
+// fee and never more than it
+pub fn maker_rebate(amount: u64, maker_rebate_bps: u16, platform_share: u64) -> Result<u64> {
+    let rebate = pro_rata(amount, maker_rebate_bps as u64, BPS_DENOMINATOR as u64)?;
+    Ok(rebate.min(platform_share))
+}
+
+// Risk premium on `amount` for a trader losing `loss_rate_bps` of their
+// disputes, reaching `max_bps` of the amount at a 100% loss rate
+pub fn risk_premium(amount: u64, loss_rate_bps: u16, max_bps: u16) -> Result<u64> {
+    let premium_bps = pro_rata(max_bps as u64, loss_rate_bps as u64, BPS_DENOMINATOR as u64)?;
+    pro_rata(amount, premium_bps, BPS_DENOMINATOR as u64)
+}
+
+// Splits yield a lent-out vault `earned` into (platform, maker, taker)
+// shares: the config sets the maker's cut against the platform, and the offer
+// how much of that cut is passed on to its taker
+pub fn split_yield(
+    earned: u64,
+    maker_share_bps: u16,
+    taker_share_bps: u16,
+) -> Result<(u64, u64, u64)> {
+    let makers_cut = pro_rata(earned, maker_share_bps as u64, BPS_DENOMINATOR as u64)?;
+    let taker_share = pro_rata(makers_cut, taker_share_bps as u64, BPS_DENOMINATOR as u64)?;
+    Ok((earned - makers_cut, makers_cut - taker_share, taker_share))
+}
+
+// Where the mint_a of a settling escrow goes: the platform fee split between
+// the platform, the insurance fund, the maker's rebate and the partner's
+// revenue share, plus the seller's risk premium, the partner's fee and the
+// taker's donation, with the rest for the buyer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SettlementSplit {
+    pub platform_share: u64,
+    pub insurance_share: u64,
+    pub rebate: u64,
+    pub premium: u64,
+    pub partner_share: u64,
+    pub partner_fee: u64,
+    pub donation: u64,
+    pub buyer_amount: u64,
+}
+
+impl SettlementSplit {
+    // `loss_rate_bps` is the initializer's dispute-loss rate before this trade
+    pub fn compute(
+        config: &Config,
+        mint: &Pubkey,
+        category: u8,
+        amount: u64,
+        loss_rate_bps: u16,
+        now: i64,
+    ) -> Result<Self> {
+        let fees = FeeBreakdown::for_settlement(config, category, amount, now)?;
+        let split = Self::for_payout(config, fees.platform_fee, fees.net_amount)?;
+        // The maker's rebate comes out of what the platform would have kept
+        let rebate = maker_rebate(amount, config.maker_rebate_bps(mint), split.platform_share)?;
+        // Sellers who keep losing disputes top up the insurance fund on top of the fee
+        let premium = risk_premium(amount, loss_rate_bps, config.risk_premium_max_bps)?
+            .min(split.buyer_amount);
+        Ok(Self {
+            platform_share: split.platform_share - rebate,
+            insurance_share: split.insurance_share + premium,
+            rebate,
+            premium,
+            buyer_amount: split.buyer_amount - premium,
+            ..split
+        })
+    }
+
+    // Just the platform fee, less the insurance fund's slice, for the paths
+    // that pay no maker rebate or risk premium (tranches, milestones,
+    // vesting and streams)
+    pub fn for_payout(config: &Config, platform_fee: u64, net_amount: u64) -> Result<Self> {
+        let (platform_share, insurance_share) =
+            split_insurance(platform_fee, config.insurance_fee_bps)?;
+        Ok(Self {
+            platform_share,
+            insurance_share,
+            rebate: 0,
+            premium: 0,
+            partner_share: 0,
+            partner_fee: 0,
+            donation: 0,
+            buyer_amount: net_amount,
+        })
+    }
+
+    // The partner's fee comes out of the buyer's payout, and its revenue
+    // share out of what the platform keeps
+    pub fn with_partner(mut self, partner: &PartnerConfig) -> Result<Self> {
+        self.partner_share = partner.revenue_share(self.platform_share)?;
+        self.platform_share -= self.partner_share;
+        self.partner_fee = partner.fee(self.buyer_amount)?;
+        self.buyer_amount -= self.partner_fee;
+        Ok(self)
+    }
+
+    // The taker's pledge comes out of what is left for them
+    pub fn with_donation(mut self, pledge: &DonationPledge, decimals: u8) -> Result<Self> {
+        self.donation = pledge.amount(self.buyer_amount, decimals)?;
+        self.buyer_amount -= self.donation;
+        Ok(self)
+    }
+
+    // The partner's cut and the taker's pledge, in the order `exchange` takes
+    // them, for the read-only views that preview its payout
+    pub fn with_escrow_extras(
+        self,
+        escrow: &Escrow,
+        partner: Option<&PartnerConfig>,
+        pledge: Option<&DonationPledge>,
+        decimals: u8,
+    ) -> Result<Self> {
+        let mut split = self;
+        if escrow.has_partner() {
+            let partner = partner.ok_or(EscrowError::PartnerAccountsMissing)?;
+            split = split.with_partner(partner)?;
+        }
+        if escrow.has_donation_pledge() {
+            let pledge = pledge.ok_or(EscrowError::DonationPledgeMissing)?;
+            split = split.with_donation(pledge, decimals)?;
+        }
+        Ok(split)
+    }
+}
+
+// Charges the anti-spam creation fee. A refundable fee is parked on the escrow
+// so closing it on settlement hands it back with the rent; otherwise it goes
+// straight to the platform wallet. Returns whether the fee was parked.
+pub fn charge_creation_fee<'info>(
+    config: &Config,
+    payer: AccountInfo<'info>,
+    escrow: AccountInfo<'info>,
+    platform_wallet: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+) -> Result<bool> {
+    let fee = config.creation_fee_lamports as u64;
+    if fee == 0 {
+        return Ok(false);
+    }
+    let to = if config.refund_creation_fee {
+        escrow
+    } else {
+        platform_wallet
+    };
+    transfer(
+        CpiContext::new(system_program, Transfer { from: payer, to }),
+        fee,
+    )?;
+    Ok(config.refund_creation_fee)
+}
+
+// Moves a parked creation fee to the platform wallet before an escrow that
+// never settled is closed
+pub fn forfeit_creation_fee(escrow: &AccountInfo, platform_wallet: &AccountInfo) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(escrow.data_len());
+    let fee = escrow.lamports().saturating_sub(rent);
+    **escrow.try_borrow_mut_lamports()? -= fee;
+    **platform_wallet.try_borrow_mut_lamports()? += fee;
+    Ok(())
+}
+
+// Platform fee on a given amount of mint_a, taken by every settlement path
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeeBreakdown {
+    pub amount: u64,
+    pub fee_percentage: u8,
+    pub platform_fee: u64,
+    pub net_amount: u64,
+}
+
+impl FeeBreakdown {
+    // What settling `amount` of an escrow in `category` at `now` is charged:
+    // the category's rate or the standard one, lowered by a running promo.
+    // Every settlement path takes its platform fee through here.
+    pub fn for_settlement(config: &Config, category: u8, amount: u64, now: i64) -> Result<Self> {
+        Self::at_rate(amount, config.fee_percentage(category, now))
+    }
+
+    // Same split at a fee percentage other than the standard one, e.g. a promo
+    pub fn at_rate(amount: u64, fee_percentage: u8) -> Result<Self> {
+        let platform_fee = (amount as u128)
+            .checked_mul(fee_percentage as u128)
+            .ok_or(EscrowError::MathOverflow)?
+            / 100;
+        let platform_fee = platform_fee as u64;
+
+        Ok(Self {
+            amount,
+            fee_percentage,
+            platform_fee,
+            net_amount: amount - platform_fee,
+        })
+    }
+}
+
+// Pro-rata quote for taking `amount` of mint_a out of an escrow
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Quote {
+    pub escrow: Pubkey,
+    pub amount: u64,
+    pub taker_amount: u64,
+    pub split: SettlementSplit,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A zeroed config: the standard 6% fee and no rebate, premium or promo
+    fn config() -> Config {
+        let mut config = Config::try_from_slice(&[0u8; Config::INIT_SPACE]).unwrap();
+        config.insurance_fee_bps = 1_000;
+        config
+    }
+
+    fn partner() -> PartnerConfig {
+        PartnerConfig {
+            version: PartnerConfig::VERSION,
+            bump: 255,
+            partner_id: 1,
+            fee_bps: 100,
+            fee_wallet: Pubkey::new_unique(),
+            branding_id: [0; 32],
+            revenue_share_bps: 5_000,
+            _reserved: [0; 30],
+        }
+    }
+
+    #[test]
+    fn settlement_split_adds_up_to_the_amount() {
+        let mut config = config();
+        config.maker_rebate_bps = 100;
+        config.risk_premium_max_bps = 200;
+
+        let split =
+            SettlementSplit::compute(&config, &Pubkey::default(), 0, 10_000, 5_000, 0).unwrap();
+        // 600 fee, 60 of it to insurance; a 1% rebate off the platform's
+        // share and a 1% premium at a 50% loss rate off the buyer's
+        assert_eq!(
+            split,
+            SettlementSplit {
+                platform_share: 440,
+                insurance_share: 160,
+                rebate: 100,
+                premium: 100,
+                partner_share: 0,
+                partner_fee: 0,
+                donation: 0,
+                buyer_amount: 9_300,
+            }
+        );
+    }
+
+    #[test]
+    fn partner_and_donation_come_out_of_the_split() {
+        let pledge = DonationPledge {
+            version: DonationPledge::VERSION,
+            bump: 255,
+            escrow: Pubkey::new_unique(),
+            taker: Pubkey::new_unique(),
+            charity: Pubkey::new_unique(),
+            donation_bps: 1_000,
+            round_up: true,
+            _reserved: [0; 16],
+        };
+        let split = SettlementSplit::for_payout(&config(), 600, 9_400)
+            .unwrap()
+            .with_partner(&partner())
+            .unwrap()
+            .with_donation(&pledge, 2)
+            .unwrap();
+
+        assert_eq!((split.platform_share, split.partner_share), (270, 270));
+        assert_eq!(split.partner_fee, 94);
+        // 10% of 9_306, rounded up so the taker gets whole tokens
+        assert_eq!((split.donation, split.buyer_amount), (1_006, 8_300));
+        assert_eq!(
+            split.platform_share
+                + split.insurance_share
+                + split.partner_share
+                + split.partner_fee
+                + split.donation
+                + split.buyer_amount,
+            10_000
+        );
+    }
+
+    #[test]
+    fn previews_need_the_escrows_partner() {
+        let mut escrow = Escrow::new(
+            1,
+            255,
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            10_000,
+            500,
+        );
+        let split = SettlementSplit::for_payout(&config(), 600, 9_400).unwrap();
+        assert_eq!(split.with_escrow_extras(&escrow, None, None, 6).unwrap(), split);
+
+        escrow.partner_id = 1;
+        assert!(split.with_escrow_extras(&escrow, None, None, 6).is_err());
+        let with_partner = split
+            .with_escrow_extras(&escrow, Some(&partner()), None, 6)
+            .unwrap();
+        assert_eq!(with_partner.buyer_amount, 9_306);
+    }
+}
@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+use std::convert::TryInto;
+
+use crate::clock;
+use crate::constants::{CIVIC_GATEWAY_PROGRAM_ID, SAS_PROGRAM_ID};
+use crate::errors::EscrowError;
+use crate::states::{
+    AttestationRequirements, ComplianceApproval, Config, Escrow, RegionPolicy, StablecoinList,
+};
+
+// Borsh layout of a Civic gateway token account
+#[derive(AnchorDeserialize)]
+struct GatewayToken {
+    _features: u8,
+    _parent_gateway_token: Option<Pubkey>,
+    owner_wallet: Pubkey,
+    _owner_identity: Option<Pubkey>,
+    gatekeeper_network: Pubkey,
+    _issuing_gatekeeper: Pubkey,
+    state: u8,
+    expire_time: Option<i64>,
+}
+
+// GatewayTokenState::Active
+const GATEWAY_TOKEN_ACTIVE: u8 = 0;
+
+// With a gatekeeper network configured, `wallet` must present an active,
+// unexpired gateway token issued on that network
+pub fn require_gateway_pass(
+    config: &Config,
+    gateway_token: Option<&AccountInfo>,
+    wallet: &Pubkey,
+) -> Result<()> {
+    if config.gatekeeper_network == Pubkey::default() {
+        return Ok(());
+    }
+    let gateway_token = gateway_token.ok_or(EscrowError::GatewayPassRequired)?;
+    require_keys_eq!(
+        *gateway_token.owner,
+        CIVIC_GATEWAY_PROGRAM_ID,
+        EscrowError::InvalidGatewayPass
+    );
+
+    let data = gateway_token.try_borrow_data()?;
+    let token =
+        GatewayToken::deserialize(&mut &data[..]).map_err(|_| EscrowError::InvalidGatewayPass)?;
+    require_keys_eq!(token.owner_wallet, *wallet, EscrowError::InvalidGatewayPass);
+    require_keys_eq!(
+        token.gatekeeper_network,
+        config.gatekeeper_network,
+        EscrowError::InvalidGatewayPass
+    );
+    require!(
+        token.state == GATEWAY_TOKEN_ACTIVE,
+        EscrowError::InvalidGatewayPass
+    );
+    if let Some(expire_time) = token.expire_time {
+        require!(expire_time > clock::now()?, EscrowError::InvalidGatewayPass);
+    }
+    Ok(())
+}
+
+// In stablecoin-only mode both legs of a new offer must be listed stablecoins
+pub fn require_stablecoin_market(
+    config: &Config,
+    stablecoins: Option<&Account<StablecoinList>>,
+    mint_a: &Pubkey,
+    mint_b: &Pubkey,
+) -> Result<()> {
+    if !config.stablecoin_only {
+        return Ok(());
+    }
+    let stablecoins = stablecoins.ok_or(EscrowError::StablecoinListMissing)?;
+    require!(
+        stablecoins.contains(mint_a) && stablecoins.contains(mint_b),
+        EscrowError::NotAStablecoin
+    );
+    Ok(())
+}
+
+// Borsh layout of a Solana Attestation Service attestation account, after its
+// one-byte discriminator. `nonce` is the attested wallet.
+#[derive(AnchorDeserialize)]
+struct Attestation {
+    nonce: Pubkey,
+    credential: Pubkey,
+    schema: Pubkey,
+    data: Vec<u8>,
+    _signer: Pubkey,
+    expiry: i64,
+}
+
+// Every schema in `requirements` must be covered by one of `attestations`:
+// an unexpired SAS attestation for `wallet` from the trusted credential
+pub fn require_attestations(
+    requirements: &AttestationRequirements,
+    attestations: &[AccountInfo],
+    wallet: &Pubkey,
+) -> Result<()> {
+    let now = clock::now()?;
+    for schema in requirements.required_schemas() {
+        let mut covered = false;
+        for info in attestations {
+            if let Some(attestation) = read_attestation(info)? {
+                covered = attestation.schema == *schema
+                    && attestation.credential == requirements.credential
+                    && attestation.nonce == *wallet
+                    && (attestation.expiry == 0 || attestation.expiry > now);
+                if covered {
+                    break;
+                }
+            }
+        }
+        require!(covered, EscrowError::AttestationMissing);
+    }
+    Ok(())
+}
+
+fn read_attestation(info: &AccountInfo) -> Result<Option<Attestation>> {
+    if *info.owner != SAS_PROGRAM_ID {
+        return Ok(None);
+    }
+    let data = info.try_borrow_data()?;
+    if data.is_empty() {
+        return Ok(None);
+    }
+    Ok(Attestation::deserialize(&mut &data[1..]).ok())
+}
+
+// Checks the global and per-offer attestation sets for a taker taking `escrow`
+pub fn require_taker_attestations(
+    config: &Config,
+    escrow: &Escrow,
+    global: Option<&Account<AttestationRequirements>>,
+    offer: Option<&Account<AttestationRequirements>>,
+    attestations: &[AccountInfo],
+    taker: &Pubkey,
+) -> Result<()> {
+    if config.attestations_required {
+        let global = global.ok_or(EscrowError::InvalidAttestationRequirements)?;
+        require_attestations(global, attestations, taker)?;
+    }
+    if escrow.has_flag(Escrow::FLAG_OFFER_ATTESTATIONS) {
+        let offer = offer.ok_or(EscrowError::InvalidAttestationRequirements)?;
+        require_attestations(offer, attestations, taker)?;
+    }
+    Ok(())
+}
+
+// With a region policy on the offer, the taker must present an unexpired
+// region attestation for themselves whose code the policy permits
+pub fn require_taker_region(
+    escrow: &Escrow,
+    policy: Option<&Account<RegionPolicy>>,
+    attestations: &[AccountInfo],
+    taker: &Pubkey,
+) -> Result<()> {
+    if !escrow.has_flag(Escrow::FLAG_REGION_POLICY) {
+        return Ok(());
+    }
+    let policy = policy.ok_or(EscrowError::InvalidAttestationRequirements)?;
+    let now = clock::now()?;
+    for info in attestations {
+        let attestation = match read_attestation(info)? {
+            Some(attestation) => attestation,
+            None => continue,
+        };
+        if attestation.schema != policy.schema
+            || attestation.credential != policy.credential
+            || attestation.nonce != *taker
+            || (attestation.expiry != 0 && attestation.expiry <= now)
+        {
+            continue;
+        }
+        let region: [u8; 2] = attestation
+            .data
+            .as_slice()
+            .try_into()
+            .map_err(|_| EscrowError::AttestationMissing)?;
+        require!(policy.permits(region), EscrowError::RegionNotPermitted);
+        return Ok(());
+    }
+    err!(EscrowError::AttestationMissing)
+}
+
+// Paying `escrow` out needs the desk's approval while the config requires it,
+// and the offer's own compliance key's whenever it has one, both given for
+// the current taker
+pub fn require_compliance_approval(
+    config: &Config,
+    escrow: &Escrow,
+    approval: Option<&Account<ComplianceApproval>>,
+) -> Result<()> {
+    let offer_required = escrow.has_compliance_signer();
+    if !config.compliance_required && !offer_required {
+        return Ok(());
+    }
+    let approval = approval.ok_or(EscrowError::ComplianceApprovalMissing)?;
+    require!(
+        approval.taker == escrow.taker
+            && (approval.desk_approved || !config.compliance_required)
+            && (approval.offer_approved || !offer_required),
+        EscrowError::ComplianceApprovalMissing
+    );
+    Ok(())
+}
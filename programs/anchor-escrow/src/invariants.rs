@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::errors::EscrowError;
+use crate::states::{Escrow, EscrowStatus};
+
+// Checked at the end of the instructions that move an escrow's funds or
+// status, in release builds too, so a logic bug aborts the transaction instead
+// of leaving behind an escrow its vault can't pay out.
+
+// The vault has to cover what the escrow still owes. It may hold more, as
+// anyone can send tokens to an associated token account.
+pub fn require_vault_backs_escrow(
+    escrow: &Escrow,
+    vault: &mut InterfaceAccount<TokenAccount>,
+) -> Result<()> {
+    vault.reload()?;
+    require!(
+        vault.amount >= escrow.vault_owed()?,
+        EscrowError::VaultUnderfunded
+    );
+    Ok(())
+}
+
+// What a deposit of `sent` actually added to a vault that held `before`, which
+// becomes the escrowed amount: Token-2022 transfer fees make it less than what
+// was sent. Nothing arriving, or more than was sent, means a transfer hook is
+// moving the vault's balance and the deposit is refused.
+pub fn require_deposit_received(
+    vault: &mut InterfaceAccount<TokenAccount>,
+    before: u64,
+    sent: u64,
+) -> Result<u64> {
+    vault.reload()?;
+    deposit_received(before, vault.amount, sent)
+}
+
+pub fn deposit_received(before: u64, after: u64, sent: u64) -> Result<u64> {
+    let received = after
+        .checked_sub(before)
+        .ok_or(EscrowError::DepositMismatch)?;
+    require!(
+        received > 0 && received <= sent,
+        EscrowError::DepositMismatch
+    );
+    Ok(received)
+}
+
+// Status only moves forward, one step at a time: Created, Funded, Releasing
+pub fn require_status_transition(before: u8, after: &Escrow) -> Result<()> {
+    let legal = before == after.status
+        || (before == EscrowStatus::Created as u8 && after.status == EscrowStatus::Funded as u8)
+        || (before == EscrowStatus::Funded as u8 && after.status == EscrowStatus::Releasing as u8);
+    require!(legal, EscrowError::IllegalStatusTransition);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deposit_escrows_what_the_vault_received() {
+        assert_eq!(deposit_received(0, 100, 100).unwrap(), 100);
+        // A 1% transfer fee leaves less in the vault than was sent
+        assert_eq!(deposit_received(5, 104, 100).unwrap(), 99);
+        assert!(deposit_received(5, 5, 100).is_err());
+        assert!(deposit_received(0, 101, 100).is_err());
+        assert!(deposit_received(5, 4, 100).is_err());
+    }
+}
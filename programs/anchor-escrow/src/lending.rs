@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+// Instructions a yield adapter program must expose, Anchor-style:
+// `deposit(amount: u64)` lends `amount` out of the token account into the
+// protocol, `withdraw()` redeems the owner's whole position back into it.
+// Both take the owner (the escrow PDA, signing), its token account, the mint
+// and the token program, followed by whatever accounts the protocol needs.
+// The adapter picks the venue by mint: for the native mint that is a liquid
+// staking pool (e.g. Marinade), which it deposits the unwrapped SOL into and
+// liquid-unstakes and re-wraps on withdrawal.
+const DEPOSIT_IX: &[u8] = b"global:deposit";
+const WITHDRAW_IX: &[u8] = b"global:withdraw";
+
+pub struct AdapterAccounts<'a, 'info> {
+    pub adapter: &'a AccountInfo<'info>,
+    pub owner: &'a AccountInfo<'info>,
+    pub token_account: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub token_program: &'a AccountInfo<'info>,
+    pub protocol_accounts: &'a [AccountInfo<'info>],
+}
+
+pub fn deposit_to_adapter(
+    accounts: &AdapterAccounts,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = hash(DEPOSIT_IX).to_bytes()[..8].to_vec();
+    amount.serialize(&mut data)?;
+    invoke_adapter(accounts, data, signer_seeds)
+}
+
+pub fn withdraw_from_adapter(accounts: &AdapterAccounts, signer_seeds: &[&[&[u8]]]) -> Result<()> {
+    let data = hash(WITHDRAW_IX).to_bytes()[..8].to_vec();
+    invoke_adapter(accounts, data, signer_seeds)
+}
+
+fn invoke_adapter(
+    accounts: &AdapterAccounts,
+    data: Vec<u8>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut metas = vec![
+        AccountMeta::new_readonly(accounts.owner.key(), true),
+        AccountMeta::new(accounts.token_account.key(), false),
+        AccountMeta::new_readonly(accounts.mint.key(), false),
+        AccountMeta::new_readonly(accounts.token_program.key(), false),
+    ];
+    metas.extend(
+        accounts
+            .protocol_accounts
+            .iter()
+            .map(|account| AccountMeta {
+                pubkey: account.key(),
+                is_signer: account.is_signer,
+                is_writable: account.is_writable,
+            }),
+    );
+    let mut infos = vec![
+        accounts.owner.clone(),
+        accounts.token_account.clone(),
+        accounts.mint.clone(),
+        accounts.token_program.clone(),
+    ];
+    infos.extend_from_slice(accounts.protocol_accounts);
+
+    invoke_signed(
+        &Instruction {
+            program_id: accounts.adapter.key(),
+            accounts: metas,
+            data,
+        },
+        &infos,
+        signer_seeds,
+    )?;
+    Ok(())
+}
@@ -1,34 +1,976 @@
+// The IDL resize handler #[program] generates calls AccountInfo::realloc,
+// deprecated since solana-account-info 2.3. It is emitted at the crate root,
+// outside `mod anchor_escrow`, so the allow can't be scoped any narrower.
+#![allow(deprecated)]
 use anchor_lang::prelude::*;
 mod contexts;
 use contexts::*;
-mod states;
-mod constants;
+pub mod states;
+pub mod constants;
+pub mod errors;
+mod events;
+mod fees;
+mod gating;
+mod audit;
+mod sigverify;
+mod memo;
+mod wormhole;
+mod payment_proof;
+mod delegation;
+mod pricing;
+mod swap;
+mod lending;
+mod payout;
+mod clock;
+mod invariants;
+pub use contexts::{CategoryFee, ConfigUpdate};
+use fees::{Quote, SettlementSplit};
+use states::{FiatQuote, PartialPayments, TemplateTerms};
 
+#[cfg(not(feature = "localnet"))]
 declare_id!("Bua4jWEfUYb3QcaWnfJEbG4KKv6C1SqJSGFr5KCntZDW");
+// Local validator deploys use the id from the README walkthrough
+#[cfg(feature = "localnet")]
+declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 #[program]
 pub mod anchor_escrow {
     use super::*;
 
+    // `terms_hash` commits both parties to the off-chain trade agreement; zeroes if none
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize(
         ctx: Context<Initialize>,
         seed: u64,
         initializer_amount: u64,
         taker_amount: u64,
+        terms_hash: [u8; 32],
+        tags: u32,
+        category: u8,
+        note: [u8; 64],
+        idempotency_key: [u8; 16],
     ) -> Result<()> {
-        ctx.accounts
-            .initialize_escrow(seed, &ctx.bumps, initializer_amount, taker_amount)?;
+        ctx.accounts.initialize_escrow(
+            seed,
+            &ctx.bumps,
+            initializer_amount,
+            taker_amount,
+            terms_hash,
+            tags,
+            category,
+            note,
+            idempotency_key,
+        )?;
         ctx.accounts.deposit(initializer_amount)
     }
 
+    // `initialize` at the maker's next sequential address instead of a chosen seed
+    pub fn initialize_sequential(
+        ctx: Context<InitializeSequential>,
+        initializer_amount: u64,
+        taker_amount: u64,
+        terms_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.initialize_sequential(
+            &ctx.bumps,
+            initializer_amount,
+            taker_amount,
+            terms_hash,
+        )
+    }
+
+    // Relayed `initialize` from the maker's signed permit; see InitializeWithPermit
+    pub fn initialize_with_permit(
+        ctx: Context<InitializeWithPermit>,
+        seed: u64,
+        initializer_amount: u64,
+        taker_amount: u64,
+        terms_hash: [u8; 32],
+        valid_until: i64,
+        nonce: u64,
+    ) -> Result<()> {
+        ctx.accounts.initialize_with_permit(
+            &ctx.bumps,
+            seed,
+            initializer_amount,
+            taker_amount,
+            terms_hash,
+            valid_until,
+            nonce,
+        )
+    }
+
+    // Non-custodial `initialize`: delegates initializer_amount to the escrow PDA
+    // instead of depositing it
+    pub fn initialize_delegated(
+        ctx: Context<InitializeDelegated>,
+        seed: u64,
+        initializer_amount: u64,
+        taker_amount: u64,
+        terms_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.initialize_delegated(
+            seed,
+            &ctx.bumps,
+            initializer_amount,
+            taker_amount,
+            terms_hash,
+        )
+    }
+
+    // Settles a delegated escrow by pulling from the maker's ATA
+    pub fn exchange_delegated(ctx: Context<ExchangeDelegated>) -> Result<()> {
+        ctx.accounts.exchange_delegated(&ctx.bumps)
+    }
+
+    // Permissionless: closes a delegated offer that lost its backing before payment
+    pub fn invalidate_delegated(ctx: Context<InvalidateDelegated>) -> Result<()> {
+        ctx.accounts.invalidate_delegated()
+    }
+
+    // Posts and funds a new escrow under `seed` with the terms of `source`
+    pub fn relist_escrow(ctx: Context<RelistEscrow>, seed: u64) -> Result<()> {
+        ctx.accounts.relist_escrow(seed, &ctx.bumps)
+    }
+
+    // Saves default terms for a mint pair; reusing an id overwrites them
+    pub fn set_template(
+        ctx: Context<SetTemplate>,
+        template_id: u16,
+        terms: TemplateTerms,
+    ) -> Result<()> {
+        ctx.accounts.set_template(&ctx.bumps, template_id, terms)
+    }
+
+    pub fn close_template(ctx: Context<CloseTemplate>) -> Result<()> {
+        ctx.accounts.close_template()
+    }
+
+    // `initialize` priced and configured by the maker's template
+    pub fn initialize_from_template(
+        ctx: Context<InitializeFromTemplate>,
+        seed: u64,
+        initializer_amount: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .initialize_from_template(seed, &ctx.bumps, initializer_amount)
+    }
+
+    // Prefunds `runs` escrows to a trusted taker, posted one per `interval`
+    pub fn create_recurring(
+        ctx: Context<CreateRecurring>,
+        schedule_id: u16,
+        terms: RecurringTerms,
+    ) -> Result<()> {
+        ctx.accounts
+            .create_recurring(&ctx.bumps, schedule_id, terms)
+    }
+
+    // Permissionless keeper crank: posts the schedule's next escrow once due
+    pub fn spawn_recurring(ctx: Context<SpawnRecurring>) -> Result<()> {
+        ctx.accounts.spawn_recurring(&ctx.bumps)
+    }
+
+    // Stops the schedule and refunds the runs not yet posted
+    pub fn cancel_recurring(ctx: Context<CancelRecurring>) -> Result<()> {
+        ctx.accounts.cancel_recurring()
+    }
+
+    // Two-phase alternative to `initialize`: state only, funded later by `fund_escrow`
+    pub fn create_escrow(
+        ctx: Context<CreateEscrow>,
+        seed: u64,
+        initializer_amount: u64,
+        taker_amount: u64,
+        terms_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.create_escrow(
+            seed,
+            &ctx.bumps,
+            initializer_amount,
+            taker_amount,
+            terms_hash,
+        )
+    }
+
+    // Deposits initializer_amount from any funder wallet into a created escrow
+    pub fn fund_escrow(ctx: Context<FundEscrow>) -> Result<()> {
+        ctx.accounts.fund_escrow()
+    }
+
+    // Closes a created escrow that was never funded
+    pub fn abort_escrow(ctx: Context<AbortEscrow>) -> Result<()> {
+        ctx.accounts.abort_escrow()
+    }
+
+    // Adds inventory to an open escrow, keeping its unit price
+    pub fn increase_amount(ctx: Context<IncreaseAmount>, amount: u64) -> Result<()> {
+        ctx.accounts.increase_amount(amount)
+    }
+
+    // Withdraws part of an open escrow's inventory back to the maker, keeping its unit price
+    pub fn decrease_amount(ctx: Context<DecreaseAmount>, amount: u64) -> Result<()> {
+        ctx.accounts.decrease_amount(amount)
+    }
+
+    // Changes the price of an escrow nobody has paid for yet
+    pub fn reprice(ctx: Context<Reprice>, taker_amount: u64) -> Result<()> {
+        ctx.accounts.reprice(taker_amount)
+    }
+
+    // Posts a ladder of escrows for the same mint pair; remaining accounts are (escrow, vault) pairs
+    pub fn initialize_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, InitializeMany<'info>>,
+        terms: Vec<EscrowTerms>,
+    ) -> Result<()> {
+        ctx.accounts
+            .initialize_many(&ctx.bumps, &terms, ctx.remaining_accounts)
+    }
+
     pub fn cancel(ctx: Context<Cancel>) -> Result<()> {
-        ctx.accounts.refund_and_close_vault()
+        ctx.accounts.refund_and_close_vault(&ctx.bumps)
+    }
+
+    // Refunds and closes several of the maker's escrows; remaining accounts are (escrow, vault) pairs
+    pub fn cancel_many<'info>(ctx: Context<'_, '_, 'info, 'info, CancelMany<'info>>) -> Result<()> {
+        ctx.accounts.refund_and_close_vaults(ctx.remaining_accounts)
+    }
+
+    // Stakes the maker deposit the config requires before an open offer can be taken
+    pub fn post_maker_deposit(ctx: Context<PostMakerDeposit>) -> Result<()> {
+        ctx.accounts.post_maker_deposit(&ctx.bumps)
+    }
+
+    // Returns a maker deposit once its escrow is gone, or pays a forfeited one to the taker
+    pub fn refund_maker_deposit(ctx: Context<RefundMakerDeposit>) -> Result<()> {
+        ctx.accounts.refund_maker_deposit()
+    }
+
+    // Admin sets the SAS schemas every taker must be attested for; empty turns it off
+    pub fn set_global_attestations(
+        ctx: Context<SetGlobalAttestations>,
+        credential: Pubkey,
+        schemas: Vec<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_global_attestations(&ctx.bumps, credential, schemas)
+    }
+
+    // Maker adds SAS schemas takers of this offer must be attested for
+    pub fn set_offer_attestations(
+        ctx: Context<SetOfferAttestations>,
+        credential: Pubkey,
+        schemas: Vec<Pubkey>,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_offer_attestations(&ctx.bumps, credential, schemas)
+    }
+
+    // Maker restricts the offer to (or away from) ISO 3166-1 alpha-2 region codes
+    pub fn set_offer_regions(
+        ctx: Context<SetOfferRegions>,
+        credential: Pubkey,
+        schema: Pubkey,
+        allow_list: bool,
+        codes: Vec<[u8; 2]>,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_offer_regions(&ctx.bumps, credential, schema, allow_list, codes)
+    }
+
+    // Claims an open escrow for the signing taker
+    pub fn accept_escrow(ctx: Context<AcceptEscrow>) -> Result<()> {
+        ctx.accounts.accept_escrow(&ctx.bumps, ctx.remaining_accounts)
+    }
+
+    // Claims an offer with a hidden price, revealing the price and salt the maker shared off-chain
+    pub fn accept_hidden_offer(
+        ctx: Context<AcceptEscrow>,
+        taker_amount: u64,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.reveal_price(taker_amount, salt)?;
+        ctx.accounts.accept_escrow(&ctx.bumps, ctx.remaining_accounts)
+    }
+
+    // Claims an offer priced in several currencies, paying in `currency` (ISO 4217 numeric)
+    pub fn accept_in_currency(ctx: Context<AcceptEscrow>, currency: u16) -> Result<()> {
+        ctx.accounts.select_currency(currency)?;
+        ctx.accounts.accept_escrow(&ctx.bumps, ctx.remaining_accounts)
+    }
+
+    // Switches the designated taker, or reopens the offer with Pubkey::default()
+    pub fn reassign_taker(ctx: Context<ReassignTaker>, new_taker: Pubkey) -> Result<()> {
+        ctx.accounts.reassign_taker(new_taker)
+    }
+
+    // Asks takers to lock collateral before they can confirm payment; mint is default for SOL
+    pub fn require_bond(ctx: Context<RequireBond>, mint: Pubkey, amount: u64) -> Result<()> {
+        ctx.accounts.require_bond(&ctx.bumps, mint, amount)
+    }
+
+    // Taker locks the required bond, accepting the escrow if it was still open
+    pub fn post_bond(ctx: Context<PostBond>) -> Result<()> {
+        ctx.accounts.post_bond(&ctx.bumps, ctx.remaining_accounts)
+    }
+
+    // Permissionless: drops closed escrows, passed as remaining accounts, from a maker's registry
+    pub fn prune_registry(ctx: Context<PruneRegistry>) -> Result<()> {
+        ctx.accounts.prune_registry(ctx.remaining_accounts)
+    }
+
+    // Permissionless: closes a settled escrow's leftover auxiliary PDAs, passed as remaining accounts
+    pub fn gc_escrow<'info>(ctx: Context<'_, '_, 'info, 'info, GcEscrow<'info>>) -> Result<()> {
+        ctx.accounts.gc_escrow(ctx.remaining_accounts)
+    }
+
+    // Pays out a bond once its escrow is gone: to the taker, or the maker if forfeited
+    pub fn release_bond(ctx: Context<ReleaseBond>) -> Result<()> {
+        ctx.accounts.release_bond()
+    }
+
+    // Maker points the taker at their encrypted payment details, committed to by hash
+    pub fn set_payment_details(
+        ctx: Context<SetPaymentDetails>,
+        uri: String,
+        details_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts
+            .set_payment_details(&ctx.bumps, uri, details_hash)
+    }
+
+    // Either party commits to the Merkle root of their encrypted trade chat so far
+    pub fn anchor_message_root(
+        ctx: Context<AnchorMessageRoot>,
+        root: [u8; 32],
+        message_count: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .anchor_message_root(&ctx.bumps, root, message_count)
     }
 
     pub fn confirm_payment(ctx: Context<ConfirmPayment>) -> Result<()> {
-        ctx.accounts.confirm_payment()
+        ctx.accounts.confirm_payment(&ctx.bumps, ctx.remaining_accounts)
+    }
+
+    // confirm_payment carrying the bank reference, which an earlier Memo instruction repeats
+    pub fn confirm_payment_with_reference(
+        ctx: Context<ConfirmPayment>,
+        reference: String,
+    ) -> Result<()> {
+        ctx.accounts.confirm_payment_with_reference(&ctx.bumps, ctx.remaining_accounts, reference)
+    }
+
+    // Confirms one fiat transfer of `amount` toward taker_amount, for offers taking partial payments
+    pub fn confirm_partial_payment(ctx: Context<ConfirmPayment>, amount: u64) -> Result<()> {
+        ctx.accounts
+            .confirm_partial_payment(&ctx.bumps, ctx.remaining_accounts, amount)
+    }
+
+    // Seller pays out the mint_a for the tranches confirmed so far
+    pub fn release_tranche(ctx: Context<ReleaseTranche>) -> Result<()> {
+        ctx.accounts.release_tranche(&ctx.bumps)
+    }
+
+    // Lets a session key or ops bot confirm payments for the signer
+    pub fn set_payment_delegate(ctx: Context<SetPaymentDelegate>, delegate: Pubkey) -> Result<()> {
+        ctx.accounts.set_payment_delegate(&ctx.bumps, delegate)
+    }
+
+    pub fn revoke_payment_delegate(ctx: Context<RevokePaymentDelegate>) -> Result<()> {
+        ctx.accounts.revoke_payment_delegate()
+    }
+
+    // `confirm_payment` by the taker's delegate, for escrows the taker already took
+    pub fn delegate_confirm_payment(ctx: Context<DelegateConfirmPayment>) -> Result<()> {
+        ctx.accounts.delegate_confirm_payment()
+    }
+
+    // Lets a hot key run the scoped maker instructions (SCOPE_* of MakerSession) until expires_at
+    pub fn create_session(
+        ctx: Context<CreateSession>,
+        session_key: Pubkey,
+        expires_at: i64,
+        scopes: u8,
+    ) -> Result<()> {
+        ctx.accounts
+            .create_session(&ctx.bumps, session_key, expires_at, scopes)
+    }
+
+    pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+        ctx.accounts.revoke_session()
+    }
+
+    // Admin names the program that checks fiat payment proofs; default turns it off
+    pub fn set_payment_verifier(ctx: Context<SetPaymentVerifier>, program: Pubkey) -> Result<()> {
+        ctx.accounts.set_payment_verifier(&ctx.bumps, program)
+    }
+
+    // Admin names the aggregator `exchange_and_swap` routes payouts through; default disables
+    pub fn set_swap_router(ctx: Context<SetSwapRouter>, program: Pubkey) -> Result<()> {
+        ctx.accounts.set_swap_router(&ctx.bumps, program)
+    }
+
+    // Admin registers or updates a white-label partner's fees, fee wallet and branding
+    pub fn set_partner_config(
+        ctx: Context<SetPartnerConfig>,
+        partner_id: u16,
+        fee_bps: u16,
+        fee_wallet: Pubkey,
+        branding_id: [u8; 32],
+        revenue_share_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts.set_partner_config(
+            &ctx.bumps,
+            partner_id,
+            fee_bps,
+            fee_wallet,
+            branding_id,
+            revenue_share_bps,
+        )
+    }
+
+    // Admin sets the mint fees are consolidated into and the keeper allowed to convert them
+    pub fn set_fee_converter(ctx: Context<SetFeeConverter>, keeper: Pubkey) -> Result<()> {
+        ctx.accounts.set_fee_converter(&ctx.bumps, keeper)
+    }
+
+    // Admin names the lending adapter idle vaults can be deposited through
+    pub fn set_yield_adapter(ctx: Context<SetYieldAdapter>, program: Pubkey) -> Result<()> {
+        ctx.accounts.set_yield_adapter(&ctx.bumps, program)
+    }
+
+    // Maker lends an open offer's vault out; remaining accounts go to the adapter
+    pub fn deposit_to_yield<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DepositToYield<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.deposit_to_yield(ctx.remaining_accounts)
+    }
+
+    // Share of the maker's yield, e.g. from staked SOL, the offer passes on to its taker
+    pub fn set_yield_split(ctx: Context<SetYieldSplit>, taker_share_bps: u16) -> Result<()> {
+        ctx.accounts.set_yield_split(taker_share_bps)
+    }
+
+    // Maker names a compliance key that must also approve this offer's release
+    pub fn set_offer_compliance(ctx: Context<SetOfferCompliance>, signer: Pubkey) -> Result<()> {
+        ctx.accounts.set_offer_compliance(signer)
+    }
+
+    // The compliance desk or the offer's compliance key signs off on paying out to the taker
+    pub fn approve_release(ctx: Context<ApproveRelease>) -> Result<()> {
+        ctx.accounts.approve_release(&ctx.bumps)
+    }
+
+    // Permissionless: brings a lent-out vault back and splits the yield
+    pub fn withdraw_from_yield<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawFromYield<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.withdraw_from_yield(ctx.remaining_accounts)
+    }
+
+    // Maker takes back a position the venue lost part of, shrinking the escrow to it
+    pub fn write_off_yield<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WriteOffYield<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.write_off_yield(ctx.remaining_accounts)
+    }
+
+    // Admin or keeper swaps platform fees in `mint_in` into the settlement
+    // mint; remaining accounts are the router's route
+    pub fn convert_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ConvertFees<'info>>,
+        amount: u64,
+        min_out: u64,
+        route_data: Vec<u8>,
+    ) -> Result<()> {
+        ctx.accounts
+            .convert_fees(amount, min_out, route_data, ctx.remaining_accounts)
+    }
+
+    // Maker opts the offer in to payment confirmation by the configured verifier
+    pub fn set_accept_payment_proof(ctx: Context<SetAcceptPaymentProof>, accept: bool) -> Result<()> {
+        ctx.accounts.set_accept_payment_proof(accept)
+    }
+
+    // Maker lets the taker pay across several transfers, released per tranche or at the end
+    pub fn set_partial_payments(
+        ctx: Context<SetPartialPayments>,
+        policy: PartialPayments,
+    ) -> Result<()> {
+        ctx.accounts.set_partial_payments(policy)
+    }
+
+    // Maker requires confirm_payment_with_reference, so every payment carries its bank reference
+    pub fn set_payment_memo_required(
+        ctx: Context<SetPaymentMemoRequired>,
+        required: bool,
+    ) -> Result<()> {
+        ctx.accounts.set_payment_memo_required(required)
+    }
+
+    // Confirms payment from a proof the verifier accepts; remaining accounts go to the verifier
+    pub fn verify_payment_proof<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VerifyPaymentProof<'info>>,
+        proof: Vec<u8>,
+    ) -> Result<()> {
+        ctx.accounts
+            .verify_payment_proof(proof, ctx.remaining_accounts)
     }
 
     pub fn exchange(ctx: Context<Exchange>) -> Result<()> {
-        ctx.accounts.withdraw_and_close_vault()
+        ctx.accounts.withdraw_and_close_vault(&ctx.bumps)
+    }
+
+    // Taker pledges part of their payout to a listed charity, paid by `exchange`
+    pub fn pledge_donation(
+        ctx: Context<PledgeDonation>,
+        donation_bps: u16,
+        round_up: bool,
+    ) -> Result<()> {
+        ctx.accounts
+            .pledge_donation(&ctx.bumps, donation_bps, round_up)
+    }
+
+    // Either party drops a settled trade's receipt; the rent goes back to whoever paid it
+    pub fn close_receipt(ctx: Context<CloseReceipt>) -> Result<()> {
+        ctx.accounts.close_receipt()
+    }
+
+    // Mints each party of a receipted trade a non-transferable completion badge
+    pub fn mint_badges(ctx: Context<MintBadges>) -> Result<()> {
+        ctx.accounts.mint_badges(&ctx.bumps)
+    }
+
+    // Posts a receipted trade to Wormhole so contracts on other chains can verify it settled
+    pub fn publish_settlement(ctx: Context<PublishSettlement>) -> Result<()> {
+        ctx.accounts.publish_settlement(&ctx.bumps)
+    }
+
+    // `exchange` submitted by a relayer, approved by the initializer's Ed25519
+    // signature over (escrow, taker, initializer_amount, valid_until, nonce)
+    pub fn release_with_signature(
+        ctx: Context<Exchange>,
+        valid_until: i64,
+        nonce: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .release_with_signature(&ctx.bumps, valid_until, nonce)
+    }
+
+    // Names an Ethereum address that may approve releases with a secp256k1
+    // signature instead; all zeroes removes it
+    pub fn set_evm_approver(ctx: Context<SetEvmApprover>, evm_approver: [u8; 20]) -> Result<()> {
+        ctx.accounts.set_evm_approver(evm_approver)
+    }
+
+    // `release_with_signature` approved by the escrow's EVM approver
+    pub fn release_with_evm_signature(
+        ctx: Context<Exchange>,
+        valid_until: i64,
+        nonce: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .release_with_evm_signature(&ctx.bumps, valid_until, nonce)
+    }
+
+    // `exchange` paying the taker in another mint: the payout is swapped by
+    // the configured router along `route_data`, with the route's accounts as
+    // remaining accounts, and the taker must end up with at least `min_out`
+    pub fn exchange_and_swap<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Exchange<'info>>,
+        min_out: u64,
+        route_data: Vec<u8>,
+    ) -> Result<()> {
+        ctx.accounts
+            .release_and_swap(&ctx.bumps, min_out, route_data, ctx.remaining_accounts)
+    }
+
+    // Puts an escrow on a linear vesting schedule (start, cliff, duration in seconds)
+    pub fn set_release_schedule(
+        ctx: Context<SetReleaseSchedule>,
+        start: i64,
+        cliff: i64,
+        duration: i64,
+    ) -> Result<()> {
+        ctx.accounts.set_release_schedule(start, cliff, duration)
+    }
+
+    // Streams the escrow to the taker over `duration` seconds once payment is confirmed
+    pub fn set_stream(ctx: Context<SetReleaseSchedule>, duration: i64) -> Result<()> {
+        ctx.accounts.set_stream(duration)
+    }
+
+    // Draws whatever has streamed since the last claim
+    pub fn claim_stream(ctx: Context<ClaimStream>) -> Result<()> {
+        ctx.accounts.claim_stream(&ctx.bumps)
+    }
+
+    // Seller releases a confirmed vesting escrow; the taker then claims over time
+    pub fn start_vesting(ctx: Context<StartVesting>) -> Result<()> {
+        ctx.accounts.start_vesting(&ctx.bumps)
+    }
+
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        ctx.accounts.claim_vested()
+    }
+
+    // Names a neutral arbiter for milestone sign-off; Pubkey::default() removes it
+    pub fn set_arbiter(ctx: Context<SetArbiter>, arbiter: Pubkey) -> Result<()> {
+        ctx.accounts.set_arbiter(arbiter)
+    }
+
+    // Names the registered arbitrator best matching the offer's regions and `language`
+    pub fn assign_arbiter<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AssignArbiter<'info>>,
+        language: [u8; 2],
+    ) -> Result<()> {
+        ctx.accounts.assign_arbiter(ctx.remaining_accounts, language)
+    }
+
+    // Registered arbitrators lock the policy's stake mint to become eligible for cases
+    pub fn stake_arbitrator(ctx: Context<StakeArbitrator>, amount: u64) -> Result<()> {
+        ctx.accounts.stake_arbitrator(amount)
+    }
+
+    // Leaves the assignment pool; the stake unlocks after one appeal window
+    pub fn request_unstake(ctx: Context<RequestUnstake>) -> Result<()> {
+        ctx.accounts.request_unstake()
+    }
+
+    // Returns stake to the arbitrator once their unstake has unlocked
+    pub fn withdraw_stake(ctx: Context<WithdrawStake>, amount: u64) -> Result<()> {
+        ctx.accounts.withdraw_stake(amount)
+    }
+
+    // Pays an arbitrator's accrued per-case rewards from the reward pool
+    pub fn claim_arbitration_rewards(ctx: Context<ClaimArbitrationRewards>) -> Result<()> {
+        ctx.accounts.claim_arbitration_rewards()
+    }
+
+    // Appends a tranche; milestone amounts must add up to initializer_amount
+    pub fn add_milestone(
+        ctx: Context<AddMilestone>,
+        amount: u64,
+        requires_arbiter: bool,
+    ) -> Result<()> {
+        ctx.accounts
+            .add_milestone(&ctx.bumps, amount, requires_arbiter)
+    }
+
+    pub fn confirm_milestone(ctx: Context<ConfirmMilestone>) -> Result<()> {
+        ctx.accounts.confirm_milestone()
+    }
+
+    // Pays a confirmed tranche to the taker; the last one closes the escrow
+    pub fn release_milestone(ctx: Context<ReleaseMilestone>) -> Result<()> {
+        ctx.accounts.release_milestone(&ctx.bumps)
+    }
+
+    // Either party escalates a tranche to the escrow's arbiter
+    pub fn dispute_milestone(ctx: Context<DisputeMilestone>, reason_hash: [u8; 32]) -> Result<()> {
+        ctx.accounts.dispute_milestone(&ctx.bumps, reason_hash)
+    }
+
+    // Arbiter pays the disputed tranche to the taker or refunds it to the initializer
+    pub fn resolve_milestone_dispute(
+        ctx: Context<ResolveMilestoneDispute>,
+        release_to_taker: bool,
+    ) -> Result<()> {
+        ctx.accounts
+            .resolve_milestone_dispute(&ctx.bumps, release_to_taker)
+    }
+
+    // Hands a disputed milestone to a jury of registered arbitrators
+    pub fn convene_jury<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ConveneJury<'info>>,
+        commit_period: i64,
+        reveal_period: i64,
+    ) -> Result<()> {
+        ctx.accounts.convene_jury(
+            &ctx.bumps,
+            ctx.remaining_accounts,
+            commit_period,
+            reveal_period,
+        )
+    }
+
+    // A juror commits to a hidden vote on whether the disputed tranche goes to the taker
+    pub fn commit_jury_vote(ctx: Context<CommitJuryVote>, commitment: [u8; 32]) -> Result<()> {
+        ctx.accounts.commit_jury_vote(&ctx.bumps, commitment)
+    }
+
+    // Opens a committed vote once the commit phase is over
+    pub fn reveal_jury_vote(
+        ctx: Context<RevealJuryVote>,
+        release_to_taker: bool,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.reveal_jury_vote(release_to_taker, salt)
+    }
+
+    // Permissionless: slashes a juror who committed but never revealed
+    pub fn penalize_juror(ctx: Context<PenalizeJuror>) -> Result<()> {
+        ctx.accounts.penalize_juror()
+    }
+
+    // Closes a jury that ended without a verdict, returning the dispute to the arbiter
+    pub fn dissolve_jury(ctx: Context<DissolveJury>) -> Result<()> {
+        ctx.accounts.dissolve_jury(&ctx.bumps)
+    }
+
+    // Executes the jury's majority verdict on a disputed milestone
+    pub fn tally_and_resolve(ctx: Context<ResolveMilestoneDispute>) -> Result<()> {
+        ctx.accounts.tally_and_resolve(&ctx.bumps)
+    }
+
+    // Either party takes an open milestone dispute to the DAO
+    pub fn escalate_dispute(ctx: Context<EscalateDispute>, proposal: Pubkey) -> Result<()> {
+        ctx.accounts.escalate_dispute(&ctx.bumps, proposal)
+    }
+
+    // The governance authority rules on an escalated dispute through a passed proposal
+    pub fn governance_resolve(
+        ctx: Context<ResolveMilestoneDispute>,
+        release_to_taker: bool,
+    ) -> Result<()> {
+        ctx.accounts
+            .governance_resolve(&ctx.bumps, release_to_taker)
+    }
+
+    // One-time setup of the protocol config; signer must be the program's upgrade authority
+    pub fn initialize_config(ctx: Context<InitializeConfig>, insurance_fee_bps: u16) -> Result<()> {
+        ctx.accounts
+            .initialize_config(&ctx.bumps, insurance_fee_bps)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, update: ConfigUpdate) -> Result<()> {
+        ctx.accounts.update_config(&ctx.bumps, update)
+    }
+
+    // Admin compensates the victim of a resolved fraud dispute out of the insurance fund
+    pub fn pay_claim(ctx: Context<PayClaim>, escrow: Pubkey, amount: u64) -> Result<()> {
+        ctx.accounts.pay_claim(&ctx.bumps, escrow, amount)
+    }
+
+    // Maker withdraws the rebates their filled offers have accrued in one mint
+    pub fn claim_maker_rebate(ctx: Context<ClaimMakerRebate>) -> Result<()> {
+        ctx.accounts.claim_maker_rebate(&ctx.bumps)
+    }
+
+    // Partner's fee wallet withdraws its accrued share of the platform fee in one mint
+    pub fn claim_partner_fees(ctx: Context<ClaimPartnerFees>) -> Result<()> {
+        ctx.accounts.claim_partner_fees(&ctx.bumps)
+    }
+
+    // Admin bars a wallet from creating, taking or receiving escrows
+    pub fn block_wallet(
+        ctx: Context<BlockWallet>,
+        wallet: Pubkey,
+        reason_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.block_wallet(&ctx.bumps, wallet, reason_hash)
+    }
+
+    pub fn unblock_wallet(ctx: Context<UnblockWallet>) -> Result<()> {
+        ctx.accounts.unblock_wallet(&ctx.bumps)
+    }
+
+    // Admin scales a wallet's volume caps, e.g. after a higher KYC tier
+    pub fn set_volume_tier(ctx: Context<SetVolumeTier>, wallet: Pubkey, tier: u8) -> Result<()> {
+        ctx.accounts.set_volume_tier(&ctx.bumps, wallet, tier)
+    }
+
+    // Offers still unconfirmed at `expires_at` can be reaped by anyone; zero removes the deadline
+    pub fn set_expiry(ctx: Context<SetExpiry>, expires_at: i64) -> Result<()> {
+        ctx.accounts.set_expiry(expires_at)
+    }
+
+    // Pushes a taken escrow's deadline later, signed by both the maker and the taker
+    pub fn extend_deadline(ctx: Context<ExtendDeadline>, expires_at: i64) -> Result<()> {
+        ctx.accounts.extend_deadline(expires_at)
+    }
+
+    // Permissionless keeper crank: refunds an expired, unconfirmed offer to its maker
+    pub fn reap_expired(ctx: Context<ReapExpired>) -> Result<()> {
+        ctx.accounts.reap_expired()
+    }
+
+    // Permissionless matcher crank: settles two opposing open offers whose prices cross
+    pub fn match_offers(ctx: Context<MatchOffers>) -> Result<()> {
+        ctx.accounts.match_offers()
+    }
+
+    // Permissionless keeper crank: pays whatever has vested to the taker
+    pub fn auto_release(ctx: Context<AutoRelease>) -> Result<()> {
+        ctx.accounts.auto_release()
+    }
+
+    // Seconds the seller gets to release after payment confirmation; zero uses the config default
+    pub fn set_auto_release(ctx: Context<SetAutoRelease>, auto_release_after: i64) -> Result<()> {
+        ctx.accounts.set_auto_release(auto_release_after)
+    }
+
+    // Records the rate an offer is priced at (the feed's when None) to guard settlement against market moves
+    pub fn set_rate_snapshot(ctx: Context<SetRateSnapshot>, rate: Option<u64>) -> Result<()> {
+        ctx.accounts.set_rate_snapshot(rate)
+    }
+
+    // Both parties re-price an escrow whose rate guard tripped at the feed's current rate
+    pub fn accept_rate_move(ctx: Context<AcceptRateMove>) -> Result<()> {
+        ctx.accounts.accept_rate_move()
+    }
+
+    // Seconds after payment confirmation before the escrow may release, for fraud checks
+    pub fn set_release_delay(ctx: Context<SetReleaseDelay>, release_delay: i64) -> Result<()> {
+        ctx.accounts.set_release_delay(release_delay)
+    }
+
+    // Replaces the maker's free-form reference until a taker takes the offer
+    pub fn set_note(ctx: Context<SetNote>, note: [u8; 64]) -> Result<()> {
+        ctx.accounts.set_note(note)
+    }
+
+    // Replaces an open offer's price with a commitment to it, revealed by the taker on acceptance
+    pub fn hide_price(ctx: Context<HidePrice>, commitment: [u8; 32]) -> Result<()> {
+        ctx.accounts.hide_price(commitment)
+    }
+
+    // Lists the fiat currencies an open offer can be paid in, each with its own price
+    pub fn set_fiat_quotes(ctx: Context<SetFiatQuotes>, quotes: Vec<FiatQuote>) -> Result<()> {
+        ctx.accounts.set_fiat_quotes(&ctx.bumps, quotes)
+    }
+
+    // Voids the signer's outstanding permits and release approvals by skipping their nonce
+    pub fn advance_nonce(ctx: Context<AdvanceNonce>) -> Result<()> {
+        ctx.accounts.advance_nonce(&ctx.bumps)
+    }
+
+    // Permissionless: refunds the rent of an idempotency key that has expired
+    pub fn close_idempotency_record(ctx: Context<CloseIdempotencyRecord>) -> Result<()> {
+        ctx.accounts.close_idempotency_record()
+    }
+
+    // Permissionless: pays the taker once the seller's release window has lapsed
+    pub fn force_release(ctx: Context<ForceRelease>) -> Result<()> {
+        ctx.accounts.force_release(&ctx.bumps)
+    }
+
+    // Admin halts release and cancel on one escrow while a fraud report is reviewed
+    pub fn freeze_escrow(ctx: Context<FreezeEscrow>, reason_hash: [u8; 32]) -> Result<()> {
+        ctx.accounts.freeze_escrow(&ctx.bumps, reason_hash)
+    }
+
+    pub fn unfreeze_escrow(ctx: Context<UnfreezeEscrow>) -> Result<()> {
+        ctx.accounts.unfreeze_escrow(&ctx.bumps)
+    }
+
+    // Upgrades an escrow written by an older program version to the current layout
+    pub fn migrate_escrow(ctx: Context<MigrateEscrow>) -> Result<()> {
+        ctx.accounts.migrate_escrow()
+    }
+
+    // Admin recovery for escrow `seed` when only its vault or only its state survived
+    pub fn close_orphaned_vault(ctx: Context<CloseOrphanedVault>, seed: u64) -> Result<()> {
+        ctx.accounts.close_orphaned_vault(&ctx.bumps, seed)
+    }
+
+    // Names the oracle key that posts the (mint_a, mint_b) market's exchange rate
+    pub fn set_rate_feed(ctx: Context<SetRateFeed>, authority: Pubkey) -> Result<()> {
+        ctx.accounts.set_rate_feed(&ctx.bumps, authority)
+    }
+
+    // Oracle pushes the market's current exchange rate
+    pub fn post_rate(ctx: Context<PostRate>, rate: u64) -> Result<()> {
+        ctx.accounts.post_rate(rate)
+    }
+
+    // Admin lists or delists `mint` as a stablecoin for stablecoin-only mode
+    pub fn set_stablecoin(ctx: Context<SetStablecoin>, mint: Pubkey, listed: bool) -> Result<()> {
+        ctx.accounts.set_stablecoin(&ctx.bumps, mint, listed)
+    }
+
+    // Admin lists or delists a charity wallet takers may pledge donations to
+    pub fn set_charity(ctx: Context<SetCharity>, wallet: Pubkey, listed: bool) -> Result<()> {
+        ctx.accounts.set_charity(&ctx.bumps, wallet, listed)
+    }
+
+    // Sets the compliance desk key that approves releases while the config requires it
+    pub fn set_compliance_desk(ctx: Context<SetComplianceDesk>, signer: Pubkey) -> Result<()> {
+        ctx.accounts.set_compliance_desk(&ctx.bumps, signer)
+    }
+
+    // Registers or updates an arbitrator and the regions and languages they cover
+    pub fn set_arbitrator(
+        ctx: Context<SetArbitrator>,
+        wallet: Pubkey,
+        is_active: bool,
+        regions: Vec<[u8; 2]>,
+        languages: Vec<[u8; 2]>,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_arbitrator(&ctx.bumps, wallet, is_active, regions, languages)
+    }
+
+    // Sets the stake mint, minimum stake, case reward, slash rate and appeal window
+    pub fn set_arbitration_policy(
+        ctx: Context<SetArbitrationPolicy>,
+        stake_mint: Pubkey,
+        min_stake: u64,
+        reward_per_case: u64,
+        slash_bps: u16,
+        appeal_window: i64,
+    ) -> Result<()> {
+        ctx.accounts.set_arbitration_policy(
+            &ctx.bumps,
+            stake_mint,
+            min_stake,
+            reward_per_case,
+            slash_bps,
+            appeal_window,
+        )
+    }
+
+    // Upholds an appeal against a milestone ruling and slashes the arbiter's stake
+    pub fn overturn_ruling(ctx: Context<OverturnRuling>, reason_hash: [u8; 32]) -> Result<()> {
+        ctx.accounts.overturn_ruling(&ctx.bumps, reason_hash)
+    }
+
+    // Names the SPL Governance authority that rules on escalated disputes
+    pub fn set_governance(ctx: Context<SetGovernance>, authority: Pubkey) -> Result<()> {
+        ctx.accounts.set_governance(&ctx.bumps, authority)
+    }
+
+    // Creates the Token-2022 non-transferable mint for trade completion badges
+    pub fn initialize_badge_mint(ctx: Context<InitializeBadgeMint>) -> Result<()> {
+        ctx.accounts.initialize_badge_mint(&ctx.bumps)
+    }
+
+    // Grows a config written by an older program version to the current layout
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        ctx.accounts.migrate_config()
+    }
+
+    // Read-only: simulate to get the pro-rata price and settlement split for `amount` of an escrow
+    pub fn view_quote(ctx: Context<ViewQuote>, amount: u64) -> Result<Quote> {
+        ctx.accounts.view_quote(amount)
+    }
+
+    // Read-only: simulate to get the settlement split `exchange` would make of `amount`
+    pub fn view_fee_breakdown(
+        ctx: Context<ViewFeeBreakdown>,
+        amount: u64,
+    ) -> Result<SettlementSplit> {
+        ctx.accounts.view_fee_breakdown(amount)
+    }
+
+    // Read-only: simulate to get the program's semver and account layout versions
+    pub fn get_version(ctx: Context<GetVersion>) -> Result<ProgramVersion> {
+        ctx.accounts.get_version()
+    }
+
+    // Test builds only: later instructions in the transaction see `unix_timestamp` as the time
+    #[cfg(feature = "test-clock")]
+    pub fn warp_clock(ctx: Context<WarpClock>, unix_timestamp: i64) -> Result<()> {
+        ctx.accounts.warp_clock(unix_timestamp)
     }
 }
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::constants::MEMO_PROGRAM_ID;
+use crate::errors::EscrowError;
+
+// Some instruction before this one in the transaction must be an SPL Memo
+// whose text is exactly `memo`. The bank reference then sits on-chain next
+// to the confirmation for reconciliation tools to match against statements.
+pub fn require_memo(instructions: &AccountInfo, memo: &[u8]) -> Result<()> {
+    let current = load_current_index_checked(instructions)?;
+    for index in 0..current {
+        let ix = load_instruction_at_checked(index as usize, instructions)?;
+        if ix.program_id == MEMO_PROGRAM_ID && ix.data == memo {
+            return Ok(());
+        }
+    }
+    err!(EscrowError::PaymentMemoMissing)
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+
+// Instruction a payment verifier program must expose: Anchor's
+// `verify_payment(claim: PaymentClaim)`, with the escrow as its first
+// (read-only) account followed by whatever accounts the proof needs. It
+// returns Ok only if `claim.proof` shows the taker paid for this escrow.
+const VERIFY_PAYMENT_IX: &[u8] = b"global:verify_payment";
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PaymentClaim {
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    pub initializer: Pubkey,
+    pub mint_a: Pubkey,
+    pub initializer_amount: u64,
+    pub taker_amount: u64,
+    pub proof: Vec<u8>,
+}
+
+// CPIs the verifier; any error it returns aborts the whole instruction
+pub fn verify_payment_claim<'info>(
+    verifier: &AccountInfo<'info>,
+    escrow: &AccountInfo<'info>,
+    proof_accounts: &[AccountInfo<'info>],
+    claim: &PaymentClaim,
+) -> Result<()> {
+    let mut data = hash(VERIFY_PAYMENT_IX).to_bytes()[..8].to_vec();
+    claim.serialize(&mut data)?;
+
+    let mut accounts = vec![AccountMeta::new_readonly(escrow.key(), false)];
+    accounts.extend(proof_accounts.iter().map(|account| AccountMeta {
+        pubkey: account.key(),
+        is_signer: account.is_signer,
+        is_writable: account.is_writable,
+    }));
+    let mut infos = vec![escrow.clone()];
+    infos.extend_from_slice(proof_accounts);
+
+    invoke(
+        &Instruction {
+            program_id: verifier.key(),
+            accounts,
+            data,
+        },
+        &infos,
+    )?;
+    Ok(())
+}
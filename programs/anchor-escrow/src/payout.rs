@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer_checked, TokenAccount, TransferChecked};
+
+use crate::errors::EscrowError;
+use crate::events::{DonationMade, MakerRebateAccrued, PartnerFeePaid, PartnerShareAccrued};
+use crate::fees::SettlementSplit;
+use crate::states::{DonationPledge, MakerRebate, PartnerAccrual, PartnerConfig};
+
+// Transfers out of a settling escrow's vault that more than one payout path
+// makes, signed by the escrow: the partner's cut, the maker's rebate and the
+// taker's donation, in the amounts `SettlementSplit` worked out.
+pub struct VaultAccounts<'a, 'info> {
+    pub escrow: &'a AccountInfo<'info>,
+    pub vault: &'a AccountInfo<'info>,
+    pub mint: &'a AccountInfo<'info>,
+    pub decimals: u8,
+    pub token_program: &'a AccountInfo<'info>,
+}
+
+// The partner an escrow came through, its fee wallet's account, and the
+// pool account and accrual its revenue share is parked in
+pub struct PartnerAccounts<'a, 'info> {
+    pub config: Option<&'a Account<'info, PartnerConfig>>,
+    pub fee_ata: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub pool_ata: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub accrual: Option<&'a mut Account<'info, PartnerAccrual>>,
+    pub accrual_bump: Option<u8>,
+}
+
+impl<'a, 'info> PartnerAccounts<'a, 'info> {
+    pub fn config(&self) -> Result<&PartnerConfig> {
+        self.config
+            .map(|config| &**config)
+            .ok_or(error!(EscrowError::PartnerAccountsMissing))
+    }
+}
+
+// The rebate pool's account and the maker's accrual in it
+pub struct RebateAccounts<'a, 'info> {
+    pub pool_ata: Option<&'a InterfaceAccount<'info, TokenAccount>>,
+    pub accrual: Option<&'a mut Account<'info, MakerRebate>>,
+    pub accrual_bump: Option<u8>,
+}
+
+pub fn transfer_from_vault<'info>(
+    accounts: &VaultAccounts<'_, 'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let cpi_accounts = TransferChecked {
+        from: accounts.vault.clone(),
+        mint: accounts.mint.clone(),
+        to: to.clone(),
+        authority: accounts.escrow.clone(),
+    };
+    transfer_checked(
+        CpiContext::new(accounts.token_program.clone(), cpi_accounts).with_signer(signer_seeds),
+        amount,
+        accounts.decimals,
+    )
+}
+
+// Pays the partner's fee to its fee wallet, and moves its share of the
+// platform fee into the pool and credits its accrual
+pub fn pay_partner<'info>(
+    accounts: &VaultAccounts<'_, 'info>,
+    partner: PartnerAccounts<'_, 'info>,
+    split: &SettlementSplit,
+    now: i64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let config = partner.config()?;
+    let escrow = accounts.escrow.key();
+    let mint = accounts.mint.key();
+    if split.partner_fee > 0 {
+        let fee_ata = partner
+            .fee_ata
+            .ok_or(EscrowError::PartnerAccountsMissing)?;
+        require_keys_eq!(
+            fee_ata.owner,
+            config.fee_wallet,
+            EscrowError::PartnerAccountsMissing
+        );
+        transfer_from_vault(
+            accounts,
+            &fee_ata.to_account_info(),
+            split.partner_fee,
+            signer_seeds,
+        )?;
+        emit!(PartnerFeePaid {
+            escrow,
+            partner_id: config.partner_id,
+            fee_wallet: config.fee_wallet,
+            mint,
+            amount: split.partner_fee,
+        });
+    }
+    if split.partner_share > 0 {
+        let pool_ata = partner
+            .pool_ata
+            .ok_or(EscrowError::PartnerAccountsMissing)?;
+        transfer_from_vault(
+            accounts,
+            &pool_ata.to_account_info(),
+            split.partner_share,
+            signer_seeds,
+        )?;
+        let partner_id = config.partner_id;
+        let accrual = partner
+            .accrual
+            .ok_or(EscrowError::PartnerAccountsMissing)?;
+        let bump = partner
+            .accrual_bump
+            .ok_or(EscrowError::PartnerAccountsMissing)?;
+        accrual.init_if_new(partner_id, mint, bump);
+        accrual.accrue(split.partner_share, now)?;
+        emit!(PartnerShareAccrued {
+            escrow,
+            partner_id,
+            mint,
+            amount: split.partner_share,
+        });
+    }
+    Ok(())
+}
+
+// Parks the maker's rebate in the pool and credits it to their accrual
+pub fn accrue_maker_rebate<'info>(
+    accounts: &VaultAccounts<'_, 'info>,
+    rebate: RebateAccounts<'_, 'info>,
+    maker: Pubkey,
+    amount: u64,
+    now: i64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let pool_ata = rebate
+        .pool_ata
+        .ok_or(EscrowError::MakerRebateAccountsMissing)?;
+    transfer_from_vault(accounts, &pool_ata.to_account_info(), amount, signer_seeds)?;
+
+    let accrual = rebate
+        .accrual
+        .ok_or(EscrowError::MakerRebateAccountsMissing)?;
+    let bump = rebate
+        .accrual_bump
+        .ok_or(EscrowError::MakerRebateAccountsMissing)?;
+    let mint = accounts.mint.key();
+    accrual.init_if_new(maker, mint, bump);
+    accrual.accrue(amount, now)?;
+
+    emit!(MakerRebateAccrued {
+        escrow: accounts.escrow.key(),
+        maker,
+        mint,
+        amount,
+    });
+    Ok(())
+}
+
+// Pays the taker's pledge to the charity
+pub fn donate<'info>(
+    accounts: &VaultAccounts<'_, 'info>,
+    pledge: Option<&DonationPledge>,
+    charity_ata: Option<&InterfaceAccount<'info, TokenAccount>>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let pledge = pledge.ok_or(EscrowError::DonationPledgeMissing)?;
+    let charity_ata = charity_ata.ok_or(EscrowError::DonationPledgeMissing)?;
+    require_keys_eq!(
+        charity_ata.owner,
+        pledge.charity,
+        EscrowError::InvalidDonation
+    );
+    transfer_from_vault(
+        accounts,
+        &charity_ata.to_account_info(),
+        amount,
+        signer_seeds,
+    )?;
+
+    emit!(DonationMade {
+        escrow: accounts.escrow.key(),
+        taker: pledge.taker,
+        charity: pledge.charity,
+        mint: accounts.mint.key(),
+        amount,
+    });
+    Ok(())
+}
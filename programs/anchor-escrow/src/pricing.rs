@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{BPS_DENOMINATOR, MAX_RATE_AGE};
+use crate::errors::EscrowError;
+use crate::states::{Config, Escrow, RateFeed};
+
+// The feed's rate, if it was posted recently enough to trade on
+pub fn current_rate(feed: &RateFeed, now: i64) -> Result<u64> {
+    require!(
+        feed.rate > 0 && now.saturating_sub(feed.updated_at) <= MAX_RATE_AGE,
+        EscrowError::StaleRate
+    );
+    Ok(feed.rate)
+}
+
+// How far `current` is from `snapshot`, in basis points of `snapshot`
+pub fn deviation_bps(snapshot: u64, current: u64) -> u64 {
+    let moved = (snapshot as u128).abs_diff(current as u128);
+    (moved * BPS_DENOMINATOR as u128 / snapshot as u128).min(u64::MAX as u128) as u64
+}
+
+// Settlement of an escrow with a rate snapshot needs the market's feed, and
+// the feed must not have moved further than the config allows since the
+// offer was made. Both parties together clear a tripped guard with
+// `accept_rate_move`.
+pub fn require_rate_within(
+    config: &Config,
+    escrow: &Escrow,
+    feed: Option<&Account<RateFeed>>,
+    now: i64,
+) -> Result<()> {
+    if !escrow.has_rate_snapshot() || config.max_rate_deviation_bps == 0 {
+        return Ok(());
+    }
+    let feed = feed.ok_or(EscrowError::RateFeedMissing)?;
+    let current = current_rate(feed, now)?;
+    require!(
+        deviation_bps(escrow.rate_snapshot, current) <= config.max_rate_deviation_bps as u64,
+        EscrowError::RateDeviationExceeded
+    );
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{ed25519_program, secp256k1_program};
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::errors::EscrowError;
+
+// Prefixes every off-chain release approval so the signature can't be lifted
+// from, or reused for, another protocol's message. v2 added the signer's
+// nonce, see `SignerNonce`.
+const RELEASE_APPROVAL_DOMAIN: &[u8] = b"anchor-escrow:release:v2";
+const PERMIT_DOMAIN: &[u8] = b"anchor-escrow:permit:v2";
+
+// Ed25519SignatureOffsets: seven little-endian u16s after the two-byte header
+const ED25519_HEADER_LEN: usize = 2;
+const ED25519_OFFSETS_LEN: usize = 14;
+const ED25519_PUBKEY_LEN: usize = 32;
+// Instruction index meaning "this instruction's own data"
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+// SecpSignatureOffsets: u16 signature offset, u8 instruction index, u16 eth
+// address offset, u8 index, u16 message offset, u16 message size, u8 index
+const SECP256K1_HEADER_LEN: usize = 1;
+const SECP256K1_OFFSETS_LEN: usize = 11;
+const ETH_ADDRESS_LEN: usize = 20;
+// EIP-191 prefix wallets add in `personal_sign`
+const ETH_SIGNED_MESSAGE_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n";
+
+// What the initializer, or the escrow's EVM approver, signs to let a relayer
+// release `amount` of `escrow` to `taker` until `valid_until`. Either way the
+// nonce is the initializer's.
+pub fn release_approval_message(
+    escrow: &Pubkey,
+    taker: &Pubkey,
+    amount: u64,
+    valid_until: i64,
+    nonce: u64,
+) -> Vec<u8> {
+    [
+        RELEASE_APPROVAL_DOMAIN,
+        escrow.as_ref(),
+        taker.as_ref(),
+        &amount.to_le_bytes(),
+        &valid_until.to_le_bytes(),
+        &nonce.to_le_bytes(),
+    ]
+    .concat()
+}
+
+// What a maker signs to let a relayer open escrow `seed` on their behalf,
+// pulling `initializer_amount` through the program's delegate approval
+#[allow(clippy::too_many_arguments)]
+pub fn permit_message(
+    maker: &Pubkey,
+    mint_a: &Pubkey,
+    mint_b: &Pubkey,
+    seed: u64,
+    initializer_amount: u64,
+    taker_amount: u64,
+    terms_hash: &[u8; 32],
+    valid_until: i64,
+    nonce: u64,
+) -> Vec<u8> {
+    [
+        PERMIT_DOMAIN,
+        maker.as_ref(),
+        mint_a.as_ref(),
+        mint_b.as_ref(),
+        &seed.to_le_bytes(),
+        &initializer_amount.to_le_bytes(),
+        &taker_amount.to_le_bytes(),
+        terms_hash,
+        &valid_until.to_le_bytes(),
+        &nonce.to_le_bytes(),
+    ]
+    .concat()
+}
+
+// The instruction right before this one must be an Ed25519 program
+// instruction holding a single signature by `signer` over `message`. The
+// precompile has already checked the signature by the time we run, so all
+// that is left is making sure it covers what we expect.
+pub fn require_ed25519_signature(
+    instructions: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<()> {
+    let current = load_current_index_checked(instructions)?;
+    require!(current > 0, EscrowError::InvalidReleaseApproval);
+    let ix = load_instruction_at_checked((current - 1) as usize, instructions)?;
+    require_keys_eq!(
+        ix.program_id,
+        ed25519_program::ID,
+        EscrowError::InvalidReleaseApproval
+    );
+
+    let data = &ix.data;
+    require!(
+        data.len() >= ED25519_HEADER_LEN + ED25519_OFFSETS_LEN && data[0] == 1,
+        EscrowError::InvalidReleaseApproval
+    );
+    let offsets = &data[ED25519_HEADER_LEN..ED25519_HEADER_LEN + ED25519_OFFSETS_LEN];
+    let read = |i: usize| u16::from_le_bytes([offsets[2 * i], offsets[2 * i + 1]]);
+    let (signature_ix, pubkey_offset, pubkey_ix) = (read(1), read(2) as usize, read(3));
+    let (message_offset, message_size, message_ix) = (read(4) as usize, read(5) as usize, read(6));
+    // Everything must live in the Ed25519 instruction itself, otherwise the
+    // verified bytes could differ from the ones we read here
+    require!(
+        signature_ix == CURRENT_INSTRUCTION
+            && pubkey_ix == CURRENT_INSTRUCTION
+            && message_ix == CURRENT_INSTRUCTION,
+        EscrowError::InvalidReleaseApproval
+    );
+
+    let signed_pubkey = data
+        .get(pubkey_offset..pubkey_offset + ED25519_PUBKEY_LEN)
+        .ok_or(EscrowError::InvalidReleaseApproval)?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(EscrowError::InvalidReleaseApproval)?;
+    require!(
+        signed_pubkey == signer.as_ref() && signed_message == message,
+        EscrowError::InvalidReleaseApproval
+    );
+    Ok(())
+}
+
+// Same as `require_ed25519_signature`, for a Secp256k1 program instruction
+// recovering to `eth_address`. The precompile hashes the message with
+// keccak256 as-is, so both a raw signature over `message` and a
+// `personal_sign` one (EIP-191 prefixed) are accepted.
+pub fn require_secp256k1_signature(
+    instructions: &AccountInfo,
+    eth_address: &[u8; 20],
+    message: &[u8],
+) -> Result<()> {
+    let current = load_current_index_checked(instructions)?;
+    require!(current > 0, EscrowError::InvalidReleaseApproval);
+    let index = current - 1;
+    let ix = load_instruction_at_checked(index as usize, instructions)?;
+    require_keys_eq!(
+        ix.program_id,
+        secp256k1_program::ID,
+        EscrowError::InvalidReleaseApproval
+    );
+
+    let data = &ix.data;
+    require!(
+        data.len() >= SECP256K1_HEADER_LEN + SECP256K1_OFFSETS_LEN && data[0] == 1,
+        EscrowError::InvalidReleaseApproval
+    );
+    let offsets = &data[SECP256K1_HEADER_LEN..SECP256K1_HEADER_LEN + SECP256K1_OFFSETS_LEN];
+    let read = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]) as usize;
+    let (signature_ix, address_offset, address_ix) = (offsets[2], read(3), offsets[5]);
+    let (message_offset, message_size, message_ix) = (read(6), read(8), offsets[10]);
+    // Unlike Ed25519 there is no "current instruction" marker, so the indexes
+    // must point at the Secp256k1 instruction itself
+    require!(
+        [signature_ix, address_ix, message_ix]
+            .iter()
+            .all(|&i| i as u16 == index),
+        EscrowError::InvalidReleaseApproval
+    );
+
+    let signed_address = data
+        .get(address_offset..address_offset + ETH_ADDRESS_LEN)
+        .ok_or(EscrowError::InvalidReleaseApproval)?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(EscrowError::InvalidReleaseApproval)?;
+    require!(
+        signed_address == eth_address
+            && (signed_message == message || signed_message == eip191_message(message)),
+        EscrowError::InvalidReleaseApproval
+    );
+    Ok(())
+}
+
+fn eip191_message(message: &[u8]) -> Vec<u8> {
+    [
+        ETH_SIGNED_MESSAGE_PREFIX,
+        message.len().to_string().as_bytes(),
+        message,
+    ]
+    .concat()
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+// Terms of arbitrator staking, at [b"arbitration"]. Per-case rewards are paid
+// out of the reward pool, this account's associated token account for
+// `stake_mint`, which the platform tops up from the arbitration fees it
+// collects.
+#[account]
+#[derive(InitSpace)]
+pub struct ArbitrationPolicy {
+    pub version: u8,
+    pub bump: u8,
+    pub stake_mint: Pubkey,
+    // Stake `assign_arbiter` requires of a candidate
+    pub min_stake: u64,
+    // Credited to the arbiter for every dispute they resolve
+    pub reward_per_case: u64,
+    // Share of the arbiter's stake an overturned ruling costs them
+    pub slash_bps: u16,
+    // How long after a ruling it may be overturned, and so how long an
+    // unstaking arbitrator waits before withdrawing
+    pub appeal_window: i64,
+    pub _reserved: [u8; 32],
+}
+
+impl ArbitrationPolicy {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + ArbitrationPolicy::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{BPS_DENOMINATOR, MAX_ARBITRATOR_LANGUAGES, MAX_ARBITRATOR_REGIONS};
+
+// An admin-vetted arbitrator, at [b"arbitrator", wallet], tagged with the
+// regions (ISO 3166-1 alpha-2) and languages (ISO 639-1) they handle
+// disputes for, so `assign_arbiter` can match them to localized fiat rails.
+// Also their stake PDA: the staked tokens sit in this account's associated
+// token account for the arbitration policy's stake mint.
+#[account]
+#[derive(InitSpace)]
+pub struct Arbitrator {
+    pub version: u8,
+    pub bump: u8,
+    // Inactive arbitrators keep their tags but aren't assigned new escrows
+    pub is_active: bool,
+    pub region_count: u8,
+    pub language_count: u8,
+    pub wallet: Pubkey,
+    pub regions: [[u8; 2]; MAX_ARBITRATOR_REGIONS],
+    pub languages: [[u8; 2]; MAX_ARBITRATOR_LANGUAGES],
+    pub staked: u64,
+    // Case rewards earned and not yet claimed from the reward pool
+    pub pending_rewards: u64,
+    // When a requested unstake may be withdrawn; 0 while fully staked
+    pub unlock_at: i64,
+    // Track record, kept up by `resolve_milestone_dispute` and `overturn_ruling`
+    pub cases_resolved: u32,
+    pub rulings_overturned: u32,
+    // Sum over resolved cases of the time from dispute to ruling
+    pub total_resolution_time: u64,
+    pub _reserved: [u8; 32],
+}
+
+impl Arbitrator {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + Arbitrator::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    // A region match outranks a language match
+    pub const REGION_SCORE: u8 = 2;
+    pub const LANGUAGE_SCORE: u8 = 1;
+
+    pub fn set_tags(&mut self, regions: &[[u8; 2]], languages: &[[u8; 2]]) {
+        self.region_count = regions.len() as u8;
+        self.regions = [[0; 2]; MAX_ARBITRATOR_REGIONS];
+        self.regions[..regions.len()].copy_from_slice(regions);
+        self.language_count = languages.len() as u8;
+        self.languages = [[0; 2]; MAX_ARBITRATOR_LANGUAGES];
+        self.languages[..languages.len()].copy_from_slice(languages);
+    }
+
+    // Takes new cases only while active, staked enough and not on the way out
+    pub fn is_eligible(&self, min_stake: u64) -> bool {
+        self.is_active && self.unlock_at == 0 && self.staked >= min_stake
+    }
+
+    pub fn track_resolved(&mut self, resolution_time: i64) {
+        self.cases_resolved = self.cases_resolved.saturating_add(1);
+        self.total_resolution_time = self
+            .total_resolution_time
+            .saturating_add(resolution_time.max(0) as u64);
+    }
+
+    pub fn average_resolution_time(&self) -> u64 {
+        if self.cases_resolved == 0 {
+            return 0;
+        }
+        self.total_resolution_time / self.cases_resolved as u64
+    }
+
+    pub fn overturn_rate_bps(&self) -> u16 {
+        if self.cases_resolved == 0 {
+            return 0;
+        }
+        (self.rulings_overturned as u64 * BPS_DENOMINATOR as u64 / self.cases_resolved as u64)
+            as u16
+    }
+
+    pub fn serves_any(&self, regions: &[[u8; 2]]) -> bool {
+        self.regions[..self.region_count as usize]
+            .iter()
+            .any(|region| regions.contains(region))
+    }
+
+    pub fn speaks(&self, language: [u8; 2]) -> bool {
+        self.languages[..self.language_count as usize].contains(&language)
+    }
+
+    // How well this arbitrator fits a trade in `regions` conducted in `language`
+    pub fn match_score(&self, regions: &[[u8; 2]], language: [u8; 2]) -> u8 {
+        let mut score = 0;
+        if self.serves_any(regions) {
+            score += Self::REGION_SCORE;
+        }
+        if self.speaks(language) {
+            score += Self::LANGUAGE_SCORE;
+        }
+        score
+    }
+}
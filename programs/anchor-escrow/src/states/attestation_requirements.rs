@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_ATTESTATION_SCHEMAS;
+
+// Solana Attestation Service schemas a taker must hold attestations for, from
+// a trusted credential (issuer). Global at [b"attestations"], per offer at
+// [b"attestations", escrow].
+#[account]
+#[derive(InitSpace)]
+pub struct AttestationRequirements {
+    pub version: u8,
+    pub bump: u8,
+    pub schema_count: u8,
+    // Escrow the requirements belong to, Pubkey::default() for the global set
+    pub escrow: Pubkey,
+    pub credential: Pubkey,
+    pub schemas: [Pubkey; MAX_ATTESTATION_SCHEMAS],
+    pub _reserved: [u8; 32],
+}
+
+impl AttestationRequirements {
+    pub fn required_schemas(&self) -> &[Pubkey] {
+        &self.schemas[..self.schema_count as usize]
+    }
+
+    pub fn set(&mut self, credential: Pubkey, schemas: &[Pubkey]) {
+        self.credential = credential;
+        self.schema_count = schemas.len() as u8;
+        self.schemas = [Pubkey::default(); MAX_ATTESTATION_SCHEMAS];
+        self.schemas[..schemas.len()].copy_from_slice(schemas);
+    }
+
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + AttestationRequirements::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum AdminAction {
+    InitializeConfig = 0,
+    UpdateConfig = 1,
+    BlockWallet = 2,
+    UnblockWallet = 3,
+    SetVolumeTier = 4,
+    SetGlobalAttestations = 5,
+    FreezeEscrow = 6,
+    UnfreezeEscrow = 7,
+    PayClaim = 8,
+    // Signed by the escrow's arbiter rather than the config admin
+    ResolveDispute = 9,
+    SetPaymentVerifier = 10,
+    CloseOrphanedVault = 11,
+    InitializeBadgeMint = 12,
+    SetRateFeed = 13,
+    SetStablecoin = 14,
+    SetSwapRouter = 15,
+    SetFeeConverter = 16,
+    SetYieldAdapter = 17,
+    SetCharity = 18,
+    SetComplianceDesk = 19,
+    SetArbitrator = 20,
+    SetArbitrationPolicy = 21,
+    OverturnRuling = 22,
+    ConveneJury = 23,
+    DissolveJury = 24,
+    SetGovernance = 25,
+    SetPartnerConfig = 26,
+}
+
+// Head of the admin audit trail, at [b"audit_log"]. Entries themselves go out
+// as `AdminActionRecorded` events; every one is folded into `head`, so an
+// indexer replaying the events can prove none were dropped or altered.
+#[account]
+#[derive(InitSpace)]
+pub struct AuditLog {
+    pub version: u8,
+    pub bump: u8,
+    // Entries recorded so far; the next entry gets this number
+    pub sequence: u64,
+    pub last_recorded_at: i64,
+    // hashv(previous head, entry) of the latest entry, zero before the first
+    pub head: [u8; 32],
+    pub _reserved: [u8; 32],
+}
+
+impl AuditLog {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + AuditLog::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    pub fn init_if_new(&mut self, bump: u8) {
+        if self.version == 0 {
+            self.version = Self::VERSION;
+            self.bump = bump;
+        }
+    }
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+
+// Marks a wallet as barred from the venue, at [b"blocked", wallet]. The account
+// existing is the block; `unblock_wallet` closes it.
+#[account]
+#[derive(InitSpace)]
+pub struct BlockedWallet {
+    pub version: u8,
+    pub bump: u8,
+    pub wallet: Pubkey,
+    pub blocked_at: i64,
+    // Hash of the off-chain case file or sanctions reference
+    pub reason_hash: [u8; 32],
+    pub _reserved: [u8; 32],
+}
+
+impl BlockedWallet {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + BlockedWallet::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+// Taker collateral for one escrow, at [b"bond", escrow]. SOL bonds sit in this
+// account's lamports; token bonds in its associated token account for `mint`.
+#[account]
+#[derive(InitSpace)]
+pub struct Bond {
+    pub version: u8,
+    pub bump: u8,
+    pub posted: bool,
+    pub forfeited: bool,
+    pub escrow: Pubkey,
+    // Paid the rent, and receives a forfeited bond
+    pub initializer: Pubkey,
+    // Pubkey::default() until posted
+    pub taker: Pubkey,
+    // Pubkey::default() for a SOL bond
+    pub mint: Pubkey,
+    pub amount: u64,
+    // Set when a dispute ruling slashes the bond: the arbiter's share of `amount`
+    pub arbiter: Pubkey,
+    pub arbitration_fee: u64,
+    pub _reserved: [u8; 32],
+}
+
+impl Bond {
+    pub fn is_sol(&self) -> bool {
+        self.mint == Pubkey::default()
+    }
+
+    // Where a released bond goes: back to the taker unless forfeited, in
+    // which case the winner gets it minus the arbiter's cut
+    pub fn recipient(&self) -> Pubkey {
+        if self.posted && !self.forfeited {
+            self.taker
+        } else {
+            self.initializer
+        }
+    }
+
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + Bond::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
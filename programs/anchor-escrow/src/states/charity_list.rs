@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_CHARITIES;
+use crate::errors::EscrowError;
+
+// Charity wallets takers may pledge part of their payout to, at
+// [b"charities"]. Checked when the pledge is made, so delisting a charity
+// doesn't break settlement of escrows already pledged to it.
+#[account]
+#[derive(InitSpace)]
+pub struct CharityList {
+    pub version: u8,
+    pub bump: u8,
+    pub count: u16,
+    // Only the first `count` entries are meaningful; order isn't kept
+    pub wallets: [Pubkey; MAX_CHARITIES],
+    pub _reserved: [u8; 32],
+}
+
+impl CharityList {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + CharityList::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    pub fn init_if_new(&mut self, bump: u8) {
+        if self.version == 0 {
+            self.version = CharityList::VERSION;
+            self.bump = bump;
+        }
+    }
+
+    pub fn listed_wallets(&self) -> &[Pubkey] {
+        &self.wallets[..self.count as usize]
+    }
+
+    pub fn contains(&self, wallet: &Pubkey) -> bool {
+        self.listed_wallets().contains(wallet)
+    }
+
+    // Listing a wallet twice is a no-op
+    pub fn add(&mut self, wallet: Pubkey) -> Result<()> {
+        if self.contains(&wallet) {
+            return Ok(());
+        }
+        let count = self.count as usize;
+        require!(count < MAX_CHARITIES, EscrowError::CharityListFull);
+        self.wallets[count] = wallet;
+        self.count += 1;
+        Ok(())
+    }
+
+    pub fn remove(&mut self, wallet: &Pubkey) -> bool {
+        match self.listed_wallets().iter().position(|w| w == wallet) {
+            Some(i) => {
+                let last = self.count as usize - 1;
+                self.wallets[i] = self.wallets[last];
+                self.wallets[last] = Pubkey::default();
+                self.count -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+// Four-eyes sign-off on paying an escrow out to `taker`, at
+// [b"compliance_approval", escrow]. The desk and the offer's own compliance
+// key each set their half; a new taker voids both.
+#[account]
+#[derive(InitSpace)]
+pub struct ComplianceApproval {
+    pub version: u8,
+    pub bump: u8,
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    // Approved by the [b"compliance"] desk signer
+    pub desk_approved: bool,
+    // Approved by the escrow's `compliance_signer`
+    pub offer_approved: bool,
+    pub approved_at: i64,
+    pub _reserved: [u8; 16],
+}
+
+impl ComplianceApproval {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + ComplianceApproval::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
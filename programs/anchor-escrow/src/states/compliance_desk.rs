@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+// The platform's compliance key, at [b"compliance"]. While the config's
+// `compliance_required` switch is on, it has to approve every escrow's
+// release with `approve_release` first.
+#[account]
+#[derive(InitSpace)]
+pub struct ComplianceDesk {
+    pub version: u8,
+    pub bump: u8,
+    pub signer: Pubkey,
+    pub _reserved: [u8; 32],
+}
+
+impl ComplianceDesk {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + ComplianceDesk::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_FEE_CATEGORIES, PLATFORM_FEE_PERCENTAGE};
+
+// Protocol-wide settings, at [b"config"]. Borsh-encoded, so fields are only
+// ever appended; `migrate_config` grows accounts written by older versions.
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    pub version: u8,
+    pub bump: u8,
+    // Bump of the [b"insurance"] PDA that owns the insurance fund token accounts
+    pub insurance_bump: u8,
+    // Share of every platform fee routed to the insurance fund, in basis points
+    pub insurance_fee_bps: u16,
+    pub admin: Pubkey,
+    // Arbiter's cut of a slashed bond, in basis points
+    pub arbitration_fee_bps: u16,
+    // Deposit open-offer makers stake, in basis points of initializer_amount
+    pub maker_deposit_bps: u16,
+    // Cancels after payment confirmation a maker may rack up before deposits
+    // start being forfeited to the taker; 0 never forfeits
+    pub max_cancel_strikes: u8,
+    // Civic gatekeeper network both parties need a pass from; default disables gating
+    pub gatekeeper_network: Pubkey,
+    // Takers need the attestations listed at [b"attestations"]
+    pub attestations_required: bool,
+    // Per-wallet caps on mint_a traded per day and per week; 0 is unlimited.
+    // Amounts are raw token units, so they only make sense on single-mint markets.
+    pub daily_volume_limit: u64,
+    pub weekly_volume_limit: u64,
+    // Escrows one maker may have open at once; 0 is unlimited
+    pub max_open_escrows: u16,
+    // Anti-spam lamports charged per escrow created; 0 disables the fee
+    pub creation_fee_lamports: u32,
+    // Hold the fee on the escrow and return it on settlement, keeping it only
+    // for offers that are cancelled or aborted
+    pub refund_creation_fee: bool,
+    // Default window, in hours, a seller has to release a confirmed escrow
+    // before anyone may `force_release` it to the taker; 0 disables
+    pub auto_release_after_hours: u16,
+    pub _reserved: [u8; 1],
+    // Platform fee percentage `exchange` charges between `promo_starts_at`
+    // (inclusive) and `promo_ends_at` (exclusive); an empty window disables it
+    pub promo_fee_percentage: u8,
+    pub promo_starts_at: i64,
+    pub promo_ends_at: i64,
+    // Share of each filled offer paid back to its maker out of the platform
+    // fee, in basis points of the amount; capped at the platform's own share
+    pub maker_rebate_bps: u16,
+    // Market (mint_a) the rebate is limited to; default rebates every mint
+    pub rebate_mint: Pubkey,
+    // Extra fee, in basis points of the amount, charged at a 100% dispute-loss
+    // rate and scaled down linearly with the initializer's actual rate; it all
+    // goes to the insurance fund. 0 disables.
+    pub risk_premium_max_bps: u16,
+    // Share of the rent `gc_escrow` reclaims that goes to the cranker, in
+    // basis points; the rest goes to the platform wallet
+    pub gc_bounty_bps: u16,
+    // Bit n set: offers in category n pay `category_fee_percentages[n]`
+    // instead of the standard platform fee
+    pub category_fee_overrides: u16,
+    pub category_fee_percentages: [u8; MAX_FEE_CATEGORIES],
+    pub _reserved_v2: [u8; 8],
+    // Config::FEATURE_* subsystems that are switched on
+    pub features: u64,
+    // How far, in basis points, a market's feed may move from an escrow's
+    // rate snapshot before settlement is refused; 0 disables the guard
+    pub max_rate_deviation_bps: u16,
+    // New offers may only trade mints on the [b"stablecoins"] list
+    pub stablecoin_only: bool,
+    // Maker's cut of the yield a lent-out vault earns, in basis points; the
+    // rest goes to the platform
+    pub yield_maker_share_bps: u16,
+    // Releases need the [b"compliance"] desk's `approve_release` first
+    pub compliance_required: bool,
+    // Lamports the party opening a milestone dispute puts down: refunded if
+    // they win, the arbitration fee if they lose
+    pub dispute_fee: u64,
+    // Seconds past an escrow's deadline before anyone may reap it, so a
+    // payment confirmed right at the deadline isn't raced by the reap
+    pub reap_grace_period: u32,
+    pub _reserved_v3: [u8; 14],
+}
+
+impl Config {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + Config::INIT_SPACE;
+
+    pub const VERSION: u8 = 3;
+
+    // Allocated size of version 1, which ended at `_reserved`
+    pub const V1_SPACE: usize = 109;
+    // Allocated size of version 2, which ended at `_reserved_v2`
+    pub const V2_SPACE: usize = 190;
+
+    // Runtime switches, so a subsystem can ship dark and be killed without a
+    // redeploy. A switch only guards the ways into its subsystem; whatever
+    // pays out or winds down what is already in flight keeps working.
+    // `dispute_milestone`
+    pub const FEATURE_DISPUTES: u64 = 1 << 0;
+    // `set_partial_payments` and `confirm_partial_payment`
+    pub const FEATURE_PARTIAL_PAYMENTS: u64 = 1 << 1;
+    // `initialize_delegated`
+    pub const FEATURE_DELEGATED: u64 = 1 << 2;
+    // `initialize_with_permit`
+    pub const FEATURE_PERMITS: u64 = 1 << 3;
+    // `create_recurring` and `spawn_recurring`
+    pub const FEATURE_RECURRING: u64 = 1 << 4;
+    // `mint_badges`
+    pub const FEATURE_BADGES: u64 = 1 << 5;
+    // `deposit_to_yield`; new, so it starts switched off
+    pub const FEATURE_YIELD: u64 = 1 << 6;
+    // `match_offers`; new, so it starts switched off
+    pub const FEATURE_ORDER_MATCHING: u64 = 1 << 7;
+    // `publish_settlement`; new, so it starts switched off
+    pub const FEATURE_WORMHOLE: u64 = 1 << 8;
+    // What new and migrated configs start with: everything that shipped
+    // before the switches existed
+    pub const DEFAULT_FEATURES: u64 = Self::FEATURE_DISPUTES
+        | Self::FEATURE_PARTIAL_PAYMENTS
+        | Self::FEATURE_DELEGATED
+        | Self::FEATURE_PERMITS
+        | Self::FEATURE_RECURRING
+        | Self::FEATURE_BADGES;
+
+    pub fn is_promo_active(&self, now: i64) -> bool {
+        self.promo_starts_at <= now && now < self.promo_ends_at
+    }
+
+    // Platform fee percentage for an offer in `category` at `now`; a running
+    // promo only ever lowers it
+    pub fn fee_percentage(&self, category: u8, now: i64) -> u8 {
+        let fee = self.category_fee_percentage(category);
+        if self.is_promo_active(now) {
+            fee.min(self.promo_fee_percentage)
+        } else {
+            fee
+        }
+    }
+
+    pub fn category_fee_percentage(&self, category: u8) -> u8 {
+        let index = category as usize;
+        if index < MAX_FEE_CATEGORIES && self.category_fee_overrides & (1 << index) != 0 {
+            self.category_fee_percentages[index]
+        } else {
+            PLATFORM_FEE_PERCENTAGE
+        }
+    }
+
+    pub fn has_feature(&self, feature: u64) -> bool {
+        self.features & feature == feature
+    }
+
+    // Maker rebate rate for fills of `mint`
+    pub fn maker_rebate_bps(&self, mint: &Pubkey) -> u16 {
+        if self.rebate_mint == Pubkey::default() || self.rebate_mint == *mint {
+            self.maker_rebate_bps
+        } else {
+            0
+        }
+    }
+}
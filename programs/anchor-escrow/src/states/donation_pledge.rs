@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BPS_DENOMINATOR;
+use crate::fees::pro_rata;
+
+// A taker's opt-in to give part of their payout to a listed charity, at
+// [b"donation", escrow]. `exchange` and `force_release` pay it out and close
+// it back to the taker; if the escrow ends any other way `gc_escrow`
+// reclaims it.
+#[account]
+#[derive(InitSpace)]
+pub struct DonationPledge {
+    pub version: u8,
+    pub bump: u8,
+    pub escrow: Pubkey,
+    pub taker: Pubkey,
+    pub charity: Pubkey,
+    // Share of the payout given, in basis points
+    pub donation_bps: u16,
+    // Also give whatever is left below a whole token, so the taker receives
+    // a round amount
+    pub round_up: bool,
+    pub _reserved: [u8; 16],
+}
+
+impl DonationPledge {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + DonationPledge::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    // What the taker gives out of a `payout` of a mint with `decimals`
+    pub fn amount(&self, payout: u64, decimals: u8) -> Result<u64> {
+        let mut donation = pro_rata(payout, self.donation_bps as u64, BPS_DENOMINATOR as u64)?;
+        if self.round_up {
+            if let Some(unit) = 10u64.checked_pow(decimals as u32) {
+                donation += (payout - donation) % unit;
+            }
+        }
+        Ok(donation)
+    }
+}
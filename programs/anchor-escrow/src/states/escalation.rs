@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+// A milestone dispute routed to the DAO, at [b"escalation", dispute]. While
+// it exists neither the escrow's arbiter nor a jury can rule; only
+// `governance_resolve` can.
+#[account]
+#[derive(InitSpace)]
+pub struct Escalation {
+    pub version: u8,
+    pub bump: u8,
+    pub dispute: Pubkey,
+    pub escalated_by: Pubkey,
+    // SPL Governance proposal the party filed for the ruling, for indexers
+    pub proposal: Pubkey,
+    pub escalated_at: i64,
+    pub _reserved: [u8; 32],
+}
+
+impl Escalation {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + Escalation::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
@@ -1,7 +1,727 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
 
-#[account]
+use crate::constants::{BPS_DENOMINATOR, PROGRAM_VERSION, SECONDS_PER_HOUR};
+use crate::errors::EscrowError;
+use crate::fees::pro_rata;
+use crate::states::Config;
+
+const PRICE_COMMITMENT_DOMAIN: &[u8] = b"anchor-escrow:price:v1";
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum EscrowStatus {
+    // State exists but the vault has not been funded yet
+    Created = 0,
+    // Vault holds initializer_amount of mint_a
+    Funded = 1,
+    // Seller released a vesting escrow; the taker claims it over the schedule
+    Releasing = 2,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ReleaseMode {
+    // `exchange` pays the taker out in full
+    Immediate = 0,
+    // `start_vesting` then `claim_vested` along the release schedule
+    Vesting = 1,
+    // Tranches paid out one `release_milestone` at a time
+    Milestones = 2,
+    // Unlocks continuously from payment confirmation, drawn with `claim_stream`
+    Streaming = 3,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum BondState {
+    // Takers accept without collateral
+    None = 0,
+    // Maker asked for a taker bond that hasn't been posted yet
+    Required = 1,
+    // Bond is locked in its PDA until the escrow settles
+    Posted = 2,
+    // Taker lost a dispute; the bond goes to the maker
+    Forfeited = 3,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PartialPayments {
+    // The taker confirms the whole payment at once
+    Disabled = 0,
+    // Tranches add up, but nothing is released before the full amount is in
+    AtFullPayment = 1,
+    // The seller may `release_tranche` the mint_a matching each confirmed tranche
+    PerTranche = 2,
+}
+
+// Zero-copy so instructions read and write the account in place instead of
+// Borsh-decoding it every time. Fields are ordered by alignment so the
+// `repr(C)` layout has no implicit padding; `version` stays the first byte
+// after the discriminator in every layout.
+//
+// Byte offsets (including the 8-byte discriminator) are part of the public
+// interface for getProgramAccounts memcmp filters and are locked by the
+// tests below:
+//   version 8, bump 9, payment_confirmed 10, status 11, release_mode 12,
+//   milestone_count 13, milestones_released 14, bond_state 15, seed 16,
+//   initializer_amount 24, taker_amount 32, initializer 40, mint_a 72,
+//   mint_b 104, taker_assigned_at 136, taker 144, release_start 176,
+//   release_cliff 184, release_duration 192, released_amount 200,
+//   arbiter 208, milestone_total 240, maker_deposit 248, flags 256,
+//   _reserved 257..264, expires_at 264, next_action_at 272,
+//   payment_confirmed_at 280, auto_release_after 288, evm_approver 296,
+//   payment_methods 316, dispute_paused_at 320, open_disputes 328,
+//   payment_memo_required 329, partner_id 330, _reserved_v2 332..344,
+//   terms_hash 344,
+//   sequence 376, tags 384, category 388, partial_payments 389,
+//   payment_tranches 390, confirmed_amount 392, release_delay 400,
+//   last_tranche_at 408, program_version 416, donation_pledged 419,
+//   fiat_currency 420,
+//   yield_taker_share_bps 422, rate_snapshot 424, yield_principal 432,
+//   funder 440, compliance_signer 472, price_commitment 504, note 536
+//
+// New fields are carved out of `_reserved_v2`. Once it runs out, append to
+// the end, bump VERSION and teach `migrate_escrow` the new size.
+#[account(zero_copy)]
+#[derive(InitSpace)]
 pub struct Escrow {
+    pub version: u8,
+    pub bump: u8,
+    pub payment_confirmed: u8,
+    pub status: u8,
+    pub release_mode: u8,
+    pub milestone_count: u8,
+    // Milestones settled either way (released or refunded by the arbiter)
+    pub milestones_released: u8,
+    pub bond_state: u8,
+    pub seed: u64,
+    pub initializer_amount: u64,
+    pub taker_amount: u64,
+    pub initializer: Pubkey,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    // Unix timestamp of the last taker assignment, 0 while open
+    pub taker_assigned_at: i64,
+    // Pubkey::default() while the offer is open to anyone
+    pub taker: Pubkey,
+    // Vesting schedule; a zero start means "when the seller releases"
+    pub release_start: i64,
+    pub release_cliff: i64,
+    pub release_duration: i64,
+    // Amount already paid out: net of fees for vesting, gross for streaming
+    pub released_amount: u64,
+    // Optional neutral party for milestone sign-off, Pubkey::default() if none
+    pub arbiter: Pubkey,
+    // Sum of all milestone amounts; must reach initializer_amount before work starts
+    pub milestone_total: u64,
+    // mint_a the maker staked in its [b"maker_deposit", escrow] PDA, 0 if none
+    pub maker_deposit: u64,
+    // Escrow::FLAG_* bits
+    pub flags: u8,
+    pub _reserved: [u8; 7],
+    // Unix time after which an unconfirmed offer may be reaped, 0 if it never expires
+    pub expires_at: i64,
+    // Schedule hint for keepers: when `reap_expired` or `auto_release` next has
+    // work to do, 0 if nothing is scheduled
+    pub next_action_at: i64,
+    // Unix time the taker confirmed their payment, 0 before that
+    pub payment_confirmed_at: i64,
+    // Seconds the seller has to release after confirmation before anyone may
+    // `force_release`; 0 falls back to the config default
+    pub auto_release_after: i64,
+    // Ethereum address whose secp256k1 signature may approve the release in
+    // place of the initializer's, all zeroes if none
+    pub evm_approver: [u8; 20],
+    // Off-chain payment rails the maker accepts, as a client-defined bitmask;
+    // 0 if unspecified
+    pub payment_methods: u32,
+    // Unix time the first of the currently open disputes was raised; the
+    // deadline stands still from then until the last one is resolved
+    pub dispute_paused_at: i64,
+    // Milestone disputes raised and not yet resolved
+    pub open_disputes: u8,
+    // 1 if `confirm_payment` must carry the bank reference, matched by a
+    // Memo instruction earlier in the same transaction
+    pub payment_memo_required: u8,
+    // [b"partner", partner_id] frontend the offer was created through, whose
+    // fee `exchange` takes; 0 if none
+    pub partner_id: u16,
+    pub _reserved_v2: [u8; 12],
+    // Hash of the off-chain trade agreement both parties commit to, for
+    // arbiters to check a dispute against; zeroes if none was given
+    pub terms_hash: [u8; 32],
+    // 1-based position in the [b"escrow_counter"] creation order, 0 for
+    // escrows created before the counter or by paths that don't count
+    pub sequence: u64,
+    // Client-defined labels (e.g. stablecoin, NFT, bulk OTC) for filtering
+    pub tags: u32,
+    // Single market category; picks the config's per-category fee, if any
+    pub category: u8,
+    // PartialPayments the maker accepts
+    pub partial_payments: u8,
+    // Partial confirmations recorded so far
+    pub payment_tranches: u16,
+    // Fiat the taker has confirmed toward `taker_amount` and the seller has
+    // not released against yet
+    pub confirmed_amount: u64,
+    // Buyer protection: seconds after a payment confirmation before any
+    // mint_a may leave the vault, so fraud checks can flag it first; 0 if none
+    pub release_delay: i64,
+    // Unix time of the latest partial confirmation, 0 before the first
+    pub last_tranche_at: i64,
+    // PROGRAM_VERSION of the build that created the escrow, all zeroes for
+    // escrows older than the stamp. Unlike `version` it says nothing about
+    // the layout, only which program logic wrote the terms.
+    pub program_version: [u8; 3],
+    // 1 once the taker pledged part of their payout to charity; `exchange`
+    // then needs the [b"donation", escrow] pledge
+    pub donation_pledged: u8,
+    // ISO 4217 numeric code of the fiat `taker_amount` is in, once the taker
+    // picked one of the offer's [b"fiat_quotes", escrow]; 0 if unspecified
+    pub fiat_currency: u16,
+    // Share of the maker's part of the vault's yield passed on to the taker,
+    // in basis points; lets an offer sweeten a long lock-up for the buyer
+    pub yield_taker_share_bps: u16,
+    // Market rate from the [b"rate_feed", mint_a, mint_b] feed, or one the
+    // maker stated, when the offer was priced; 0 leaves settlement unguarded
+    pub rate_snapshot: u64,
+    // mint_a parked in the yield adapter, i.e. what `withdraw_from_yield` has
+    // to bring back to the vault; 0 while the vault holds everything
+    pub yield_principal: u64,
+    // Whoever deposited mint_a through `fund_escrow` on the initializer's
+    // behalf, and gets it back if the offer is cancelled; default when the
+    // initializer funded it themselves
+    pub funder: Pubkey,
+    // Compliance key that must approve this offer's release on top of the
+    // platform desk; default leaves it to the desk alone
+    pub compliance_signer: Pubkey,
+    // Hash of the hidden price while `taker_amount` is withheld from the
+    // order book, see `Escrow::hidden_price_commitment`; zero once public
+    pub price_commitment: [u8; 32],
+    // Maker's free-form reference (invoice number, order ID), UTF-8 padded
+    // with zeroes; editable until a taker is in
+    pub note: [u8; 64],
+}
+
+// InitSpace must match the in-memory layout, i.e. no compiler padding
+const _: () = assert!(Escrow::INIT_SPACE == std::mem::size_of::<Escrow>());
+
+impl Escrow {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + Escrow::INIT_SPACE;
+
+    // Bump whenever the layout changes and teach `migrate_escrow` the upgrade
+    pub const VERSION: u8 = 5;
+
+    // Offsets (including the discriminator) of the fields order-book UIs filter
+    // on, for getProgramAccounts memcmp filters; e.g. open offers in a market
+    // are STATUS_OFFSET = Funded, MINT_A_OFFSET = mint and TAKER_OFFSET = zeroes
+    pub const STATUS_OFFSET: usize = 11;
+    pub const INITIALIZER_OFFSET: usize = 40;
+    pub const MINT_A_OFFSET: usize = 72;
+    pub const MINT_B_OFFSET: usize = 104;
+    pub const TAKER_OFFSET: usize = 144;
+    pub const FLAGS_OFFSET: usize = 256;
+    pub const EXPIRES_AT_OFFSET: usize = 264;
+    pub const TAGS_OFFSET: usize = 384;
+    pub const CATEGORY_OFFSET: usize = 388;
+
+    // Allocated size of version 1, which ended at `_reserved`
+    pub const V1_SPACE: usize = 264;
+    // Allocated size of version 2, which ended at `_reserved_v2`
+    pub const V2_SPACE: usize = 344;
+    // Allocated size of version 3, which ended at `yield_principal`
+    pub const V3_SPACE: usize = 440;
+    // Allocated size of version 4, which ended at what is now `price_commitment`
+    pub const V4_SPACE: usize = 536;
+
+    // Takers need the attestations listed at [b"attestations", escrow]
+    pub const FLAG_OFFER_ATTESTATIONS: u8 = 1 << 0;
+    // Takers need a region attestation the [b"regions", escrow] policy permits
+    pub const FLAG_REGION_POLICY: u8 = 1 << 1;
+    // The escrow holds a refundable creation fee on top of its rent
+    pub const FLAG_CREATION_FEE_HELD: u8 = 1 << 2;
+    // An admin froze the escrow pending a fraud review: nothing is released or refunded
+    pub const FLAG_FROZEN: u8 = 1 << 3;
+    // The maker lets the configured verifier confirm payment from a proof
+    pub const FLAG_ACCEPTS_PAYMENT_PROOF: u8 = 1 << 4;
+    // Payment was confirmed by a verified proof rather than the taker's word
+    pub const FLAG_PAYMENT_PROVEN: u8 = 1 << 5;
+    // Non-custodial: mint_a stays in the maker's ATA under an SPL delegation
+    // to the escrow PDA and is only pulled at settlement; there is no vault
+    pub const FLAG_DELEGATED: u8 = 1 << 6;
+    // Priced in several currencies at [b"fiat_quotes", escrow]; the taker has
+    // to pick one, which clears the flag
+    pub const FLAG_FIAT_QUOTES: u8 = 1 << 7;
+
+    pub fn new(
+        seed: u64,
+        bump: u8,
+        initializer: Pubkey,
+        mint_a: Pubkey,
+        mint_b: Pubkey,
+        initializer_amount: u64,
+        taker_amount: u64,
+    ) -> Self {
+        Self {
+            version: Self::VERSION,
+            bump,
+            payment_confirmed: 0,
+            status: EscrowStatus::Created as u8,
+            release_mode: ReleaseMode::Immediate as u8,
+            milestone_count: 0,
+            milestones_released: 0,
+            bond_state: BondState::None as u8,
+            seed,
+            initializer_amount,
+            taker_amount,
+            initializer,
+            mint_a,
+            mint_b,
+            taker_assigned_at: 0,
+            taker: Pubkey::default(),
+            release_start: 0,
+            release_cliff: 0,
+            release_duration: 0,
+            released_amount: 0,
+            arbiter: Pubkey::default(),
+            milestone_total: 0,
+            maker_deposit: 0,
+            flags: 0,
+            _reserved: [0; 7],
+            expires_at: 0,
+            next_action_at: 0,
+            payment_confirmed_at: 0,
+            auto_release_after: 0,
+            evm_approver: [0; 20],
+            payment_methods: 0,
+            dispute_paused_at: 0,
+            open_disputes: 0,
+            payment_memo_required: 0,
+            partner_id: 0,
+            _reserved_v2: [0; 12],
+            terms_hash: [0; 32],
+            sequence: 0,
+            tags: 0,
+            category: 0,
+            partial_payments: PartialPayments::Disabled as u8,
+            payment_tranches: 0,
+            confirmed_amount: 0,
+            release_delay: 0,
+            last_tranche_at: 0,
+            program_version: PROGRAM_VERSION,
+            donation_pledged: 0,
+            fiat_currency: 0,
+            yield_taker_share_bps: 0,
+            rate_snapshot: 0,
+            yield_principal: 0,
+            funder: Pubkey::default(),
+            compliance_signer: Pubkey::default(),
+            price_commitment: [0; 32],
+            note: [0; 64],
+        }
+    }
+
+    // Fresh, unfunded copy of the offer terms for `relist_escrow`. Runtime
+    // state (taker, confirmation, deadlines) and terms kept in per-offer PDAs
+    // (milestones, attestation and region lists, fiat quotes) are not carried
+    // over.
+    pub fn relisted(&self, seed: u64, bump: u8) -> Self {
+        let mut escrow = Self::new(
+            seed,
+            bump,
+            self.initializer,
+            self.mint_a,
+            self.mint_b,
+            self.initializer_amount,
+            self.taker_amount,
+        );
+        if self.is_vesting() || self.is_streaming() {
+            // A zero start vests from whenever the seller releases
+            escrow.release_mode = self.release_mode;
+            escrow.release_cliff = self.release_cliff;
+            escrow.release_duration = self.release_duration;
+        }
+        escrow.arbiter = self.arbiter;
+        escrow.auto_release_after = self.auto_release_after;
+        escrow.evm_approver = self.evm_approver;
+        escrow.payment_methods = self.payment_methods;
+        escrow.terms_hash = self.terms_hash;
+        escrow.tags = self.tags;
+        escrow.category = self.category;
+        escrow.partial_payments = self.partial_payments;
+        escrow.release_delay = self.release_delay;
+        escrow.yield_taker_share_bps = self.yield_taker_share_bps;
+        escrow.compliance_signer = self.compliance_signer;
+        escrow.note = self.note;
+        escrow.fiat_currency = self.fiat_currency;
+        escrow.payment_memo_required = self.payment_memo_required;
+        escrow.partner_id = self.partner_id;
+        escrow.flags = self.flags & Self::FLAG_ACCEPTS_PAYMENT_PROOF;
+        escrow
+    }
+
+    pub fn is_payment_confirmed(&self) -> bool {
+        self.payment_confirmed != 0
+    }
+
+    pub fn is_funded(&self) -> bool {
+        self.status == EscrowStatus::Funded as u8
+    }
+
+    // A bond was asked for and nobody has posted it yet
+    pub fn is_bond_pending(&self) -> bool {
+        self.bond_state == BondState::Required as u8
+    }
+
+    pub fn is_bond_posted(&self) -> bool {
+        self.bond_state == BondState::Posted as u8
+    }
+
+    // Open offers must be backed by this much staked mint_a before a taker can take them
+    pub fn required_maker_deposit(&self, maker_deposit_bps: u16) -> Result<u64> {
+        pro_rata(
+            self.initializer_amount,
+            maker_deposit_bps as u64,
+            BPS_DENOMINATOR as u64,
+        )
+    }
+
+    pub fn is_vesting(&self) -> bool {
+        self.release_mode == ReleaseMode::Vesting as u8
+    }
+
+    pub fn is_streaming(&self) -> bool {
+        self.release_mode == ReleaseMode::Streaming as u8
+    }
+
+    pub fn is_immediate(&self) -> bool {
+        self.release_mode == ReleaseMode::Immediate as u8
+    }
+
+    pub fn is_milestones(&self) -> bool {
+        self.release_mode == ReleaseMode::Milestones as u8
+    }
+
+    pub fn has_arbiter(&self) -> bool {
+        self.arbiter != Pubkey::default()
+    }
+
+    // Portion of `total` unlocked at `now` under the linear schedule with cliff
+    pub fn vested_amount(&self, total: u64, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(self.release_start);
+        if elapsed < self.release_cliff {
+            0
+        } else if elapsed >= self.release_duration {
+            total
+        } else {
+            ((total as u128) * (elapsed as u128) / (self.release_duration as u128)) as u64
+        }
+    }
+
+    // Keepers fire at the cliff, then at the end of the schedule
+    pub fn next_release_at(&self, now: i64) -> i64 {
+        let cliff_at = self.release_start.saturating_add(self.release_cliff);
+        if now < cliff_at {
+            cliff_at
+        } else {
+            self.release_start.saturating_add(self.release_duration)
+        }
+    }
+
+    // Never while a dispute is open, so arbitration can't be cut short by a
+    // refund
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at != 0 && now >= self.expires_at && !self.is_dispute_paused()
+    }
+
+    // Escrows what a short deposit, e.g. of a transfer-fee mint, actually
+    // delivered, keeping the unit price: taker_amount shrinks in proportion
+    pub fn settle_deposit(&mut self, received: u64) -> Result<()> {
+        if self.initializer_amount > 0 {
+            self.taker_amount = pro_rata(self.taker_amount, received, self.initializer_amount)?;
+        }
+        self.initializer_amount = received;
+        Ok(())
+    }
+
+    // Expired for longer than `grace_period`, which is when `reap_expired`
+    // may close it
+    pub fn is_reapable(&self, now: i64, grace_period: i64) -> bool {
+        self.is_expired(now.saturating_sub(grace_period))
+    }
+
+    pub fn is_dispute_paused(&self) -> bool {
+        self.open_disputes > 0
+    }
+
+    pub fn pause_for_dispute(&mut self, now: i64) -> Result<()> {
+        if self.open_disputes == 0 {
+            self.dispute_paused_at = now;
+        }
+        self.open_disputes = self
+            .open_disputes
+            .checked_add(1)
+            .ok_or(EscrowError::MathOverflow)?;
+        Ok(())
+    }
+
+    // Once the last open dispute is resolved, the deadline moves back by the
+    // time spent in arbitration. Disputes raised before the pause existed
+    // never counted, so there is nothing to undo for them.
+    pub fn resume_after_dispute(&mut self, now: i64) {
+        if self.open_disputes == 0 {
+            return;
+        }
+        self.open_disputes -= 1;
+        if self.open_disputes > 0 {
+            return;
+        }
+        let paused = now.saturating_sub(self.dispute_paused_at).max(0);
+        if self.expires_at != 0 {
+            if self.next_action_at == self.expires_at {
+                self.next_action_at = self.next_action_at.saturating_add(paused);
+            }
+            self.expires_at = self.expires_at.saturating_add(paused);
+        }
+        self.dispute_paused_at = 0;
+    }
+
+    // When an immediate escrow the seller sat on becomes force-releasable, if ever
+    pub fn auto_release_at(&self, config: &Config) -> Option<i64> {
+        let window = if self.auto_release_after > 0 {
+            self.auto_release_after
+        } else {
+            config.auto_release_after_hours as i64 * SECONDS_PER_HOUR
+        };
+        if !self.is_payment_confirmed() || !self.is_immediate() || self.is_dispute_paused() {
+            return None;
+        }
+        // A verified proof leaves the seller nothing to check, though the
+        // release delay still runs
+        if self.has_flag(Self::FLAG_PAYMENT_PROVEN) {
+            return Some(self.release_unlocked_at());
+        }
+        if window == 0 {
+            return None;
+        }
+        Some(
+            self.payment_confirmed_at
+                .saturating_add(window)
+                .max(self.release_unlocked_at()),
+        )
+    }
+
+    // Earliest time a confirmed escrow may pay out, by anyone
+    pub fn release_unlocked_at(&self) -> i64 {
+        self.payment_confirmed_at.saturating_add(self.release_delay)
+    }
+
+    pub fn is_release_unlocked(&self, now: i64) -> bool {
+        now >= self.release_unlocked_at()
+    }
+
+    // Same for the tranches confirmed so far, counted from the latest one
+    pub fn is_tranche_release_unlocked(&self, now: i64) -> bool {
+        now >= self.last_tranche_at.saturating_add(self.release_delay)
+    }
+
+    pub fn mark_payment_confirmed(&mut self, now: i64, config: &Config) {
+        self.payment_confirmed = 1;
+        self.payment_confirmed_at = now;
+        // Confirmed offers can no longer be reaped, only force-released once
+        // the seller's window lapses
+        self.next_action_at = self.auto_release_at(config).unwrap_or(0);
+        if self.is_streaming() {
+            self.status = EscrowStatus::Releasing as u8;
+            self.release_start = now.saturating_add(self.release_delay);
+        }
+    }
+
+    pub fn accepts_partial_payments(&self) -> bool {
+        self.partial_payments != PartialPayments::Disabled as u8
+    }
+
+    pub fn releases_per_tranche(&self) -> bool {
+        self.partial_payments == PartialPayments::PerTranche as u8
+    }
+
+    // The taker has paid part of the price, so the offer is no longer the
+    // maker's to hand to someone else or let lapse
+    pub fn has_partial_payment(&self) -> bool {
+        self.payment_tranches > 0
+    }
+
+    // The taker has paid in full or in part. Cancelling then backs out on
+    // money already sent, so it counts as a strike against the maker even
+    // when only a tranche was confirmed
+    pub fn has_taker_paid(&self) -> bool {
+        self.is_payment_confirmed() || self.has_partial_payment()
+    }
+
+    // Records a tranche of `amount` fiat; true once the whole price is confirmed
+    pub fn confirm_tranche(&mut self, amount: u64, now: i64) -> Result<bool> {
+        let confirmed = self
+            .confirmed_amount
+            .checked_add(amount)
+            .ok_or(EscrowError::MathOverflow)?;
+        require!(
+            amount > 0 && confirmed <= self.taker_amount,
+            EscrowError::InvalidPaymentTranche
+        );
+        self.confirmed_amount = confirmed;
+        self.payment_tranches = self.payment_tranches.saturating_add(1);
+        self.last_tranche_at = now;
+        Ok(confirmed == self.taker_amount)
+    }
+
+    // mint_a the confirmed but unreleased fiat pays for, at the offer's price
+    pub fn tranche_release_amount(&self) -> Result<u64> {
+        pro_rata(
+            self.initializer_amount,
+            self.confirmed_amount,
+            self.taker_amount,
+        )
+    }
+
+    // Shrinks the escrow to what is left after releasing `released` mint_a
+    // for the confirmed tranches, so every other settlement path simply works
+    // on the remainder
+    pub fn settle_tranche(&mut self, released: u64) {
+        self.initializer_amount -= released;
+        self.taker_amount -= self.confirmed_amount;
+        self.confirmed_amount = 0;
+    }
+
+    // Vesting pays its platform fee, and any partner's fee, when it starts;
+    // what is left is what the schedule releases
+    pub fn settle_upfront_fee(&mut self, fees: u64) {
+        self.initializer_amount -= fees;
+    }
+
+    pub fn has_rate_snapshot(&self) -> bool {
+        self.rate_snapshot != 0
+    }
+
+    pub fn has_compliance_signer(&self) -> bool {
+        self.compliance_signer != Pubkey::default()
+    }
+
+    pub fn has_funder(&self) -> bool {
+        self.funder != Pubkey::default()
+    }
+
+    // Owner of the wallet a cancelled offer's mint_a goes back to
+    pub fn refund_owner(&self) -> Pubkey {
+        if self.has_funder() {
+            self.funder
+        } else {
+            self.initializer
+        }
+    }
+
+    // mint_a the vault still has to hold for this escrow. Delegated escrows
+    // and parked yield positions keep it elsewhere, and milestone escrows
+    // don't record refunded milestones, so those owe nothing here.
+    pub fn vault_owed(&self) -> Result<u64> {
+        if self.status == EscrowStatus::Created as u8
+            || self.is_delegated()
+            || self.has_yield_position()
+            || self.is_milestones()
+        {
+            return Ok(0);
+        }
+        Ok(self.initializer_amount.saturating_sub(self.released_amount))
+    }
+
+    // An open offer `match_offers` may cross against an opposing one: funded,
+    // untaken, paid out in one go, with nothing held outside the vault or
+    // owed to a partner, and no rate band there is no feed to check against
+    pub fn is_matchable(&self, now: i64) -> bool {
+        self.is_funded()
+            && !self.has_taker()
+            && self.is_immediate()
+            && !self.is_expired(now)
+            && !self.is_frozen()
+            && !self.is_delegated()
+            && !self.is_bond_pending()
+            && !self.has_hidden_price()
+            && !self.has_flag(Self::FLAG_FIAT_QUOTES)
+            && !self.has_yield_position()
+            && self.maker_deposit == 0
+            && !self.has_partner()
+            && !self.has_rate_snapshot()
+    }
+
+    pub fn has_partner(&self) -> bool {
+        self.partner_id != 0
+    }
+
+    pub fn requires_payment_memo(&self) -> bool {
+        self.payment_memo_required != 0
+    }
+
+    pub fn has_donation_pledge(&self) -> bool {
+        self.donation_pledged != 0
+    }
+
+    pub fn has_yield_position(&self) -> bool {
+        self.yield_principal != 0
+    }
+
+    pub fn has_evm_approver(&self) -> bool {
+        self.evm_approver != [0; 20]
+    }
+
+    pub fn has_flag(&self, flag: u8) -> bool {
+        self.flags & flag != 0
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.has_flag(Self::FLAG_FROZEN)
+    }
+
+    pub fn is_delegated(&self) -> bool {
+        self.has_flag(Self::FLAG_DELEGATED)
+    }
+
+    pub fn has_hidden_price(&self) -> bool {
+        self.price_commitment != [0; 32]
+    }
+
+    // What a maker publishes instead of the price: binding the escrow stops
+    // the commitment being copied onto another offer, and the salt stops the
+    // price being brute-forced from the public size
+    pub fn hidden_price_commitment(
+        escrow: &Pubkey,
+        taker_amount: u64,
+        salt: &[u8; 32],
+    ) -> [u8; 32] {
+        hashv(&[
+            PRICE_COMMITMENT_DOMAIN,
+            escrow.as_ref(),
+            &taker_amount.to_le_bytes(),
+            salt,
+        ])
+        .to_bytes()
+    }
+
+    pub fn has_taker(&self) -> bool {
+        self.taker != Pubkey::default()
+    }
+
+    pub fn assign_taker(&mut self, taker: Pubkey, now: i64) {
+        self.taker = taker;
+        self.taker_assigned_at = if taker == Pubkey::default() { 0 } else { now };
+    }
+}
+
+// Borsh layout written before the zero-copy conversion (version 0). Shares
+// the `Escrow` discriminator, so it is told apart by its allocated size.
+#[derive(AnchorDeserialize)]
+pub struct EscrowV0 {
     pub seed: u64,
     pub bump: u8,
     pub initializer: Pubkey,
@@ -12,7 +732,396 @@ pub struct Escrow {
     pub payment_confirmed: bool,
 }
 
-impl Space for Escrow {
-    // First 8 Bytes are Discriminator (u64)
-    const INIT_SPACE: usize = 8 + 8 + 1 + 32 + 32 + 32 + 8 + 8 + 1 + 1 + 32;
+impl EscrowV0 {
+    pub const SPACE: usize = 8 + 8 + 1 + 32 + 32 + 32 + 8 + 8 + 1 + 1 + 32;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::mem::offset_of;
+
+    // Offsets as seen by memcmp filters, i.e. after the discriminator
+    fn offset(field_offset: usize) -> usize {
+        8 + field_offset
+    }
+
+    #[test]
+    fn escrow_layout_offsets_are_stable() {
+        assert_eq!(offset(offset_of!(Escrow, version)), 8);
+        assert_eq!(offset(offset_of!(Escrow, bump)), 9);
+        assert_eq!(offset(offset_of!(Escrow, payment_confirmed)), 10);
+        assert_eq!(offset(offset_of!(Escrow, status)), 11);
+        assert_eq!(offset(offset_of!(Escrow, release_mode)), 12);
+        assert_eq!(offset(offset_of!(Escrow, milestone_count)), 13);
+        assert_eq!(offset(offset_of!(Escrow, milestones_released)), 14);
+        assert_eq!(offset(offset_of!(Escrow, bond_state)), 15);
+        assert_eq!(offset(offset_of!(Escrow, seed)), 16);
+        assert_eq!(offset(offset_of!(Escrow, initializer_amount)), 24);
+        assert_eq!(offset(offset_of!(Escrow, taker_amount)), 32);
+        assert_eq!(offset(offset_of!(Escrow, initializer)), 40);
+        assert_eq!(offset(offset_of!(Escrow, mint_a)), 72);
+        assert_eq!(offset(offset_of!(Escrow, mint_b)), 104);
+        assert_eq!(offset(offset_of!(Escrow, taker_assigned_at)), 136);
+        assert_eq!(offset(offset_of!(Escrow, taker)), 144);
+        assert_eq!(offset(offset_of!(Escrow, release_start)), 176);
+        assert_eq!(offset(offset_of!(Escrow, release_cliff)), 184);
+        assert_eq!(offset(offset_of!(Escrow, release_duration)), 192);
+        assert_eq!(offset(offset_of!(Escrow, released_amount)), 200);
+        assert_eq!(offset(offset_of!(Escrow, arbiter)), 208);
+        assert_eq!(offset(offset_of!(Escrow, milestone_total)), 240);
+        assert_eq!(offset(offset_of!(Escrow, maker_deposit)), 248);
+        assert_eq!(offset(offset_of!(Escrow, flags)), 256);
+        assert_eq!(offset(offset_of!(Escrow, _reserved)), 257);
+        assert_eq!(offset(offset_of!(Escrow, expires_at)), 264);
+        assert_eq!(offset(offset_of!(Escrow, next_action_at)), 272);
+        assert_eq!(offset(offset_of!(Escrow, payment_confirmed_at)), 280);
+        assert_eq!(offset(offset_of!(Escrow, auto_release_after)), 288);
+        assert_eq!(offset(offset_of!(Escrow, evm_approver)), 296);
+        assert_eq!(offset(offset_of!(Escrow, payment_methods)), 316);
+        assert_eq!(offset(offset_of!(Escrow, dispute_paused_at)), 320);
+        assert_eq!(offset(offset_of!(Escrow, open_disputes)), 328);
+        assert_eq!(offset(offset_of!(Escrow, payment_memo_required)), 329);
+        assert_eq!(offset(offset_of!(Escrow, partner_id)), 330);
+        assert_eq!(offset(offset_of!(Escrow, _reserved_v2)), 332);
+        assert_eq!(offset(offset_of!(Escrow, terms_hash)), 344);
+        assert_eq!(offset(offset_of!(Escrow, sequence)), 376);
+        assert_eq!(offset(offset_of!(Escrow, tags)), 384);
+        assert_eq!(offset(offset_of!(Escrow, category)), 388);
+        assert_eq!(offset(offset_of!(Escrow, partial_payments)), 389);
+        assert_eq!(offset(offset_of!(Escrow, payment_tranches)), 390);
+        assert_eq!(offset(offset_of!(Escrow, confirmed_amount)), 392);
+        assert_eq!(offset(offset_of!(Escrow, release_delay)), 400);
+        assert_eq!(offset(offset_of!(Escrow, last_tranche_at)), 408);
+        assert_eq!(offset(offset_of!(Escrow, program_version)), 416);
+        assert_eq!(offset(offset_of!(Escrow, donation_pledged)), 419);
+        assert_eq!(offset(offset_of!(Escrow, fiat_currency)), 420);
+        assert_eq!(offset(offset_of!(Escrow, yield_taker_share_bps)), 422);
+        assert_eq!(offset(offset_of!(Escrow, rate_snapshot)), 424);
+        assert_eq!(offset(offset_of!(Escrow, yield_principal)), 432);
+        assert_eq!(offset(offset_of!(Escrow, funder)), 440);
+        assert_eq!(offset(offset_of!(Escrow, compliance_signer)), 472);
+        assert_eq!(offset(offset_of!(Escrow, price_commitment)), 504);
+        assert_eq!(offset(offset_of!(Escrow, note)), 536);
+    }
+
+    #[test]
+    fn filter_offsets_match_the_layout() {
+        assert_eq!(offset(offset_of!(Escrow, status)), Escrow::STATUS_OFFSET);
+        assert_eq!(offset(offset_of!(Escrow, initializer)), Escrow::INITIALIZER_OFFSET);
+        assert_eq!(offset(offset_of!(Escrow, mint_a)), Escrow::MINT_A_OFFSET);
+        assert_eq!(offset(offset_of!(Escrow, mint_b)), Escrow::MINT_B_OFFSET);
+        assert_eq!(offset(offset_of!(Escrow, taker)), Escrow::TAKER_OFFSET);
+        assert_eq!(offset(offset_of!(Escrow, flags)), Escrow::FLAGS_OFFSET);
+        assert_eq!(offset(offset_of!(Escrow, expires_at)), Escrow::EXPIRES_AT_OFFSET);
+        assert_eq!(offset(offset_of!(Escrow, tags)), Escrow::TAGS_OFFSET);
+        assert_eq!(offset(offset_of!(Escrow, category)), Escrow::CATEGORY_OFFSET);
+    }
+
+    #[test]
+    fn escrow_space_is_stable() {
+        assert_eq!(Escrow::SPACE, 600);
+        assert_eq!(offset(offset_of!(Escrow, expires_at)), Escrow::V1_SPACE);
+        assert_eq!(offset(offset_of!(Escrow, terms_hash)), Escrow::V2_SPACE);
+        assert_eq!(offset(offset_of!(Escrow, funder)), Escrow::V3_SPACE);
+        assert_eq!(offset(offset_of!(Escrow, note)), Escrow::V4_SPACE);
+    }
+
+    #[test]
+    fn vesting_unlocks_linearly_after_the_cliff() {
+        let mut escrow = Escrow::new(
+            1,
+            255,
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            0,
+            0,
+        );
+        escrow.release_start = 1_000;
+        escrow.release_cliff = 100;
+        escrow.release_duration = 400;
+
+        assert_eq!(escrow.vested_amount(800, 900), 0);
+        assert_eq!(escrow.vested_amount(800, 1_099), 0);
+        assert_eq!(escrow.vested_amount(800, 1_100), 200);
+        assert_eq!(escrow.vested_amount(800, 1_200), 400);
+        assert_eq!(escrow.vested_amount(800, 1_400), 800);
+        assert_eq!(escrow.vested_amount(800, 5_000), 800);
+
+        assert_eq!(escrow.next_release_at(900), 1_100);
+        assert_eq!(escrow.next_release_at(1_100), 1_400);
+    }
+
+    #[test]
+    fn tranches_release_pro_rata_and_shrink_the_escrow() {
+        let mut escrow = Escrow::new(
+            1,
+            255,
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            1_000,
+            500,
+        );
+        escrow.partial_payments = PartialPayments::PerTranche as u8;
+
+        assert!(escrow.confirm_tranche(0, 0).is_err());
+        assert!(!escrow.confirm_tranche(150, 0).unwrap());
+        assert!(!escrow.confirm_tranche(50, 0).unwrap());
+        assert_eq!((escrow.confirmed_amount, escrow.payment_tranches), (200, 2));
+        assert_eq!(escrow.tranche_release_amount().unwrap(), 400);
+        assert!(!escrow.is_payment_confirmed());
+        assert!(escrow.has_taker_paid());
+
+        escrow.settle_tranche(400);
+        assert_eq!((escrow.initializer_amount, escrow.taker_amount), (600, 300));
+        assert_eq!(escrow.confirmed_amount, 0);
+        assert!(escrow.has_partial_payment());
+
+        assert!(escrow.confirm_tranche(301, 0).is_err());
+        assert!(escrow.confirm_tranche(300, 0).unwrap());
+        assert_eq!(escrow.tranche_release_amount().unwrap(), 600);
+    }
+
+    #[test]
+    fn release_delay_counts_from_the_latest_confirmation() {
+        let mut escrow = Escrow::new(
+            1,
+            255,
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            1_000,
+            500,
+        );
+        escrow.release_delay = 600;
+        escrow.payment_confirmed_at = 1_000;
+        assert!(!escrow.is_release_unlocked(1_599));
+        assert!(escrow.is_release_unlocked(1_600));
+
+        escrow.confirm_tranche(100, 2_000).unwrap();
+        assert!(!escrow.is_tranche_release_unlocked(2_599));
+        assert!(escrow.is_tranche_release_unlocked(2_600));
+    }
+
+    #[test]
+    fn relisting_keeps_terms_and_drops_runtime_state() {
+        let maker = Pubkey::new_unique();
+        let mut escrow = Escrow::new(
+            1,
+            255,
+            maker,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            800,
+            40,
+        );
+        escrow.release_mode = ReleaseMode::Vesting as u8;
+        escrow.release_start = 1_000;
+        escrow.release_cliff = 100;
+        escrow.release_duration = 400;
+        escrow.status = EscrowStatus::Releasing as u8;
+        escrow.payment_confirmed = 1;
+        escrow.assign_taker(Pubkey::new_unique(), 900);
+        escrow.flags = Escrow::FLAG_FROZEN | Escrow::FLAG_ACCEPTS_PAYMENT_PROOF;
+        escrow.tags = 0b1010;
+        escrow.category = 3;
+        escrow.yield_taker_share_bps = 2_500;
+        escrow.yield_principal = 700;
+        escrow.funder = Pubkey::new_unique();
+        escrow.compliance_signer = Pubkey::new_unique();
+        escrow.note[..7].copy_from_slice(b"INV-042");
+        escrow.fiat_currency = 356;
+
+        let relisted = escrow.relisted(2, 254);
+        assert_eq!((relisted.seed, relisted.bump), (2, 254));
+        assert_eq!(relisted.initializer, maker);
+        assert_eq!(relisted.mint_a, escrow.mint_a);
+        assert_eq!(relisted.initializer_amount, 800);
+        assert_eq!(relisted.taker_amount, 40);
+        assert_eq!((relisted.tags, relisted.category), (0b1010, 3));
+        assert_eq!(relisted.yield_taker_share_bps, 2_500);
+        assert!(!relisted.has_yield_position());
+        assert!(!relisted.has_funder());
+        assert_eq!(relisted.compliance_signer, escrow.compliance_signer);
+        assert_eq!(relisted.note, escrow.note);
+        assert_eq!(relisted.fiat_currency, 356);
+        assert!(relisted.is_vesting());
+        assert_eq!(relisted.release_start, 0);
+        assert_eq!(relisted.release_duration, 400);
+        assert_eq!(relisted.status, EscrowStatus::Created as u8);
+        assert!(!relisted.is_payment_confirmed());
+        assert!(!relisted.has_taker());
+        assert_eq!(relisted.flags, Escrow::FLAG_ACCEPTS_PAYMENT_PROOF);
+    }
+
+    #[test]
+    fn refunds_go_to_the_funder_when_there_is_one() {
+        let maker = Pubkey::new_unique();
+        let mut escrow = Escrow::new(
+            1,
+            255,
+            maker,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            800,
+            40,
+        );
+        assert_eq!(escrow.refund_owner(), maker);
+
+        let treasury = Pubkey::new_unique();
+        escrow.funder = treasury;
+        assert_eq!(escrow.refund_owner(), treasury);
+    }
+
+    #[test]
+    fn vault_owes_what_has_not_been_released() {
+        let mut escrow = Escrow::new(
+            1,
+            255,
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            1_000,
+            50,
+        );
+        assert_eq!(escrow.vault_owed().unwrap(), 0);
+
+        escrow.status = EscrowStatus::Funded as u8;
+        assert_eq!(escrow.vault_owed().unwrap(), 1_000);
+
+        escrow.release_mode = ReleaseMode::Vesting as u8;
+        escrow.status = EscrowStatus::Releasing as u8;
+        escrow.settle_upfront_fee(60);
+        escrow.released_amount = 140;
+        assert_eq!(escrow.vault_owed().unwrap(), 800);
+
+        escrow.release_mode = ReleaseMode::Milestones as u8;
+        assert_eq!(escrow.vault_owed().unwrap(), 0);
+    }
+
+    #[test]
+    fn open_disputes_pause_the_deadline() {
+        let mut escrow = Escrow::new(
+            1,
+            255,
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            0,
+            0,
+        );
+        escrow.expires_at = 1_000;
+        escrow.next_action_at = 1_000;
+
+        escrow.pause_for_dispute(900).unwrap();
+        escrow.pause_for_dispute(950).unwrap();
+        assert!(!escrow.is_expired(1_500));
+
+        escrow.resume_after_dispute(1_200);
+        assert!(!escrow.is_expired(1_500));
+        assert_eq!(escrow.expires_at, 1_000);
+
+        escrow.resume_after_dispute(1_300);
+        assert_eq!((escrow.expires_at, escrow.next_action_at), (1_400, 1_400));
+        assert!(!escrow.is_expired(1_399));
+        assert!(escrow.is_expired(1_400));
+        assert!(!escrow.is_reapable(1_400, 60));
+        assert!(escrow.is_reapable(1_460, 60));
+
+        // Disputes from before the pause existed resolve without effect
+        escrow.resume_after_dispute(2_000);
+        assert_eq!(escrow.expires_at, 1_400);
+    }
+
+    #[test]
+    fn price_commitment_binds_escrow_price_and_salt() {
+        let (key, salt) = (Pubkey::new_unique(), [7u8; 32]);
+        let commitment = Escrow::hidden_price_commitment(&key, 500, &salt);
+
+        assert_eq!(
+            commitment,
+            Escrow::hidden_price_commitment(&key, 500, &salt)
+        );
+        assert_ne!(
+            commitment,
+            Escrow::hidden_price_commitment(&key, 501, &salt)
+        );
+        assert_ne!(
+            commitment,
+            Escrow::hidden_price_commitment(&key, 500, &[8; 32])
+        );
+        assert_ne!(
+            commitment,
+            Escrow::hidden_price_commitment(&Pubkey::new_unique(), 500, &salt)
+        );
+
+        let mut escrow = Escrow::new(
+            1,
+            255,
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            0,
+            0,
+        );
+        assert!(!escrow.has_hidden_price());
+        escrow.price_commitment = commitment;
+        assert!(escrow.has_hidden_price());
+    }
+
+    #[test]
+    fn only_plain_open_offers_are_matchable() {
+        let mut escrow = Escrow::new(
+            1,
+            255,
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            100,
+            50,
+        );
+        assert!(!escrow.is_matchable(0));
+        escrow.status = EscrowStatus::Funded as u8;
+        assert!(escrow.is_matchable(0));
+
+        escrow.expires_at = 1_000;
+        assert!(!escrow.is_matchable(1_000));
+        escrow.flags = Escrow::FLAG_FIAT_QUOTES;
+        assert!(!escrow.is_matchable(0));
+        escrow.flags = 0;
+        escrow.rate_snapshot = 1_000;
+        assert!(!escrow.is_matchable(0));
+        escrow.rate_snapshot = 0;
+        escrow.assign_taker(Pubkey::new_unique(), 0);
+        assert!(!escrow.is_matchable(0));
+    }
+
+    #[test]
+    fn short_deposit_keeps_the_unit_price() {
+        let mut escrow = Escrow::new(
+            1,
+            255,
+            Pubkey::default(),
+            Pubkey::default(),
+            Pubkey::default(),
+            1_000,
+            500,
+        );
+        escrow.settle_deposit(1_000).unwrap();
+        assert_eq!((escrow.initializer_amount, escrow.taker_amount), (1_000, 500));
+        // A 1% transfer fee
+        escrow.settle_deposit(990).unwrap();
+        assert_eq!((escrow.initializer_amount, escrow.taker_amount), (990, 495));
+    }
+
+    #[test]
+    fn legacy_layout_is_distinguishable_by_size() {
+        assert_ne!(EscrowV0::SPACE, Escrow::SPACE);
+        assert_ne!(EscrowV0::SPACE, Escrow::V1_SPACE);
+        assert_ne!(EscrowV0::SPACE, Escrow::V2_SPACE);
+        assert_ne!(EscrowV0::SPACE, Escrow::V3_SPACE);
+        assert_ne!(EscrowV0::SPACE, Escrow::V4_SPACE);
+    }
 }
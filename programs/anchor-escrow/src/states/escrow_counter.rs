@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use std::convert::TryInto;
+
+use crate::errors::EscrowError;
+
+// Escrows created so far, at [b"escrow_counter"]. Each counted escrow stores
+// its position in `Escrow::sequence`, so indexers can tell when they have
+// missed one without scanning getProgramAccounts. Every counted creation
+// write-locks it, so those transactions land one at a time.
+#[account]
+#[derive(InitSpace)]
+pub struct EscrowCounter {
+    pub version: u8,
+    pub bump: u8,
+    pub count: u64,
+    pub _reserved: [u8; 32],
+}
+
+impl EscrowCounter {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + EscrowCounter::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    // The counter is created lazily by the first escrow that counts
+    pub fn init_if_new(&mut self, bump: u8) {
+        if self.version == 0 {
+            self.version = EscrowCounter::VERSION;
+            self.bump = bump;
+        }
+    }
+
+    // Counts one more escrow, returning its 1-based sequence number
+    pub fn next_sequence(&mut self) -> Result<u64> {
+        self.count = self.count.checked_add(1).ok_or(EscrowError::MathOverflow)?;
+        Ok(self.count)
+    }
+
+    // Seed of `initializer`'s `n`th sequential escrow, so a maker's escrows
+    // sit at addresses anyone can derive from TraderStats::sequential_escrows
+    pub fn sequential_seed(initializer: &Pubkey, n: u32) -> u64 {
+        let hash = hashv(&[b"sequential", initializer.as_ref(), &n.to_le_bytes()]);
+        u64::from_le_bytes(hash.to_bytes()[..8].try_into().unwrap())
+    }
+}
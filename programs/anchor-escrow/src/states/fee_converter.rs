@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+// Treasury upkeep settings, at [b"fee_converter"]. The platform wallet
+// approves this PDA as delegate on its fee accounts, which lets the admin or
+// `keeper` swap what they collected into `settlement_mint` through the swap
+// router without the treasury key having to sign every conversion.
+#[account]
+#[derive(InitSpace)]
+pub struct FeeConverter {
+    pub version: u8,
+    pub bump: u8,
+    // The single currency fees are consolidated into
+    pub settlement_mint: Pubkey,
+    // May run `convert_fees` besides the admin; default allows only the admin.
+    // The keeper picks the route and its minimum, so it is trusted with
+    // whatever the wallet's approvals cap the converter at.
+    pub keeper: Pubkey,
+    pub _reserved: [u8; 32],
+}
+
+impl FeeConverter {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + FeeConverter::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    pub fn may_convert(&self, config_admin: &Pubkey, caller: &Pubkey) -> bool {
+        caller == config_admin || (self.keeper != Pubkey::default() && *caller == self.keeper)
+    }
+}
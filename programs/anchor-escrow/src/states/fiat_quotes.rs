@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_FIAT_QUOTES;
+
+// Fiat currencies an offer can be paid in, each at its own price, at
+// [b"fiat_quotes", escrow]; the taker picks one when accepting. Lets a maker
+// serve several markets with one vault instead of an offer per currency.
+#[account]
+#[derive(InitSpace)]
+pub struct FiatQuotes {
+    pub version: u8,
+    pub bump: u8,
+    pub quote_count: u8,
+    pub escrow: Pubkey,
+    pub quotes: [FiatQuote; MAX_FIAT_QUOTES],
+    pub _reserved: [u8; 32],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Debug, Default, PartialEq)]
+pub struct FiatQuote {
+    // ISO 4217 numeric code, e.g. 356 for INR
+    pub currency: u16,
+    // Becomes Escrow::taker_amount when the taker picks this currency
+    pub taker_amount: u64,
+}
+
+impl FiatQuotes {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + FiatQuotes::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    pub fn listed(&self) -> &[FiatQuote] {
+        &self.quotes[..self.quote_count as usize]
+    }
+
+    pub fn price(&self, currency: u16) -> Option<u64> {
+        self.listed()
+            .iter()
+            .find(|quote| quote.currency == currency)
+            .map(|quote| quote.taker_amount)
+    }
+
+    pub fn set(&mut self, quotes: &[FiatQuote]) {
+        self.quote_count = quotes.len() as u8;
+        self.quotes = [FiatQuote::default(); MAX_FIAT_QUOTES];
+        self.quotes[..quotes.len()].copy_from_slice(quotes);
+    }
+}
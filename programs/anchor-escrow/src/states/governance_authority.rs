@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+// The DAO backstop for escalated disputes, at [b"governance"]. `authority` is
+// the native treasury of the DAO's SPL Governance governance: passed
+// proposals execute `governance_resolve` signed by it, and it pays the rent
+// the ruling needs.
+#[account]
+#[derive(InitSpace)]
+pub struct GovernanceAuthority {
+    pub version: u8,
+    pub bump: u8,
+    pub authority: Pubkey,
+    pub _reserved: [u8; 32],
+}
+
+impl GovernanceAuthority {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + GovernanceAuthority::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
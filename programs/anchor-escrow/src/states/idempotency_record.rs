@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+// Claims a maker's client-chosen idempotency key, at [b"idempotency", maker,
+// key]. A wallet that retries an `initialize` with a fresh seed but the same
+// key fails on this account instead of locking the deposit twice.
+#[account]
+#[derive(InitSpace)]
+pub struct IdempotencyRecord {
+    pub version: u8,
+    pub bump: u8,
+    pub maker: Pubkey,
+    pub idempotency_key: [u8; 16],
+    // The escrow the first attempt opened
+    pub escrow: Pubkey,
+    pub rent_payer: Pubkey,
+    pub created_at: i64,
+    pub _reserved: [u8; 16],
+}
+
+impl IdempotencyRecord {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + IdempotencyRecord::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_JURORS;
+
+// A panel of registered arbitrators deciding one milestone dispute by
+// majority, at [b"jury", dispute]. While it exists the escrow's own arbiter
+// can't rule; `tally_and_resolve` executes the verdict instead. Jurors
+// commit to a hidden vote until `commit_ends_at`, then reveal it until
+// `reveal_ends_at`; only revealed votes are counted.
+#[account]
+#[derive(InitSpace)]
+pub struct Jury {
+    pub version: u8,
+    pub bump: u8,
+    pub juror_count: u8,
+    pub votes_for_taker: u8,
+    pub votes_for_initializer: u8,
+    pub dispute: Pubkey,
+    // Only the first `juror_count` entries are meaningful
+    pub jurors: [Pubkey; MAX_JURORS],
+    pub convened_at: i64,
+    pub commit_ends_at: i64,
+    pub reveal_ends_at: i64,
+    pub _reserved: [u8; 16],
+}
+
+impl Jury {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + Jury::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    pub fn is_juror(&self, wallet: &Pubkey) -> bool {
+        self.jurors[..self.juror_count as usize].contains(wallet)
+    }
+
+    pub fn is_committing(&self, now: i64) -> bool {
+        now < self.commit_ends_at
+    }
+
+    pub fn is_revealing(&self, now: i64) -> bool {
+        self.commit_ends_at <= now && now < self.reveal_ends_at
+    }
+
+    // Some(true) pays the tranche to the taker, Some(false) refunds the
+    // initializer. A strict majority of the panel decides as soon as it is
+    // revealed; after the reveal deadline the revealed votes decide, and a
+    // tie is a hung jury.
+    pub fn verdict(&self, now: i64) -> Option<bool> {
+        let (for_taker, for_initializer) = (self.votes_for_taker, self.votes_for_initializer);
+        if 2 * for_taker > self.juror_count {
+            Some(true)
+        } else if 2 * for_initializer > self.juror_count {
+            Some(false)
+        } else if now < self.reveal_ends_at || for_taker == for_initializer {
+            None
+        } else {
+            Some(for_taker > for_initializer)
+        }
+    }
+}
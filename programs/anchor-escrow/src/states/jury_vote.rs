@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+// Prefixes every vote commitment so it can't be replayed from, or into,
+// another protocol's scheme
+const VOTE_COMMITMENT_DOMAIN: &[u8] = b"anchor-escrow:jury-vote:v1";
+
+// One juror's ballot, at [b"jury_vote", jury, juror]; its existence stops a
+// juror voting twice. Holds only the commitment until it is revealed.
+#[account]
+#[derive(InitSpace)]
+pub struct JuryVote {
+    pub version: u8,
+    pub bump: u8,
+    pub revealed: bool,
+    // Meaningful once revealed
+    pub release_to_taker: bool,
+    // The juror failed to reveal and had their stake slashed for it
+    pub penalized: bool,
+    pub jury: Pubkey,
+    pub juror: Pubkey,
+    pub commitment: [u8; 32],
+    pub committed_at: i64,
+}
+
+impl JuryVote {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + JuryVote::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    // What a juror commits to: binding the jury and juror stops a commitment
+    // being copied to another juror's ballot, and the salt stops the two
+    // possible votes being brute-forced
+    pub fn commitment(
+        jury: &Pubkey,
+        juror: &Pubkey,
+        release_to_taker: bool,
+        salt: &[u8; 32],
+    ) -> [u8; 32] {
+        hashv(&[
+            VOTE_COMMITMENT_DOMAIN,
+            jury.as_ref(),
+            juror.as_ref(),
+            &[release_to_taker as u8],
+            salt,
+        ])
+        .to_bytes()
+    }
+}
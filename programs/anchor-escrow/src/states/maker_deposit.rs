@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+// Maker stake behind one open offer, at [b"maker_deposit", escrow]. The mint_a
+// sits in this account's associated token account.
+#[account]
+#[derive(InitSpace)]
+pub struct MakerDeposit {
+    pub version: u8,
+    pub bump: u8,
+    pub forfeited: bool,
+    pub escrow: Pubkey,
+    pub maker: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    // Receives a forfeited deposit: the taker whose confirmed payment was cancelled on
+    pub taker: Pubkey,
+    pub _reserved: [u8; 32],
+}
+
+impl MakerDeposit {
+    pub fn recipient(&self) -> Pubkey {
+        if self.forfeited {
+            self.taker
+        } else {
+            self.maker
+        }
+    }
+
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + MakerDeposit::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
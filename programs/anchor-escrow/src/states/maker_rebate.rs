@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+
+// Rebates a maker has earned in one mint, at [b"maker_rebate", maker, mint].
+// The tokens themselves sit in the [b"rebate_pool"] PDA's ATA until claimed.
+#[account]
+#[derive(InitSpace)]
+pub struct MakerRebate {
+    pub version: u8,
+    pub bump: u8,
+    pub maker: Pubkey,
+    pub mint: Pubkey,
+    // Earned and not yet claimed
+    pub accrued: u64,
+    pub total_claimed: u64,
+    pub updated_at: i64,
+    pub _reserved: [u8; 32],
+}
+
+impl MakerRebate {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + MakerRebate::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    // Rebate accounts are created lazily on the maker's first rebated fill
+    pub fn init_if_new(&mut self, maker: Pubkey, mint: Pubkey, bump: u8) {
+        if self.version == 0 {
+            self.version = MakerRebate::VERSION;
+            self.bump = bump;
+            self.maker = maker;
+            self.mint = mint;
+        }
+    }
+
+    pub fn accrue(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.accrued = self
+            .accrued
+            .checked_add(amount)
+            .ok_or(EscrowError::MathOverflow)?;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    // Empties the balance, returning what is owed
+    pub fn take_accrued(&mut self, now: i64) -> Result<u64> {
+        let amount = self.accrued;
+        self.accrued = 0;
+        self.total_claimed = self
+            .total_claimed
+            .checked_add(amount)
+            .ok_or(EscrowError::MathOverflow)?;
+        self.updated_at = now;
+        Ok(amount)
+    }
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_REGISTRY_ESCROWS;
+use crate::errors::EscrowError;
+
+// A maker's open escrows, at [b"registry", maker], so a wallet can list them
+// with one account fetch plus one getMultipleAccounts instead of a program
+// scan. Every create path adds to it and every path that closes an escrow
+// removes from it; `prune_registry` drops escrows closed before that was so.
+#[account]
+#[derive(InitSpace)]
+pub struct MakerRegistry {
+    pub version: u8,
+    pub bump: u8,
+    pub maker: Pubkey,
+    pub count: u16,
+    // Only the first `count` entries are meaningful; order isn't kept
+    pub escrows: [Pubkey; MAX_REGISTRY_ESCROWS],
+    pub _reserved: [u8; 32],
+}
+
+impl MakerRegistry {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + MakerRegistry::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    // Registries are created lazily with the maker's first registered escrow
+    pub fn init_if_new(&mut self, maker: Pubkey, bump: u8) {
+        if self.version == 0 {
+            self.version = MakerRegistry::VERSION;
+            self.bump = bump;
+            self.maker = maker;
+        }
+    }
+
+    pub fn open_escrows(&self) -> &[Pubkey] {
+        &self.escrows[..self.count as usize]
+    }
+
+    pub fn add(&mut self, escrow: Pubkey) -> Result<()> {
+        let count = self.count as usize;
+        require!(count < MAX_REGISTRY_ESCROWS, EscrowError::RegistryFull);
+        self.escrows[count] = escrow;
+        self.count += 1;
+        Ok(())
+    }
+
+    // No-op for escrows that were never registered, e.g. older ones
+    pub fn remove(&mut self, escrow: &Pubkey) -> bool {
+        match self.open_escrows().iter().position(|e| e == escrow) {
+            Some(i) => {
+                let last = self.count as usize - 1;
+                self.escrows[i] = self.escrows[last];
+                self.escrows[last] = Pubkey::default();
+                self.count -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> MakerRegistry {
+        MakerRegistry {
+            version: 0,
+            bump: 0,
+            maker: Pubkey::default(),
+            count: 0,
+            escrows: [Pubkey::default(); MAX_REGISTRY_ESCROWS],
+            _reserved: [0; 32],
+        }
+    }
+
+    #[test]
+    fn removing_swaps_in_the_last_escrow() {
+        let mut registry = registry();
+        let escrows: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        for escrow in &escrows {
+            registry.add(*escrow).unwrap();
+        }
+
+        assert!(registry.remove(&escrows[0]));
+        assert_eq!(registry.open_escrows(), &[escrows[2], escrows[1]]);
+        assert_eq!(registry.escrows[2], Pubkey::default());
+        // Escrows registered before the registry existed are simply missing
+        assert!(!registry.remove(&escrows[0]));
+        assert_eq!(registry.count, 2);
+    }
+
+    #[test]
+    fn a_full_registry_refuses_more() {
+        let mut registry = registry();
+        for _ in 0..MAX_REGISTRY_ESCROWS {
+            registry.add(Pubkey::new_unique()).unwrap();
+        }
+        assert!(registry.add(Pubkey::new_unique()).is_err());
+
+        let first = registry.escrows[0];
+        registry.remove(&first);
+        assert!(registry.add(Pubkey::new_unique()).is_ok());
+    }
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::clock;
+use crate::errors::EscrowError;
+
+// A hot key the maker lets run a scoped set of their escrow instructions until
+// `expires_at`, at [b"session", maker, session_key]; e.g. a market-making bot
+// that should never hold the treasury wallet's key. Revoking closes it.
+#[account]
+#[derive(InitSpace)]
+pub struct MakerSession {
+    pub version: u8,
+    pub bump: u8,
+    pub maker: Pubkey,
+    pub session_key: Pubkey,
+    pub expires_at: i64,
+    // MakerSession::SCOPE_* bits
+    pub scopes: u8,
+    pub _reserved: [u8; 32],
+}
+
+impl MakerSession {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + MakerSession::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    // `reprice` and `set_expiry` on open offers
+    pub const SCOPE_MANAGE_OFFERS: u8 = 1 << 0;
+    // `cancel`, which refunds the vault to the maker's own ATA
+    pub const SCOPE_CANCEL: u8 = 1 << 1;
+    // `exchange`, paying a confirmed escrow out to its taker
+    pub const SCOPE_RELEASE: u8 = 1 << 2;
+    pub const SCOPE_ALL: u8 =
+        Self::SCOPE_MANAGE_OFFERS | Self::SCOPE_CANCEL | Self::SCOPE_RELEASE;
+
+    pub fn allows(&self, scope: u8, now: i64) -> bool {
+        now < self.expires_at && self.scopes & scope == scope
+    }
+
+    // `authority` may act for `maker` if it is the maker, or the key of a live
+    // session granting `scope`. The session's seeds already tie it to the pair.
+    pub fn authorize(
+        maker: &Pubkey,
+        authority: &Pubkey,
+        session: Option<&Account<MakerSession>>,
+        scope: u8,
+    ) -> Result<()> {
+        if authority == maker {
+            return Ok(());
+        }
+        let session = session.ok_or(EscrowError::InvalidSession)?;
+        require!(
+            session.allows(scope, clock::now()?),
+            EscrowError::InvalidSession
+        );
+        Ok(())
+    }
+}
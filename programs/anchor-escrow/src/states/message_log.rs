@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+// Commitment to a trade's encrypted chat, at [b"messages", escrow]. Each
+// anchored Merkle root is folded into `chain_hash`, so a dispute can show not
+// just the latest transcript but that no earlier root was rewritten.
+#[account]
+#[derive(InitSpace)]
+pub struct MessageLog {
+    pub version: u8,
+    pub bump: u8,
+    pub escrow: Pubkey,
+    // Merkle root over the first `message_count` messages
+    pub root: [u8; 32],
+    pub message_count: u64,
+    // hashv(previous chain_hash, root, message_count, author) of the latest anchor
+    pub chain_hash: [u8; 32],
+    pub last_author: Pubkey,
+    pub updated_at: i64,
+    pub _reserved: [u8; 32],
+}
+
+impl MessageLog {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + MessageLog::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    pub fn anchor(&mut self, root: [u8; 32], message_count: u64, author: Pubkey, now: i64) {
+        self.chain_hash = hashv(&[
+            &self.chain_hash,
+            &root,
+            &message_count.to_le_bytes(),
+            author.as_ref(),
+        ])
+        .to_bytes();
+        self.root = root;
+        self.message_count = message_count;
+        self.last_author = author;
+        self.updated_at = now;
+    }
+}
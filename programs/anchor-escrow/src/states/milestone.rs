@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+// One tranche of a milestone escrow, at [b"milestone", escrow, index]
+#[account]
+#[derive(InitSpace)]
+pub struct Milestone {
+    pub version: u8,
+    pub bump: u8,
+    pub index: u8,
+    pub requires_arbiter: bool,
+    pub confirmed: bool,
+    pub released: bool,
+    pub escrow: Pubkey,
+    pub amount: u64,
+    // Open dispute; only the arbiter can settle the tranche while set
+    pub disputed: bool,
+    // Settled back to the initializer by an arbiter ruling
+    pub refunded: bool,
+    pub _reserved: [u8; 30],
+}
+
+impl Milestone {
+    pub fn is_settled(&self) -> bool {
+        self.released || self.refunded
+    }
+
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + Milestone::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
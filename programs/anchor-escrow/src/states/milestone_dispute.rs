@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+// Dispute raised on one milestone, at [b"milestone_dispute", milestone].
+// Kept after resolution as the record of the arbiter's ruling.
+#[account]
+#[derive(InitSpace)]
+pub struct MilestoneDispute {
+    pub version: u8,
+    pub bump: u8,
+    pub resolved: bool,
+    // Ruling: true pays the tranche to the taker, false refunds the initializer
+    pub released_to_taker: bool,
+    pub milestone: Pubkey,
+    pub opened_by: Pubkey,
+    // Hash of the off-chain statement of the disagreement
+    pub reason_hash: [u8; 32],
+    pub opened_at: i64,
+    pub resolved_at: i64,
+    // Who ruled, so an appeal can slash their stake; default until resolved
+    // and for disputes resolved before rulings were recorded
+    pub arbiter: Pubkey,
+}
+
+impl MilestoneDispute {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + MilestoneDispute::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
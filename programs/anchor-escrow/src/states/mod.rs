@@ -1,2 +1,90 @@
 pub mod escrow;
-pub use escrow::Escrow;
+pub use escrow::{BondState, Escrow, EscrowStatus, EscrowV0, PartialPayments, ReleaseMode};
+pub mod milestone;
+pub use milestone::Milestone;
+pub mod milestone_dispute;
+pub use milestone_dispute::MilestoneDispute;
+pub mod config;
+pub use config::Config;
+pub mod bond;
+pub use bond::Bond;
+pub mod trader_stats;
+pub use trader_stats::TraderStats;
+pub mod maker_deposit;
+pub use maker_deposit::MakerDeposit;
+pub mod attestation_requirements;
+pub use attestation_requirements::AttestationRequirements;
+pub mod region_policy;
+pub use region_policy::RegionPolicy;
+pub mod blocked_wallet;
+pub use blocked_wallet::BlockedWallet;
+pub mod audit_log;
+pub use audit_log::{AdminAction, AuditLog};
+pub mod payment_delegate;
+pub use payment_delegate::PaymentDelegate;
+pub mod maker_session;
+pub use maker_session::MakerSession;
+pub mod payment_verifier;
+pub use payment_verifier::PaymentVerifier;
+pub mod offer_template;
+pub use offer_template::{OfferTemplate, TemplateTerms};
+pub mod recurring_schedule;
+pub use recurring_schedule::RecurringSchedule;
+pub mod payment_details;
+pub use payment_details::PaymentDetails;
+pub mod message_log;
+pub use message_log::MessageLog;
+pub mod maker_rebate;
+pub use maker_rebate::MakerRebate;
+pub mod permit_receipt;
+pub use permit_receipt::PermitReceipt;
+pub mod escrow_counter;
+pub use escrow_counter::EscrowCounter;
+pub mod maker_registry;
+pub use maker_registry::MakerRegistry;
+pub mod receipt;
+pub use receipt::Receipt;
+pub mod trade_history;
+pub use trade_history::{SettledTrade, TradeHistory};
+pub mod rate_feed;
+pub use rate_feed::RateFeed;
+pub mod stablecoin_list;
+pub use stablecoin_list::StablecoinList;
+pub mod swap_router;
+pub use swap_router::SwapRouter;
+pub mod fee_converter;
+pub use fee_converter::FeeConverter;
+pub mod yield_adapter;
+pub use yield_adapter::YieldAdapter;
+pub mod charity_list;
+pub use charity_list::CharityList;
+pub mod donation_pledge;
+pub use donation_pledge::DonationPledge;
+pub mod compliance_desk;
+pub use compliance_desk::ComplianceDesk;
+pub mod compliance_approval;
+pub use compliance_approval::ComplianceApproval;
+pub mod arbitrator;
+pub use arbitrator::Arbitrator;
+pub mod arbitration_policy;
+pub use arbitration_policy::ArbitrationPolicy;
+pub mod overturned_ruling;
+pub use overturned_ruling::OverturnedRuling;
+pub mod jury;
+pub use jury::Jury;
+pub mod jury_vote;
+pub use jury_vote::JuryVote;
+pub mod governance_authority;
+pub use governance_authority::GovernanceAuthority;
+pub mod escalation;
+pub use escalation::Escalation;
+pub mod fiat_quotes;
+pub use fiat_quotes::{FiatQuote, FiatQuotes};
+pub mod signer_nonce;
+pub use signer_nonce::SignerNonce;
+pub mod idempotency_record;
+pub use idempotency_record::IdempotencyRecord;
+pub mod partner_config;
+pub use partner_config::PartnerConfig;
+pub mod partner_accrual;
+pub use partner_accrual::PartnerAccrual;
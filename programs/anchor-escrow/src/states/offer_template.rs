@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BPS_DENOMINATOR;
+use crate::errors::EscrowError;
+use crate::fees::pro_rata;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, InitSpace)]
+pub struct TemplateTerms {
+    // Base price: `price_taker_amount` of mint_b per `price_initializer_amount` of mint_a
+    pub price_initializer_amount: u64,
+    pub price_taker_amount: u64,
+    // Added on top of the base price, in basis points; negative is a discount
+    pub margin_bps: i16,
+    // Copied to Escrow::payment_methods
+    pub payment_methods: u32,
+    // Bounds on initializer_amount per offer; a zero max is unlimited
+    pub min_amount: u64,
+    pub max_amount: u64,
+    // Seconds from posting until the offer may be reaped, 0 if it never expires
+    pub expires_after: i64,
+    // Copied to Escrow::auto_release_after
+    pub auto_release_after: i64,
+}
+
+impl TemplateTerms {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.price_initializer_amount > 0
+                && self.margin_bps > -(BPS_DENOMINATOR as i16)
+                && (self.max_amount == 0 || self.min_amount <= self.max_amount)
+                && self.expires_after >= 0
+                && self.auto_release_after >= 0,
+            EscrowError::InvalidTemplate
+        );
+        Ok(())
+    }
+
+    pub fn check_amount(&self, initializer_amount: u64) -> Result<()> {
+        require!(
+            initializer_amount > 0
+                && initializer_amount >= self.min_amount
+                && (self.max_amount == 0 || initializer_amount <= self.max_amount),
+            EscrowError::AmountOutsideTemplateLimits
+        );
+        Ok(())
+    }
+
+    // Base price for `initializer_amount`, with the margin applied
+    pub fn taker_amount(&self, initializer_amount: u64) -> Result<u64> {
+        let base = pro_rata(
+            initializer_amount,
+            self.price_taker_amount,
+            self.price_initializer_amount,
+        )?;
+        let factor = (BPS_DENOMINATOR as i64 + self.margin_bps as i64) as u64;
+        pro_rata(base, factor, BPS_DENOMINATOR as u64)
+    }
+}
+
+// A maker's standard offer for one mint pair, at
+// [b"template", maker, template_id], posted with `initialize_from_template`
+#[account]
+#[derive(InitSpace)]
+pub struct OfferTemplate {
+    pub version: u8,
+    pub bump: u8,
+    pub template_id: u16,
+    pub maker: Pubkey,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub terms: TemplateTerms,
+    pub _reserved: [u8; 32],
+}
+
+impl OfferTemplate {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + OfferTemplate::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
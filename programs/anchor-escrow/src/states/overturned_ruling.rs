@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+// An arbiter's ruling reversed on appeal, at [b"overturned", dispute]. Its
+// existence stops the same ruling from being slashed twice.
+#[account]
+#[derive(InitSpace)]
+pub struct OverturnedRuling {
+    pub version: u8,
+    pub bump: u8,
+    pub dispute: Pubkey,
+    pub arbiter: Pubkey,
+    // Stake taken from the arbiter into the insurance fund
+    pub slashed: u64,
+    pub overturned_at: i64,
+    // Hash of the off-chain appeal decision
+    pub reason_hash: [u8; 32],
+    pub _reserved: [u8; 32],
+}
+
+impl OverturnedRuling {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + OverturnedRuling::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
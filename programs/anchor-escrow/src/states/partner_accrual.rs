@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+
+// A partner's share of the platform fee in one mint, at
+// [b"partner_accrual", partner_id, mint]. The tokens themselves sit in the
+// [b"partner_pool"] PDA's ATA until the partner's fee wallet claims them.
+#[account]
+#[derive(InitSpace)]
+pub struct PartnerAccrual {
+    pub version: u8,
+    pub bump: u8,
+    pub partner_id: u16,
+    pub mint: Pubkey,
+    // Earned and not yet claimed
+    pub accrued: u64,
+    pub total_claimed: u64,
+    pub updated_at: i64,
+    pub _reserved: [u8; 32],
+}
+
+impl PartnerAccrual {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + PartnerAccrual::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    // Created lazily on the partner's first settlement in the mint
+    pub fn init_if_new(&mut self, partner_id: u16, mint: Pubkey, bump: u8) {
+        if self.version == 0 {
+            self.version = PartnerAccrual::VERSION;
+            self.bump = bump;
+            self.partner_id = partner_id;
+            self.mint = mint;
+        }
+    }
+
+    pub fn accrue(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.accrued = self
+            .accrued
+            .checked_add(amount)
+            .ok_or(EscrowError::MathOverflow)?;
+        self.updated_at = now;
+        Ok(())
+    }
+
+    // Empties the balance, returning what is owed
+    pub fn take_accrued(&mut self, now: i64) -> Result<u64> {
+        let amount = self.accrued;
+        self.accrued = 0;
+        self.total_claimed = self
+            .total_claimed
+            .checked_add(amount)
+            .ok_or(EscrowError::MathOverflow)?;
+        self.updated_at = now;
+        Ok(amount)
+    }
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BPS_DENOMINATOR;
+use crate::fees::pro_rata;
+
+// A white-label frontend running on this deployment, at
+// [b"partner", partner_id]. Offers created through it carry its id, and every
+// payout to their taker has its fee taken out for `fee_wallet`, on top of the
+// platform fee, and accrues its `revenue_share_bps` of the platform fee
+// itself. Never closed, since open escrows still point at it.
+#[account]
+#[derive(InitSpace)]
+pub struct PartnerConfig {
+    pub version: u8,
+    pub bump: u8,
+    // Nonzero; an escrow's 0 means it came through no partner
+    pub partner_id: u16,
+    pub fee_bps: u16,
+    // Owner of the token accounts the partner's fees are paid into
+    pub fee_wallet: Pubkey,
+    // Opaque id the frontend looks its branding (name, logo, theme) up by
+    pub branding_id: [u8; 32],
+    // Share of the platform fee on its trades the partner earns, in basis
+    // points, claimed through `claim_partner_fees`
+    pub revenue_share_bps: u16,
+    pub _reserved: [u8; 30],
+}
+
+impl PartnerConfig {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + PartnerConfig::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    // The partner's cut of a taker payout of `amount`
+    pub fn fee(&self, amount: u64) -> Result<u64> {
+        pro_rata(amount, self.fee_bps as u64, BPS_DENOMINATOR as u64)
+    }
+
+    // The partner's part of a platform fee of `platform_fee`
+    pub fn revenue_share(&self, platform_fee: u64) -> Result<u64> {
+        pro_rata(
+            platform_fee,
+            self.revenue_share_bps as u64,
+            BPS_DENOMINATOR as u64,
+        )
+    }
+}
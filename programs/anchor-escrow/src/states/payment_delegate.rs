@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+// A key the owner lets confirm payments on their behalf, at
+// [b"payment_delegate", owner], e.g. a session key on the device doing the
+// bank checks. The account existing is the grant; revoking closes it.
+#[account]
+#[derive(InitSpace)]
+pub struct PaymentDelegate {
+    pub version: u8,
+    pub bump: u8,
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub _reserved: [u8; 32],
+}
+
+impl PaymentDelegate {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + PaymentDelegate::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_PAYMENT_URI_LEN;
+
+// Where the taker finds the maker's payment details (bank account, UPI ID),
+// at [b"payment_details", escrow]. The details themselves live off-chain,
+// encrypted to the taker; `details_hash` makes any later swap of them evident.
+#[account]
+#[derive(InitSpace)]
+pub struct PaymentDetails {
+    pub version: u8,
+    pub bump: u8,
+    pub uri_len: u8,
+    pub escrow: Pubkey,
+    // URI or CID of the encrypted details
+    pub uri: [u8; MAX_PAYMENT_URI_LEN],
+    // Hash of the encrypted payload
+    pub details_hash: [u8; 32],
+    pub updated_at: i64,
+    pub _reserved: [u8; 32],
+}
+
+impl PaymentDetails {
+    pub fn uri(&self) -> &[u8] {
+        &self.uri[..self.uri_len as usize]
+    }
+
+    pub fn set_uri(&mut self, uri: &[u8]) {
+        self.uri_len = uri.len() as u8;
+        self.uri = [0; MAX_PAYMENT_URI_LEN];
+        self.uri[..uri.len()].copy_from_slice(uri);
+    }
+
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + PaymentDetails::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
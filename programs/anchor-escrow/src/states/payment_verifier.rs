@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+// Program trusted to check proofs that a taker's fiat payment happened (e.g.
+// a zkTLS or Reclaim attestation verifier), at [b"payment_verifier"].
+// Pubkey::default() turns proof-based confirmation off.
+#[account]
+#[derive(InitSpace)]
+pub struct PaymentVerifier {
+    pub version: u8,
+    pub bump: u8,
+    pub program: Pubkey,
+    pub _reserved: [u8; 32],
+}
+
+impl PaymentVerifier {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + PaymentVerifier::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    pub fn is_enabled(&self) -> bool {
+        self.program != Pubkey::default()
+    }
+}
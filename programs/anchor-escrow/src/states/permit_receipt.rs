@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+// Marks a maker's permit for escrow `seed` as spent, at [b"permit", maker,
+// seed]. Never closed: the escrow PDA is freed again once it settles, so
+// without this the same signed permit could be replayed until it expires.
+#[account]
+#[derive(InitSpace)]
+pub struct PermitReceipt {
+    pub version: u8,
+    pub bump: u8,
+    pub maker: Pubkey,
+    pub escrow: Pubkey,
+    // The relayer that submitted the permit
+    pub relayer: Pubkey,
+    pub used_at: i64,
+    pub _reserved: [u8; 32],
+}
+
+impl PermitReceipt {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + PermitReceipt::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+// Exchange rate of a market, at [b"rate_feed", mint_a, mint_b], pushed by an
+// off-chain oracle the admin names. `rate` is mint_b per mint_a in whatever
+// fixed-point scale the oracle uses; only ratios of rates are ever compared.
+#[account]
+#[derive(InitSpace)]
+pub struct RateFeed {
+    pub version: u8,
+    pub bump: u8,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    // Only key allowed to `post_rate`
+    pub authority: Pubkey,
+    // 0 until the first post
+    pub rate: u64,
+    pub updated_at: i64,
+    pub _reserved: [u8; 32],
+}
+
+impl RateFeed {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + RateFeed::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::states::Escrow;
+
+// Durable record of a settled trade, since settlement closes the escrow
+// itself. Written when the settling instruction is given one, at
+// [b"receipt", escrow, payment_confirmed_at] so a reused seed gets a fresh
+// address. Either party may close it, refunding whoever paid the rent.
+#[account]
+#[derive(InitSpace)]
+pub struct Receipt {
+    pub version: u8,
+    pub bump: u8,
+    pub escrow: Pubkey,
+    pub seed: u64,
+    pub initializer: Pubkey,
+    pub taker: Pubkey,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub initializer_amount: u64,
+    pub taker_amount: u64,
+    // mint_a the taker received
+    pub buyer_amount: u64,
+    // Everything else: the platform fee, insurance and rebate shares included,
+    // plus any risk premium
+    pub fee: u64,
+    pub terms_hash: [u8; 32],
+    pub taker_assigned_at: i64,
+    pub payment_confirmed_at: i64,
+    pub settled_at: i64,
+    pub rent_payer: Pubkey,
+    // `mint_badges` already ran for this trade
+    pub badges_minted: bool,
+    // Escrow::fiat_currency, 0 if unspecified
+    pub fiat_currency: u16,
+    // `publish_settlement` already posted this trade to Wormhole
+    pub wormhole_published: bool,
+    pub _reserved: [u8; 29],
+}
+
+impl Receipt {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + Receipt::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    pub fn new(
+        bump: u8,
+        escrow_key: Pubkey,
+        escrow: &Escrow,
+        taker: Pubkey,
+        buyer_amount: u64,
+        settled_at: i64,
+        rent_payer: Pubkey,
+    ) -> Self {
+        Self {
+            version: Receipt::VERSION,
+            bump,
+            escrow: escrow_key,
+            seed: escrow.seed,
+            initializer: escrow.initializer,
+            taker,
+            mint_a: escrow.mint_a,
+            mint_b: escrow.mint_b,
+            initializer_amount: escrow.initializer_amount,
+            taker_amount: escrow.taker_amount,
+            buyer_amount,
+            fee: escrow.initializer_amount - buyer_amount,
+            terms_hash: escrow.terms_hash,
+            taker_assigned_at: escrow.taker_assigned_at,
+            payment_confirmed_at: escrow.payment_confirmed_at,
+            settled_at,
+            rent_payer,
+            badges_minted: false,
+            fiat_currency: escrow.fiat_currency,
+            wormhole_published: false,
+            _reserved: [0; 29],
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use std::convert::TryInto;
+
+use crate::errors::EscrowError;
+use crate::fees::pro_rata;
+
+// Standing order that posts the same escrow to a trusted taker every
+// `interval` seconds, at [b"recurring", maker, schedule_id]. The inventory
+// for every run is prefunded into this account's associated token account;
+// `spawn_recurring` cranks out the next escrow once it is due.
+#[account]
+#[derive(InitSpace)]
+pub struct RecurringSchedule {
+    pub version: u8,
+    pub bump: u8,
+    pub schedule_id: u16,
+    pub maker: Pubkey,
+    // Designated taker of every escrow the schedule posts
+    pub taker: Pubkey,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub initializer_amount: u64,
+    pub taker_amount: u64,
+    pub interval: i64,
+    // Unix time the next escrow may be spawned
+    pub next_run_at: i64,
+    pub runs: u32,
+    pub runs_done: u32,
+    pub _reserved: [u8; 32],
+}
+
+impl RecurringSchedule {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + RecurringSchedule::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    // Spreads what the prefunding deposit actually added over the runs, like
+    // `Escrow::settle_deposit`: a transfer fee shrinks every run alike and
+    // keeps the unit price. The remainder stays in the vault until cancel.
+    pub fn settle_deposit(&mut self, received: u64) -> Result<()> {
+        let per_run = received / self.runs as u64;
+        require!(per_run > 0, EscrowError::DepositMismatch);
+        self.taker_amount = pro_rata(self.taker_amount, per_run, self.initializer_amount)?;
+        self.initializer_amount = per_run;
+        Ok(())
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.runs_done >= self.runs
+    }
+
+    // Escrow seed of the next run, derived so the crank needs no input and
+    // two schedules never collide
+    pub fn next_escrow_seed(&self, schedule: &Pubkey) -> u64 {
+        let hash = hashv(&[
+            b"recurring",
+            schedule.as_ref(),
+            &self.runs_done.to_le_bytes(),
+        ]);
+        u64::from_le_bytes(hash.to_bytes()[..8].try_into().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deposit_is_spread_over_the_runs_at_the_same_price() {
+        let mut schedule =
+            RecurringSchedule::try_from_slice(&[0u8; RecurringSchedule::INIT_SPACE]).unwrap();
+        schedule.initializer_amount = 100;
+        schedule.taker_amount = 50;
+        schedule.runs = 4;
+
+        // 400 sent, 396 received after a 1% transfer fee
+        schedule.settle_deposit(396).unwrap();
+        assert_eq!((schedule.initializer_amount, schedule.taker_amount), (99, 49));
+        assert!(schedule.settle_deposit(3).is_err());
+    }
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_REGION_CODES;
+
+// Jurisdictions an offer may be taken from, at [b"regions", escrow]. Takers
+// prove their region with a Solana Attestation Service attestation on
+// `schema` from `credential`, whose data is the ISO 3166-1 alpha-2 code.
+#[account]
+#[derive(InitSpace)]
+pub struct RegionPolicy {
+    pub version: u8,
+    pub bump: u8,
+    // true: only `codes` may take the offer; false: everyone but `codes`
+    pub allow_list: bool,
+    pub code_count: u8,
+    pub escrow: Pubkey,
+    pub credential: Pubkey,
+    pub schema: Pubkey,
+    pub codes: [[u8; 2]; MAX_REGION_CODES],
+    pub _reserved: [u8; 32],
+}
+
+impl RegionPolicy {
+    pub fn permits(&self, region: [u8; 2]) -> bool {
+        let listed = self.codes[..self.code_count as usize].contains(&region);
+        listed == self.allow_list
+    }
+
+    pub fn set(&mut self, allow_list: bool, codes: &[[u8; 2]]) {
+        self.allow_list = allow_list;
+        self.code_count = codes.len() as u8;
+        self.codes = [[0; 2]; MAX_REGION_CODES];
+        self.codes[..codes.len()].copy_from_slice(codes);
+    }
+
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + RegionPolicy::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
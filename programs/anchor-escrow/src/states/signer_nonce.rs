@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::EscrowError;
+
+// Next nonce a wallet's off-chain signatures must carry, at [b"nonce", wallet].
+// Permits and signed releases each consume the current value, so a captured
+// signature can't be replayed once used; `advance_nonce` voids any the wallet
+// handed out but no longer wants honoured.
+#[account]
+#[derive(InitSpace)]
+pub struct SignerNonce {
+    pub version: u8,
+    pub bump: u8,
+    pub wallet: Pubkey,
+    pub nonce: u64,
+    pub _reserved: [u8; 32],
+}
+
+impl SignerNonce {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + SignerNonce::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    pub fn init_if_new(&mut self, wallet: Pubkey, bump: u8) {
+        if self.version == 0 {
+            self.version = SignerNonce::VERSION;
+            self.bump = bump;
+            self.wallet = wallet;
+        }
+    }
+
+    pub fn advance(&mut self) -> Result<()> {
+        self.nonce = self.nonce.checked_add(1).ok_or(EscrowError::MathOverflow)?;
+        Ok(())
+    }
+
+    // Spends `nonce`, which must be the current one
+    pub fn consume(&mut self, nonce: u64) -> Result<()> {
+        require!(nonce == self.nonce, EscrowError::NonceMismatch);
+        self.advance()
+    }
+}
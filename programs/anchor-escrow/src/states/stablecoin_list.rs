@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_STABLECOINS;
+use crate::errors::EscrowError;
+
+// Mints the admin vouches for as stablecoins, at [b"stablecoins"]. With the
+// config's `stablecoin_only` switch on, both legs of every new offer must be
+// on this list.
+#[account]
+#[derive(InitSpace)]
+pub struct StablecoinList {
+    pub version: u8,
+    pub bump: u8,
+    pub count: u16,
+    // Only the first `count` entries are meaningful; order isn't kept
+    pub mints: [Pubkey; MAX_STABLECOINS],
+    pub _reserved: [u8; 32],
+}
+
+impl StablecoinList {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + StablecoinList::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    pub fn init_if_new(&mut self, bump: u8) {
+        if self.version == 0 {
+            self.version = StablecoinList::VERSION;
+            self.bump = bump;
+        }
+    }
+
+    pub fn listed_mints(&self) -> &[Pubkey] {
+        &self.mints[..self.count as usize]
+    }
+
+    pub fn contains(&self, mint: &Pubkey) -> bool {
+        self.listed_mints().contains(mint)
+    }
+
+    // Listing a mint twice is a no-op
+    pub fn add(&mut self, mint: Pubkey) -> Result<()> {
+        if self.contains(&mint) {
+            return Ok(());
+        }
+        let count = self.count as usize;
+        require!(count < MAX_STABLECOINS, EscrowError::StablecoinListFull);
+        self.mints[count] = mint;
+        self.count += 1;
+        Ok(())
+    }
+
+    pub fn remove(&mut self, mint: &Pubkey) -> bool {
+        match self.listed_mints().iter().position(|m| m == mint) {
+            Some(i) => {
+                let last = self.count as usize - 1;
+                self.mints[i] = self.mints[last];
+                self.mints[last] = Pubkey::default();
+                self.count -= 1;
+                true
+            }
+            None => false,
+        }
+    }
+}
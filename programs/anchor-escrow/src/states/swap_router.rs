@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+// Aggregator or AMM program (e.g. Jupiter) `exchange_and_swap` may route a
+// taker's payout through, at [b"swap_router"]. Pubkey::default() turns
+// swap-on-settlement off.
+#[account]
+#[derive(InitSpace)]
+pub struct SwapRouter {
+    pub version: u8,
+    pub bump: u8,
+    pub program: Pubkey,
+    pub _reserved: [u8; 32],
+}
+
+impl SwapRouter {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + SwapRouter::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    pub fn is_enabled(&self) -> bool {
+        self.program != Pubkey::default()
+    }
+}
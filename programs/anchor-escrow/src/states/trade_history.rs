@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::constants::TRADE_HISTORY_DEPTH;
+use crate::errors::EscrowError;
+use crate::events::TradeRecorded;
+use crate::states::Escrow;
+
+// Domain separation between leaves and inner nodes, so a node can't be passed
+// off as a trade
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+// A trader's settled trades as an append-only Merkle tree, at
+// [b"history", trader]. Only the root and the right-most path are kept; the
+// leaves go out in `TradeRecorded` events, from which an indexer rebuilds
+// the tree and serves proofs against `root`. Empty slots hash as zeroes.
+#[account]
+#[derive(InitSpace)]
+pub struct TradeHistory {
+    pub version: u8,
+    pub bump: u8,
+    pub trader: Pubkey,
+    // Trades recorded so far; the next one becomes leaf `leaf_count`
+    pub leaf_count: u64,
+    pub root: [u8; 32],
+    // Left sibling at each level of the path to the next leaf
+    pub frontier: [[u8; 32]; TRADE_HISTORY_DEPTH],
+    pub updated_at: i64,
+    pub _reserved: [u8; 32],
+}
+
+impl TradeHistory {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + TradeHistory::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    // Histories are created lazily with the trader's first recorded trade
+    pub fn init_if_new(&mut self, trader: Pubkey, bump: u8) {
+        if self.version == 0 {
+            self.version = TradeHistory::VERSION;
+            self.bump = bump;
+            self.trader = trader;
+        }
+    }
+
+    pub fn record(&mut self, trader: Pubkey, bump: u8, trade: &SettledTrade) -> Result<()> {
+        self.init_if_new(trader, bump);
+        let leaf_index = self.append(trade.leaf(), trade.settled_at)?;
+        emit!(TradeRecorded {
+            trader,
+            leaf_index,
+            root: self.root,
+            trade: *trade,
+        });
+        Ok(())
+    }
+
+    // Appends `leaf` and returns its index
+    fn append(&mut self, leaf: [u8; 32], now: i64) -> Result<u64> {
+        let index = self.leaf_count;
+        require!(
+            index < 1 << TRADE_HISTORY_DEPTH,
+            EscrowError::TradeHistoryFull
+        );
+        let mut node = leaf;
+        let mut empty = [0u8; 32];
+        for level in 0..TRADE_HISTORY_DEPTH {
+            node = if (index >> level) & 1 == 0 {
+                self.frontier[level] = node;
+                hashv(&[NODE_PREFIX, &node, &empty]).to_bytes()
+            } else {
+                hashv(&[NODE_PREFIX, &self.frontier[level], &node]).to_bytes()
+            };
+            empty = hashv(&[NODE_PREFIX, &empty, &empty]).to_bytes();
+        }
+        self.root = node;
+        self.leaf_count = index + 1;
+        self.updated_at = now;
+        Ok(index)
+    }
+}
+
+// What a history leaf commits to; the same leaf goes into both parties' trees
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SettledTrade {
+    pub escrow: Pubkey,
+    pub initializer: Pubkey,
+    pub taker: Pubkey,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub initializer_amount: u64,
+    pub taker_amount: u64,
+    // mint_a the taker received after fees
+    pub buyer_amount: u64,
+    pub settled_at: i64,
+}
+
+impl SettledTrade {
+    pub fn new(
+        escrow_key: Pubkey,
+        escrow: &Escrow,
+        taker: Pubkey,
+        buyer_amount: u64,
+        settled_at: i64,
+    ) -> Self {
+        Self {
+            escrow: escrow_key,
+            initializer: escrow.initializer,
+            taker,
+            mint_a: escrow.mint_a,
+            mint_b: escrow.mint_b,
+            initializer_amount: escrow.initializer_amount,
+            taker_amount: escrow.taker_amount,
+            buyer_amount,
+            settled_at,
+        }
+    }
+
+    pub fn leaf(&self) -> [u8; 32] {
+        hashv(&[
+            LEAF_PREFIX,
+            self.escrow.as_ref(),
+            self.initializer.as_ref(),
+            self.taker.as_ref(),
+            self.mint_a.as_ref(),
+            self.mint_b.as_ref(),
+            &self.initializer_amount.to_le_bytes(),
+            &self.taker_amount.to_le_bytes(),
+            &self.buyer_amount.to_le_bytes(),
+            &self.settled_at.to_le_bytes(),
+        ])
+        .to_bytes()
+    }
+}
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{BPS_DENOMINATOR, SECONDS_PER_DAY, SECONDS_PER_WEEK};
+use crate::errors::EscrowError;
+use crate::states::Config;
+
+// Per-wallet track record, at [b"trader_stats", wallet]
+#[account]
+#[derive(InitSpace)]
+pub struct TraderStats {
+    pub version: u8,
+    pub bump: u8,
+    pub wallet: Pubkey,
+    pub disputes_won: u32,
+    pub disputes_lost: u32,
+    pub bonds_forfeited: u32,
+    // Escrows the wallet cancelled after the taker had confirmed payment
+    pub cancels_after_confirmation: u32,
+    // Volume (raw mint_a units) traded in the current daily and weekly windows
+    pub day_window_start: i64,
+    pub day_volume: u64,
+    pub week_window_start: i64,
+    pub week_volume: u64,
+    // Admin-assigned limit multiplier for verified wallets; 0 counts as 1
+    pub volume_tier: u8,
+    // Escrows this wallet created that haven't been closed yet
+    pub open_escrows: u32,
+    // Escrows this wallet created that settled to their taker
+    pub trades_settled: u32,
+    // Escrows opened with `initialize_sequential`; the next one's seed is
+    // EscrowCounter::sequential_seed(wallet, sequential_escrows)
+    pub sequential_escrows: u32,
+    pub _reserved: [u8; 15],
+}
+
+impl TraderStats {
+    // Stats accounts are created lazily the first time a wallet shows up
+    pub fn init_if_new(&mut self, wallet: Pubkey, bump: u8) {
+        if self.version == 0 {
+            self.version = TraderStats::VERSION;
+            self.bump = bump;
+            self.wallet = wallet;
+        }
+    }
+
+    // Adds `amount` to the rolling windows, failing if that crosses the
+    // config's caps scaled by the wallet's tier
+    pub fn record_volume(&mut self, amount: u64, now: i64, config: &Config) -> Result<()> {
+        if now >= self.day_window_start + SECONDS_PER_DAY {
+            self.day_window_start = now;
+            self.day_volume = 0;
+        }
+        if now >= self.week_window_start + SECONDS_PER_WEEK {
+            self.week_window_start = now;
+            self.week_volume = 0;
+        }
+        self.day_volume = self
+            .day_volume
+            .checked_add(amount)
+            .ok_or(EscrowError::MathOverflow)?;
+        self.week_volume = self
+            .week_volume
+            .checked_add(amount)
+            .ok_or(EscrowError::MathOverflow)?;
+
+        let tier = self.volume_tier.max(1) as u64;
+        let within =
+            |volume: u64, limit: u64| limit == 0 || volume as u128 <= limit as u128 * tier as u128;
+        require!(
+            within(self.day_volume, config.daily_volume_limit)
+                && within(self.week_volume, config.weekly_volume_limit),
+            EscrowError::VolumeLimitExceeded
+        );
+        Ok(())
+    }
+
+    pub fn track_opened(&mut self, count: u32, config: &Config) -> Result<()> {
+        self.open_escrows = self
+            .open_escrows
+            .checked_add(count)
+            .ok_or(EscrowError::MathOverflow)?;
+        require!(
+            config.max_open_escrows == 0 || self.open_escrows <= config.max_open_escrows as u32,
+            EscrowError::TooManyOpenEscrows
+        );
+        Ok(())
+    }
+
+    // Saturating: escrows created before tracking started were never counted
+    pub fn track_closed(&mut self, count: u32) {
+        self.open_escrows = self.open_escrows.saturating_sub(count);
+    }
+
+    // Closes one of the wallet's escrows that paid out to its taker
+    pub fn track_settled(&mut self) {
+        self.track_closed(1);
+        self.trades_settled = self.trades_settled.saturating_add(1);
+    }
+
+    // Share of the wallet's outcomes, settled trades plus lost disputes, that
+    // were lost disputes, in basis points
+    pub fn dispute_loss_rate_bps(&self) -> u16 {
+        let outcomes = self.trades_settled as u64 + self.disputes_lost as u64;
+        if outcomes == 0 {
+            return 0;
+        }
+        (self.disputes_lost as u64 * BPS_DENOMINATOR as u64 / outcomes) as u16
+    }
+
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + TraderStats::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats() -> TraderStats {
+        TraderStats::try_from_slice(&[0u8; TraderStats::INIT_SPACE]).unwrap()
+    }
+
+    #[test]
+    fn closing_never_underflows_the_open_count() {
+        let mut config = Config::try_from_slice(&[0u8; Config::INIT_SPACE]).unwrap();
+        config.max_open_escrows = 2;
+        let mut stats = stats();
+
+        stats.track_opened(2, &config).unwrap();
+        assert!(stats.track_opened(1, &config).is_err());
+        stats.open_escrows = 2;
+        stats.track_settled();
+        assert_eq!((stats.open_escrows, stats.trades_settled), (1, 1));
+        // An escrow opened before tracking started closes against nothing
+        stats.track_closed(2);
+        assert_eq!(stats.open_escrows, 0);
+        assert!(stats.track_opened(2, &config).is_ok());
+    }
+
+    #[test]
+    fn loss_rate_counts_settled_trades_and_lost_disputes() {
+        let mut stats = stats();
+        assert_eq!(stats.dispute_loss_rate_bps(), 0);
+        stats.trades_settled = 3;
+        stats.disputes_lost = 1;
+        assert_eq!(stats.dispute_loss_rate_bps(), 2_500);
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+// Program wrapping a lending protocol (e.g. a Kamino or Solend adapter) that
+// idle escrow vaults can be lent out through, at [b"yield_adapter"].
+// Pubkey::default() turns deposits off.
+#[account]
+#[derive(InitSpace)]
+pub struct YieldAdapter {
+    pub version: u8,
+    pub bump: u8,
+    pub program: Pubkey,
+    // Escrows with a deposit in `program`; it can't be swapped out for
+    // another program until they have all been withdrawn
+    pub open_positions: u32,
+    pub _reserved: [u8; 32],
+}
+
+impl YieldAdapter {
+    // First 8 Bytes are Discriminator (u64)
+    pub const SPACE: usize = 8 + YieldAdapter::INIT_SPACE;
+
+    pub const VERSION: u8 = 1;
+
+    pub fn is_enabled(&self) -> bool {
+        self.program != Pubkey::default()
+    }
+}
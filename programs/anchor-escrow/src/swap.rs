@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+// A swap instruction built off-chain (e.g. from a Jupiter quote) for the
+// router to run with the escrow PDA as the user transfer authority, spending
+// from the vault into the taker's token account for the output mint
+pub struct SwapRoute<'a, 'info> {
+    // Least the taker may end up with in the output mint
+    pub min_out: u64,
+    pub data: Vec<u8>,
+    pub accounts: &'a [AccountInfo<'info>],
+}
+
+// CPIs the router with `route`, signing for `authority` wherever the route
+// lists it
+pub fn invoke_swap_route<'info>(
+    router: &AccountInfo<'info>,
+    authority: &Pubkey,
+    route: &SwapRoute<'_, 'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let accounts = route
+        .accounts
+        .iter()
+        .map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_signer: account.is_signer || account.key == authority,
+            is_writable: account.is_writable,
+        })
+        .collect();
+    invoke_signed(
+        &Instruction {
+            program_id: router.key(),
+            accounts,
+            data: route.data.clone(),
+        },
+        route.accounts,
+        signer_seeds,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::system_instruction;
+use std::convert::TryInto;
+
+use crate::states::Receipt;
+
+// Core bridge instruction index of `post_message`
+const POST_MESSAGE_IX: u8 = 1;
+// Guardians sign once the slot is finalized, so a rolled-back settlement is
+// never attested
+const CONSISTENCY_FINALIZED: u8 = 1;
+// BridgeData: guardian_set_index u32, last_lamports u64,
+// guardian_set_expiration_time u32, then the message fee as a u64
+const BRIDGE_FEE_OFFSET: usize = 16;
+
+// Leading byte of the payload, bumped whenever its layout changes
+pub const SETTLEMENT_PAYLOAD_ID: u8 = 1;
+
+// What EVM-side contracts decode, big-endian like every Wormhole payload:
+// payload id, receipt (the trade id), escrow, initializer, taker, mint_a,
+// initializer_amount, buyer_amount, mint_b, taker_amount, settled_at
+pub fn settlement_payload(receipt_key: &Pubkey, receipt: &Receipt) -> Vec<u8> {
+    [
+        &[SETTLEMENT_PAYLOAD_ID][..],
+        receipt_key.as_ref(),
+        receipt.escrow.as_ref(),
+        receipt.initializer.as_ref(),
+        receipt.taker.as_ref(),
+        receipt.mint_a.as_ref(),
+        &receipt.initializer_amount.to_be_bytes(),
+        &receipt.buyer_amount.to_be_bytes(),
+        receipt.mint_b.as_ref(),
+        &receipt.taker_amount.to_be_bytes(),
+        &receipt.settled_at.to_be_bytes(),
+    ]
+    .concat()
+}
+
+pub struct PostMessageAccounts<'a, 'info> {
+    pub program: &'a AccountInfo<'info>,
+    pub bridge: &'a AccountInfo<'info>,
+    pub message: &'a AccountInfo<'info>,
+    pub emitter: &'a AccountInfo<'info>,
+    pub sequence: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub fee_collector: &'a AccountInfo<'info>,
+    pub clock: &'a AccountInfo<'info>,
+    pub rent: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+}
+
+// Pays the bridge's message fee and posts `payload` from `emitter`. Both the
+// emitter and the new message account are PDAs of this program, signed for
+// by `signer_seeds`.
+pub fn post_message(
+    accounts: &PostMessageAccounts,
+    payload: Vec<u8>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let fee = {
+        let data = accounts.bridge.try_borrow_data()?;
+        data.get(BRIDGE_FEE_OFFSET..BRIDGE_FEE_OFFSET + 8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u64::from_le_bytes)
+            .unwrap_or(0)
+    };
+    if fee > 0 {
+        invoke(
+            &system_instruction::transfer(accounts.payer.key, accounts.fee_collector.key, fee),
+            &[
+                accounts.payer.clone(),
+                accounts.fee_collector.clone(),
+                accounts.system_program.clone(),
+            ],
+        )?;
+    }
+
+    let mut data = vec![POST_MESSAGE_IX];
+    // PostMessageData: nonce, payload, consistency level
+    (0u32, payload, CONSISTENCY_FINALIZED).serialize(&mut data)?;
+    let metas = vec![
+        AccountMeta::new(accounts.bridge.key(), false),
+        AccountMeta::new(accounts.message.key(), true),
+        AccountMeta::new_readonly(accounts.emitter.key(), true),
+        AccountMeta::new(accounts.sequence.key(), false),
+        AccountMeta::new(accounts.payer.key(), true),
+        AccountMeta::new(accounts.fee_collector.key(), false),
+        AccountMeta::new_readonly(accounts.clock.key(), false),
+        AccountMeta::new_readonly(accounts.system_program.key(), false),
+        AccountMeta::new_readonly(accounts.rent.key(), false),
+    ];
+    invoke_signed(
+        &Instruction {
+            program_id: accounts.program.key(),
+            accounts: metas,
+            data,
+        },
+        &[
+            accounts.bridge.clone(),
+            accounts.message.clone(),
+            accounts.emitter.clone(),
+            accounts.sequence.clone(),
+            accounts.payer.clone(),
+            accounts.fee_collector.clone(),
+            accounts.clock.clone(),
+            accounts.system_program.clone(),
+            accounts.rent.clone(),
+        ],
+        signer_seeds,
+    )?;
+    Ok(())
+}
@@ -4,8 +4,10 @@ import { Keypair, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from "@solana/web
 import {
   ASSOCIATED_TOKEN_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountIdempotentInstruction,
   getAssociatedTokenAddressSync,
 } from "@solana/spl-token";
+import { assert } from "chai";
 import { randomBytes } from "crypto";
 
 describe("real-escrow-test", () => {
@@ -36,6 +38,15 @@ describe("real-escrow-test", () => {
   // Platform wallet (hardcoded in program - derived automatically)
   const platformWallet = new PublicKey("CkjSZdXopqgh7jkPFn8MxdU7QKwfYdjQNNwbYABFpCx2");
   const platformAtaA = getAssociatedTokenAddressSync(mintA, platformWallet);
+
+  // Protocol config and the insurance fund fed by a slice of the platform fee
+  const config = PublicKey.findProgramAddressSync([Buffer.from("config")], program.programId)[0];
+  const insuranceFund = PublicKey.findProgramAddressSync(
+    [Buffer.from("insurance")],
+    program.programId
+  )[0];
+  const insuranceAtaA = getAssociatedTokenAddressSync(mintA, insuranceFund, true);
+  const INSURANCE_FEE_BPS = 1_000;
   
 
   // Determined Escrow and Vault addresses
@@ -46,9 +57,40 @@ describe("real-escrow-test", () => {
   )[0];
   const vault = getAssociatedTokenAddressSync(mintA, escrow, true);
 
+  // The maker's open-escrow list and track record, kept by every create and close path
+  const makerRegistry = PublicKey.findProgramAddressSync(
+    [Buffer.from("registry"), initializer.publicKey.toBuffer()],
+    program.programId
+  )[0];
+  const initializerStats = PublicKey.findProgramAddressSync(
+    [Buffer.from("trader_stats"), initializer.publicKey.toBuffer()],
+    program.programId
+  )[0];
+  // Open-escrow count before this run's offer, since devnet state carries over
+  let openEscrowsBefore = 0;
+
+  // Settlement must stay well below the default 200k CU per-instruction budget
+  const EXCHANGE_CU_CEILING = 100_000;
+
+  // Exchange expects the payout ATAs to exist already
+  const createPayoutAtas = [
+    createAssociatedTokenAccountIdempotentInstruction(
+      initializer.publicKey, takerAtaA, taker.publicKey, mintA
+    ),
+    createAssociatedTokenAccountIdempotentInstruction(
+      initializer.publicKey, platformAtaA, platformWallet, mintA
+    ),
+    createAssociatedTokenAccountIdempotentInstruction(
+      initializer.publicKey, insuranceAtaA, insuranceFund, mintA
+    ),
+  ];
+
   // Account Wrapper
   const accounts = {
+    authority: initializer.publicKey,
     initializer: initializer.publicKey,
+    // Rent is sponsored by the provider wallet, as a relayer would
+    payer: provider.publicKey,
     taker: taker.publicKey,
     mintA: mintA,
     mintB: mintB,
@@ -60,6 +102,11 @@ describe("real-escrow-test", () => {
     escrow,
     vault,
     platformWallet,
+    config,
+    insuranceFund,
+    insuranceAtaA,
+    makerRegistry,
+    initializerStats,
     associatedTokenprogram: ASSOCIATED_TOKEN_PROGRAM_ID,
     tokenProgram: TOKEN_PROGRAM_ID,
     systemProgram: SystemProgram.programId,
@@ -81,6 +128,27 @@ describe("real-escrow-test", () => {
     return signature;
   };
 
+  it("Initialize protocol config", async () => {
+    // The config survives between runs on devnet
+    if (await program.account.config.fetchNullable(config)) {
+      return;
+    }
+    const programData = PublicKey.findProgramAddressSync(
+      [program.programId.toBuffer()],
+      new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+    )[0];
+
+    await program.methods
+      .initializeConfig(INSURANCE_FEE_BPS)
+      .accounts({
+        admin: provider.publicKey,
+        programData,
+      })
+      .rpc()
+      .then(confirm)
+      .then(log);
+  });
+
   it("Initialize one-sided escrow", async () => {
     console.log("🎯 Initializing one-sided escrow...");
     console.log("Initializer (Seller):", initializer.publicKey.toString());
@@ -90,18 +158,78 @@ describe("real-escrow-test", () => {
     
     const initializerAmount = 100000;  // 0.1 Token A (USDT)
     const takerAmount = 0;             // 0 Token B (no Token B needed)
+    // Stands in for the hash of the off-chain trade agreement
+    const termsHash = Array.from(randomBytes(32));
+    // Maker's own reference, zero-padded to the fixed field size
+    const note = Array.from(Buffer.concat([Buffer.from("INV-2024-0042"), Buffer.alloc(64 - 13)]));
+    const stats = await program.account.traderStats.fetchNullable(initializerStats);
+    openEscrowsBefore = stats ? stats.openEscrows : 0;
     
     await program.methods
-      .initialize(seed, new anchor.BN(initializerAmount), new anchor.BN(takerAmount))
+      .initialize(seed, new anchor.BN(initializerAmount), new anchor.BN(takerAmount), termsHash, 0b101, 2, note, Array(16).fill(0))
       .accounts({ ...accounts })
       .signers([initializer])
       .rpc()
       .then(confirm)
       .then(log);
+
+    const state = await program.account.escrow.fetch(escrow);
+    assert.deepEqual(Array.from(state.termsHash), termsHash);
+    assert.deepEqual(Array.from(state.note), note);
+    // Counted in the global creation order
+    assert.isAbove(state.sequence.toNumber(), 0);
+    assert.equal(state.tags, 0b101);
+    assert.equal(state.category, 2);
+    // Stamped with the build that created it, as reported by get_version
+    const version = await program.methods.getVersion().view();
+    assert.deepEqual(Array.from(state.programVersion), [
+      version.major,
+      version.minor,
+      version.patch,
+    ]);
+    // Escrows what the vault received, which a transfer fee would shrink
+    const vaultBalance = await connection.getTokenAccountBalance(vault);
+    assert.equal(vaultBalance.value.amount, state.initializerAmount.toString());
       
     console.log("✅ One-sided escrow initialized successfully!");
   });
 
+  it("Registers the offer with its maker", async () => {
+    const registry = await program.account.makerRegistry.fetch(makerRegistry);
+    const open = registry.escrows.slice(0, registry.count);
+    assert.isTrue(open.some((key) => key.equals(escrow)));
+
+    const stats = await program.account.traderStats.fetch(initializerStats);
+    assert.equal(stats.openEscrows, openEscrowsBefore + 1);
+  });
+
+  it("Finds the open offer with memcmp filters", async () => {
+    // Escrow::INITIALIZER_OFFSET and Escrow::MINT_A_OFFSET
+    const offers = await program.account.escrow.all([
+      { memcmp: { offset: 40, bytes: initializer.publicKey.toBase58() } },
+      { memcmp: { offset: 72, bytes: mintA.toBase58() } },
+    ]);
+    assert.isTrue(offers.some((offer) => offer.publicKey.equals(escrow)));
+  });
+
+  it("Refuses the maker as their own taker", async () => {
+    try {
+      await program.methods
+        .confirmPayment()
+        .accounts({
+          taker: initializer.publicKey,
+          payer: provider.publicKey,
+          escrow,
+          mintA: mintA,
+        })
+        .signers([initializer])
+        .rpc();
+      assert.fail("the maker confirmed payment on their own offer");
+    } catch (err) {
+      assert.equal(err.error?.errorCode?.code, "SelfTrade");
+    }
+  });
+
   it("Confirm off-chain payment", async () => {
     console.log("💳 Confirming off-chain payment...");
     
@@ -109,6 +237,7 @@ describe("real-escrow-test", () => {
       .confirmPayment()
       .accounts({
         taker: taker.publicKey,
+        payer: provider.publicKey,
         escrow,
         mintA: mintA,
       })
@@ -120,17 +249,78 @@ describe("real-escrow-test", () => {
     console.log("✅ Payment confirmed successfully!");
   });
 
+  it("Release fits the compute budget", async () => {
+    const tx = await program.methods
+      .exchange()
+      .accounts({ ...accounts })
+      .preInstructions(createPayoutAtas)
+      .transaction();
+    tx.feePayer = initializer.publicKey;
+    tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
+    tx.sign(initializer);
+
+    const { value } = await connection.simulateTransaction(tx);
+    assert.isNull(value.err, JSON.stringify(value.logs));
+
+    // Only the exchange instruction counts against the ceiling, not the ATA setup
+    const consumed = value.logs
+      .map((line) => line.match(/^Program (\w+) consumed (\d+) of/))
+      .filter((m) => m && m[1] === program.programId.toBase58())
+      .map((m) => Number(m[2]));
+    console.log("Exchange compute units:", consumed);
+    assert.isNotEmpty(consumed);
+    assert.isBelow(Math.max(...consumed), EXCHANGE_CU_CEILING);
+  });
+
   it("Release tokens to buyer", async () => {
     console.log("🔄 Releasing tokens to buyer...");
+
+    // The view previews the same split exchange pays out
+    const { initializerAmount } = await program.account.escrow.fetch(escrow);
+    const split = await program.methods
+      .viewFeeBreakdown(initializerAmount)
+      .accounts({
+        escrow,
+        mintA,
+        config,
+        initializerStats,
+        partnerConfig: null,
+        donation: null,
+      })
+      .view();
+    const paidOut = [
+      split.platformShare,
+      split.insuranceShare,
+      split.rebate,
+      split.partnerShare,
+      split.partnerFee,
+      split.donation,
+      split.buyerAmount,
+    ].reduce((sum, part) => sum.add(part), new anchor.BN(0));
+    // The risk premium is already part of the insurance share
+    assert.equal(paidOut.toString(), initializerAmount.toString());
+    const takerBefore = await connection.getTokenAccountBalance(takerAtaA);
     
     await program.methods
       .exchange()
       .accounts({ ...accounts })
+      .preInstructions(createPayoutAtas)
       .signers([initializer])  // Only seller signs
       .rpc()
       .then(confirm)
       .then(log);
 
+    const takerAfter = await connection.getTokenAccountBalance(takerAtaA);
+    assert.equal(
+      new anchor.BN(takerAfter.value.amount).sub(new anchor.BN(takerBefore.value.amount)).toString(),
+      split.buyerAmount.toString()
+    );
+    // Settling closes the escrow out of the maker's registry and open count
+    const registry = await program.account.makerRegistry.fetch(makerRegistry);
+    assert.isFalse(registry.escrows.slice(0, registry.count).some((key) => key.equals(escrow)));
+    const stats = await program.account.traderStats.fetch(initializerStats);
+    assert.equal(stats.openEscrows, openEscrowsBefore);
+
     console.log("✅ Tokens released successfully!");
   });
 });